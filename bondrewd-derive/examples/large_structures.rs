@@ -1,7 +1,7 @@
 use bondrewd::*;
 
 #[derive(Bitfields, Debug, Clone, PartialEq)]
-#[bondrewd(default_endianess = "msb", read_from = "lsb0", enforce_bytes = "1")]
+#[bondrewd(default_endianness = "msb", read_from = "lsb0", enforce_bytes = 1)]
 pub struct StatusMagnetometer {
     mtm1: bool,
     mtm2: bool,
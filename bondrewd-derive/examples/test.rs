@@ -2,7 +2,6 @@ use bondrewd::*;
 
 #[derive(Bitfields, Clone)]
 #[bondrewd(default_endianness = "be")]
-///
 struct Simple {
     #[bondrewd(bit_length = 4)]
     one: u8,
@@ -21,7 +20,7 @@ fn main() -> anyhow::Result<()> {
     let mut bytes = test.into_bytes();
     if let Ok(checked_struct) = Simple::check_slice(&bytes){
         assert_eq!(checked_struct.read_one(), 2);
-        assert_eq!(checked_struct.read_two(), true);
+        assert!(checked_struct.read_two());
         assert_eq!(checked_struct.read_three(), 1);
     }else{
         panic!("check failed");
@@ -32,7 +31,7 @@ fn main() -> anyhow::Result<()> {
         checked_struct.write_two(false);
         checked_struct.write_three(2);
         assert_eq!(checked_struct.read_one(), 4);
-        assert_eq!(checked_struct.read_two(), false);
+        assert!(!checked_struct.read_two());
         assert_eq!(checked_struct.read_three(), 2);
     }else{
         panic!("mut check failed");
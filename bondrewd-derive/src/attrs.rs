@@ -0,0 +1,470 @@
+use crate::structs::common::Size;
+use proc_macro2::Span;
+use quote::ToTokens;
+use std::ops::Range;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, BinOp, Error, Expr, ExprRange, Ident, Lit, LitStr, Meta, RangeLimits, UnOp,
+};
+
+/// the keys allowed in one place attributes can be written (structure, field, enum, ...). an
+/// attribute that is ignored changes the layout without telling anyone, so every key inside our
+/// attribute lists has to be one of these.
+pub struct AttrKeys {
+    /// used in error messages, ex. "structure".
+    pub kind: &'static str,
+    /// keys written as `key = value`.
+    pub values: &'static [&'static str],
+    /// keys written without a value.
+    pub flags: &'static [&'static str],
+}
+
+pub const STRUCT_ATTRS: AttrKeys = AttrKeys {
+    kind: "structure",
+    values: &[
+        "default_endianness",
+        "read_from",
+        "enforce_bytes",
+        "enforce_bits",
+        "fill_bytes",
+    ],
+    flags: &[
+        "reverse",
+        "enforce_full_bytes",
+        "runtime_endianness",
+        "allow_gaps",
+        "serde_bytes",
+    ],
+};
+
+/// enums deriving `Bitfields` take the structure attributes and the size of the variant id.
+pub const VARIANTS_ATTRS: AttrKeys = AttrKeys {
+    kind: "enum",
+    values: &[
+        "default_endianness",
+        "read_from",
+        "enforce_bytes",
+        "enforce_bits",
+        "fill_bytes",
+        "id_bit_length",
+    ],
+    flags: &[
+        "reverse",
+        "enforce_full_bytes",
+        "runtime_endianness",
+        "allow_gaps",
+        "serde_bytes",
+    ],
+};
+
+pub const FIELD_ATTRS: AttrKeys = AttrKeys {
+    kind: "field",
+    values: &[
+        "endianness",
+        "bit_length",
+        "byte_length",
+        "bits",
+        "enum_primitive",
+        "struct_size",
+        "element_bit_length",
+        "element_byte_length",
+        "block_bit_length",
+        "block_byte_length",
+        "exponent_bits",
+        "rounding",
+        "scale",
+        "offset",
+        "q",
+        "length_from",
+        "alias_of",
+        "const_value",
+        "checksum",
+        "over",
+    ],
+    flags: &["reserve", "strict", "overlay"],
+};
+
+pub const VARIANT_ATTRS: AttrKeys = AttrKeys {
+    kind: "variant",
+    values: &[],
+    flags: &["invalid"],
+};
+
+pub const ENUM_ATTRS: AttrKeys = AttrKeys {
+    kind: "enum",
+    values: &[],
+    flags: &[
+        "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "partial_eq",
+        "strict", "serde_primitive",
+    ],
+};
+
+/// how an item in an attribute list was written.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ItemForm {
+    /// `key = value`
+    Value,
+    /// `key`
+    Flag,
+    /// `key(...)`, none of our keys are written like this.
+    List,
+}
+
+impl AttrKeys {
+    /// errors if `key` is not one of ours or is written the wrong way.
+    pub fn check(&self, key: &Ident, form: ItemForm) -> syn::Result<()> {
+        let name = key.to_string();
+        let is_value = self.values.contains(&name.as_str());
+        let is_flag = self.flags.contains(&name.as_str());
+        match form {
+            ItemForm::Value if is_value => Ok(()),
+            ItemForm::Flag if is_flag => Ok(()),
+            ItemForm::Value if is_flag => Err(Error::new(
+                key.span(),
+                format!("{} does not take a value, use `{}` alone", name, name),
+            )),
+            ItemForm::Flag | ItemForm::List if is_value => Err(Error::new(
+                key.span(),
+                format!("{} needs a value, ex. `{} = ...`", name, name),
+            )),
+            ItemForm::List if is_flag => Err(Error::new(
+                key.span(),
+                format!("{} does not take a list, use `{}` alone", name, name),
+            )),
+            _ => Err(self.unknown_key_error(key.span(), &name)),
+        }
+    }
+
+    fn list(&self) -> String {
+        self.values
+            .iter()
+            .chain(self.flags.iter())
+            .copied()
+            .collect::<Vec<&str>>()
+            .join(", ")
+    }
+
+    fn unknown_key_error(&self, span: Span, key: &str) -> Error {
+        // a key that is valid somewhere else was most likely put in the wrong place, suggesting a
+        // similar looking key would only be misleading.
+        let elsewhere = [&STRUCT_ATTRS, &VARIANTS_ATTRS, &FIELD_ATTRS, &VARIANT_ATTRS, &ENUM_ATTRS]
+            .into_iter()
+            .find(|other| {
+                other.kind != self.kind
+                    && (other.values.contains(&key) || other.flags.contains(&key))
+            });
+        if let Some(other) = elsewhere {
+            return Error::new(
+                span,
+                format!(
+                    "\"{}\" is not a valid {} attribute, it only goes in {} attributes. valid {} attributes are: {}",
+                    key,
+                    self.kind,
+                    other.kind,
+                    self.kind,
+                    self.list()
+                ),
+            );
+        }
+        let suggestion = closest_match(key, self.values.iter().chain(self.flags.iter()).copied())
+            .map(|close| format!(", did you mean \"{}\"?", close))
+            .unwrap_or_else(|| ".".to_string());
+        Error::new(
+            span,
+            format!(
+                "\"{}\" is not a valid {} attribute{} valid {} attributes are: {}",
+                key,
+                self.kind,
+                suggestion,
+                self.kind,
+                self.list()
+            ),
+        )
+    }
+}
+
+/// one item of an attribute list, `key = value` or a flag `key`. values can be any expression, the
+/// getters below decide what is accepted for each key.
+pub struct AttrItem {
+    pub key: Ident,
+    pub value: Option<Expr>,
+}
+
+impl AttrItem {
+    pub fn name(&self) -> String {
+        self.key.to_string()
+    }
+
+    /// the span of the value, or the key for flags.
+    pub fn span(&self) -> Span {
+        match self.value {
+            Some(ref value) => value.span(),
+            None => self.key.span(),
+        }
+    }
+
+    /// the value if it is a literal.
+    pub fn lit(&self) -> Option<&Lit> {
+        self.value.as_ref().and_then(literal)
+    }
+
+    pub fn lit_str(&self) -> Option<&LitStr> {
+        if let Some(Lit::Str(val)) = self.lit() {
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    /// integer literals and arithmetic on them, ex. `bit_length = 4 * 3`.
+    pub fn usize_value(&self) -> syn::Result<usize> {
+        let name = self.name();
+        let value = match self.value {
+            Some(ref value) => value,
+            None => return Err(Error::new(self.key.span(), format!("{} needs a value", name))),
+        };
+        eval_usize(value, &name)
+    }
+
+    /// float or integer literals, optionally negative.
+    pub fn f64_value(&self) -> syn::Result<f64> {
+        let name = self.name();
+        let parsed = match self.value {
+            Some(ref value) => eval_float(value),
+            None => None,
+        };
+        match parsed {
+            Some(Ok(number)) if number.is_finite() => Ok(number),
+            Some(Ok(_)) => Err(Error::new(self.span(), format!("{} must be a finite number", name))),
+            Some(Err(err)) => Err(err),
+            None => Err(Error::new(
+                self.span(),
+                format!("{} must use a literal number", name),
+            )),
+        }
+    }
+
+    /// a size, like `usize_value` but the value can also name constants or call `const fn`s, ex.
+    /// `bit_length = ID_BITS`. only the compiler can work those out.
+    pub fn size_value(&self) -> syn::Result<Size> {
+        let name = self.name();
+        match self.value {
+            Some(ref value) => eval_size(value, &name),
+            None => Err(Error::new(self.key.span(), format!("{} needs a value", name))),
+        }
+    }
+
+    /// `start..end` or `start..=end`, `None` if the value is not a range expression.
+    pub fn range_value(&self) -> Option<syn::Result<Range<Size>>> {
+        match self.value {
+            Some(Expr::Range(ref range)) => Some(eval_range(range, &self.name())),
+            _ => None,
+        }
+    }
+}
+
+/// every item inside the `#[{name}(...)]` attributes checked against `keys`, other attributes are
+/// left alone.
+pub fn bondrewd_attr_items(
+    attrs: &[Attribute],
+    name: &str,
+    keys: &AttrKeys,
+) -> syn::Result<Vec<AttrItem>> {
+    let mut items = Vec::new();
+    for attr in attrs.iter() {
+        if !attr.path().is_ident(name) {
+            continue;
+        }
+        if !matches!(attr.meta, Meta::List(_)) {
+            return Err(Error::new(
+                attr.span(),
+                format!("expected a list of attributes, ex. #[{}(...)]", name),
+            ));
+        }
+        attr.parse_nested_meta(|meta| {
+            let key = match meta.path.get_ident() {
+                Some(key) => key.clone(),
+                None => {
+                    return Err(meta.error(format!(
+                        "{} attribute keys must be a single identifier",
+                        keys.kind
+                    )))
+                }
+            };
+            if meta.input.peek(syn::Token![=]) {
+                keys.check(&key, ItemForm::Value)?;
+                let value: Expr = meta.value()?.parse()?;
+                items.push(AttrItem {
+                    key,
+                    value: Some(value),
+                });
+            } else if meta.input.peek(syn::token::Paren) {
+                keys.check(&key, ItemForm::List)?;
+            } else {
+                keys.check(&key, ItemForm::Flag)?;
+                items.push(AttrItem { key, value: None });
+            }
+            Ok(())
+        })?;
+    }
+    Ok(items)
+}
+
+/// true if `expr` uses a path or a call anywhere, ex. `PAYLOAD_BYTES * 8`.
+pub fn names_constants(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(_) | Expr::Call(_) | Expr::MethodCall(_) => true,
+        Expr::Group(ref group) => names_constants(&group.expr),
+        Expr::Paren(ref paren) => names_constants(&paren.expr),
+        Expr::Unary(ref unary) => names_constants(&unary.expr),
+        Expr::Cast(ref cast) => names_constants(&cast.expr),
+        Expr::Binary(ref binary) => names_constants(&binary.left) || names_constants(&binary.right),
+        _ => false,
+    }
+}
+
+fn literal(expr: &Expr) -> Option<&Lit> {
+    match expr {
+        Expr::Lit(ref lit) => Some(&lit.lit),
+        Expr::Group(ref group) => literal(&group.expr),
+        Expr::Paren(ref paren) => literal(&paren.expr),
+        _ => None,
+    }
+}
+
+/// works out integer literals and arithmetic on them. "overflowed" is only reported for
+/// arithmetic that does not fit, anything that is not an integer is a type error.
+fn eval_int(expr: &Expr, key: &str) -> syn::Result<i128> {
+    let not_integer = |span: Span| {
+        Error::new(
+            span,
+            format!("{} must be an integer literal or arithmetic on integer literals", key),
+        )
+    };
+    let number = match expr {
+        Expr::Lit(ref lit) => match lit.lit {
+            Lit::Int(ref val) => return val.base10_parse::<i128>(),
+            _ => return Err(not_integer(lit.span())),
+        },
+        Expr::Group(ref group) => return eval_int(&group.expr, key),
+        Expr::Paren(ref paren) => return eval_int(&paren.expr, key),
+        Expr::Unary(ref unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            eval_int(&unary.expr, key)?.checked_neg()
+        }
+        Expr::Binary(ref binary) => {
+            let left = eval_int(&binary.left, key)?;
+            let right = eval_int(&binary.right, key)?;
+            if right == 0 && matches!(binary.op, BinOp::Div(_) | BinOp::Rem(_)) {
+                return Err(Error::new(binary.span(), format!("division by zero in {}", key)));
+            }
+            match binary.op {
+                BinOp::Add(_) => left.checked_add(right),
+                BinOp::Sub(_) => left.checked_sub(right),
+                BinOp::Mul(_) => left.checked_mul(right),
+                BinOp::Div(_) => left.checked_div(right),
+                BinOp::Rem(_) => left.checked_rem(right),
+                BinOp::Shl(_) => u32::try_from(right).ok().and_then(|r| left.checked_shl(r)),
+                BinOp::Shr(_) => u32::try_from(right).ok().and_then(|r| left.checked_shr(r)),
+                _ => {
+                    return Err(Error::new(
+                        binary.op.span(),
+                        format!("unsupported operator in {}", key),
+                    ))
+                }
+            }
+        }
+        _ => return Err(not_integer(expr.span())),
+    };
+    number.ok_or_else(|| Error::new(expr.span(), format!("{} overflowed", key)))
+}
+
+/// errors on `item` unless the derive was built with the `serde` feature, the impls it asks for use
+/// `bondrewd::packed_serde`.
+pub fn check_serde_feature(item: &AttrItem) -> syn::Result<()> {
+    if cfg!(feature = "serde") {
+        Ok(())
+    } else {
+        Err(Error::new(
+            item.key.span(),
+            format!("{} requires the serde feature", item.name()),
+        ))
+    }
+}
+
+/// integer literals and arithmetic on them that are not negative.
+pub fn eval_usize(expr: &Expr, key: &str) -> syn::Result<usize> {
+    usize::try_from(eval_int(expr, key)?)
+        .map_err(|_| Error::new(expr.span(), format!("{} can not be negative", key)))
+}
+
+/// `eval_usize`, or the expression itself when it names constants.
+fn eval_size(expr: &Expr, key: &str) -> syn::Result<Size> {
+    if names_constants(expr) {
+        Ok(Size::Const(expr.to_token_stream()))
+    } else {
+        Ok(Size::Literal(eval_usize(expr, key)?))
+    }
+}
+
+fn eval_range(range: &ExprRange, key: &str) -> syn::Result<Range<Size>> {
+    let bound = |expr: &Option<Box<Expr>>| -> syn::Result<Size> {
+        let expr = match expr {
+            Some(expr) => expr,
+            None => {
+                return Err(Error::new(
+                    range.span(),
+                    format!("{} ranges need a start and an end", key),
+                ))
+            }
+        };
+        eval_size(expr, key)
+    };
+    let start = bound(&range.start)?;
+    let end = bound(&range.end)?;
+    Ok(match range.limits {
+        RangeLimits::HalfOpen(_) => start..end,
+        RangeLimits::Closed(_) => start..end + 1,
+    })
+}
+
+fn eval_float(expr: &Expr) -> Option<syn::Result<f64>> {
+    match expr {
+        Expr::Lit(ref lit) => match lit.lit {
+            Lit::Float(ref val) => Some(val.base10_parse::<f64>()),
+            Lit::Int(ref val) => Some(val.base10_parse::<f64>()),
+            _ => None,
+        },
+        Expr::Group(ref group) => eval_float(&group.expr),
+        Expr::Paren(ref paren) => eval_float(&paren.expr),
+        Expr::Unary(ref unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            eval_float(&unary.expr).map(|number| number.map(|number| -number))
+        }
+        _ => None,
+    }
+}
+
+/// the candidate with the smallest edit distance to `key`, as long as it is close enough to be a
+/// typo.
+fn closest_match<'a>(key: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (key.len() / 3).max(1);
+    candidates
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut last_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = last_row[j] + usize::from(a_char != *b_char);
+            row[j + 1] = substitution.min(last_row[j + 1] + 1).min(row[j] + 1);
+        }
+        last_row = row;
+    }
+    last_row[b.len()]
+}
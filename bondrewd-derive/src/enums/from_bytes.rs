@@ -39,11 +39,121 @@ pub fn generate_from_bytes(enum_info: &EnumInfo) -> syn::Result<proc_macro2::Tok
             #arm
         };
     }
+    let primitive = &enum_info.primitive;
+    if !has_catch_arm(enum_info) {
+        // strict enums without a catch variant give the last variant like an implicit catch all
+        // would, so infallible decoding never panics. try_from_primitive still rejects them.
+        let last_variant = &enum_info.last_variant;
+        arms = quote! {
+            #arms
+            _ => Self::#last_variant,
+        };
+    }
     Ok(quote! {
-        fn from_primitive(input: u8) -> Self {
+        #[allow(unreachable_patterns)]
+        fn from_primitive(input: #primitive) -> Self {
             match input {
                 #arms
             }
         }
     })
 }
+
+fn has_catch_arm(enum_info: &EnumInfo) -> bool {
+    enum_info.variants.iter().any(|var| {
+        matches!(
+            var.value,
+            EnumVariantType::CatchAll(_) | EnumVariantType::CatchPrimitive(_)
+        )
+    })
+}
+
+pub fn generate_try_from_primitive(enum_info: &EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    let mut arms = quote! {};
+    for var in enum_info.variants.iter() {
+        let name = &var.name;
+        let arm = match var.value {
+            EnumVariantType::UnsignedValue(ref value) => {
+                quote! {
+                    #value => Ok(Self::#name),
+                }
+            }
+            EnumVariantType::CatchAll(ref value) => {
+                if enum_info.explicit_catch {
+                    quote! {
+                        _ => Ok(Self::#name),
+                    }
+                } else {
+                    quote! {
+                        #value => Ok(Self::#name),
+                    }
+                }
+            }
+            EnumVariantType::CatchPrimitive(ref field_name) => {
+                if let Some(ref field_name) = field_name {
+                    quote! {
+                        _ => Ok(Self::#name { #field_name = input }),
+                    }
+                } else {
+                    quote! {
+                        _ => Ok(Self::#name(input)),
+                    }
+                }
+            }
+            EnumVariantType::Skip(_) => {
+                return Err(syn::Error::new(
+                    var.name.span(),
+                    "skip got into try from primitive, please open issue.",
+                ))
+            }
+        };
+        arms = quote! {
+            #arms
+            #arm
+        };
+    }
+    let primitive = &enum_info.primitive;
+    Ok(quote! {
+        #[allow(unreachable_patterns)]
+        fn try_from_primitive(input: #primitive) -> Result<Self, bondrewd::BitfieldEnumError<#primitive>> {
+            match input {
+                #arms
+                _ => Err(bondrewd::BitfieldEnumError(input)),
+            }
+        }
+    })
+}
+
+pub fn generate_is_variant_value(enum_info: &EnumInfo) -> proc_macro2::TokenStream {
+    let mut values = Vec::default();
+    for var in enum_info.variants.iter() {
+        match var.value {
+            EnumVariantType::UnsignedValue(ref value) | EnumVariantType::CatchAll(ref value) => {
+                values.push(value)
+            }
+            EnumVariantType::CatchPrimitive(_) | EnumVariantType::Skip(_) => {}
+        }
+    }
+    let primitive = &enum_info.primitive;
+    let has_catch = enum_info.explicit_catch;
+    let check = if values.is_empty() {
+        quote! {
+            let _ = prim;
+            false
+        }
+    } else {
+        quote! {
+            match *prim {
+                #(#values)|* => true,
+                _ => false,
+            }
+        }
+    };
+    quote! {
+        const HAS_CATCH_VARIANT: bool = #has_catch;
+        #[allow(unreachable_patterns)]
+        fn is_variant_value(prim: &#primitive) -> bool {
+            #check
+        }
+    }
+}
@@ -39,8 +39,9 @@ pub fn generate_into_bytes(enum_info: &EnumInfo) -> syn::Result<proc_macro2::Tok
             #arm
         };
     }
+    let primitive = &enum_info.primitive;
     Ok(quote! {
-        fn into_primitive(self) -> u8 {
+        fn into_primitive(self) -> #primitive {
             match self {
                 #arms
             }
@@ -2,3 +2,4 @@ pub mod from_bytes;
 pub mod into_bytes;
 pub mod parse;
 pub mod partial_eq;
+pub mod serde;
@@ -1,8 +1,9 @@
+use crate::attrs::{bondrewd_attr_items, check_serde_feature, ENUM_ATTRS, VARIANT_ATTRS};
 use proc_macro2::{Literal, Span};
 use quote::format_ident;
 use syn::parse::Error;
 use syn::spanned::Spanned;
-use syn::{Attribute, DeriveInput, Expr, Ident, Lit, Meta, NestedMeta, Variant};
+use syn::{Attribute, DeriveInput, Expr, Ident, Lit, UnOp, Variant};
 
 #[derive(Eq, Debug, Clone)]
 pub enum EnumVariantBuilderType {
@@ -17,20 +18,8 @@ impl PartialEq for EnumVariantBuilderType {
         match self {
             Self::UnsignedValue => false,
             Self::CatchAll => true,
-            Self::CatchPrimitive(_name) => {
-                if let Self::CatchPrimitive(_other_name) = other {
-                    true
-                } else {
-                    false
-                }
-            }
-            Self::Skip => {
-                if let Self::Skip = other {
-                    true
-                } else {
-                    false
-                }
-            }
+            Self::CatchPrimitive(_name) => matches!(other, Self::CatchPrimitive(_)),
+            Self::Skip => matches!(other, Self::Skip),
         }
     }
 }
@@ -58,162 +47,130 @@ pub struct EnumInfo {
     pub variants: Vec<EnumVariant>,
     pub primitive: Ident,
     pub partial_eq: bool,
+    /// implement `Serialize` and `Deserialize` through `bondrewd::packed_serde::as_primitive`.
+    pub serde_primitive: bool,
+    /// false when the last variant was made the catch all because no variant was marked invalid,
+    /// or when there is no catch variant at all.
+    pub explicit_catch: bool,
+    /// the last declared variant, `from_primitive` of a strict enum without a catch variant gives it
+    /// for values that do not match a variant.
+    pub last_variant: Ident,
 }
 
 enum ParseMetaResult {
     FoundInvalid,
-    None,
     InvalidConflict(proc_macro2::Span, Ident),
 }
 
 impl EnumInfo {
-    fn parse_meta(
-        meta: Meta,
+    /// a variant marked `invalid`, it catches the values no other variant uses.
+    fn parse_invalid(
         invalid_found: &mut Option<EnumVariantBuilder>,
         primitive_type: &mut Option<Ident>,
         var: &syn::Variant,
     ) -> syn::Result<ParseMetaResult> {
-        match meta {
-            Meta::NameValue(_) => Ok(ParseMetaResult::None),
-            Meta::Path(name_value) => {
-                if let Some(name) = name_value.get_ident() {
-                    match name.to_string().as_str() {
-                        "invalid" => {
-                            if let Some(_) = invalid_found {
-                                return Ok(ParseMetaResult::InvalidConflict(
-                                    var.ident.span(),
-                                    var.ident.clone(),
-                                ));
-                            } else {
-                                match var.fields {
-                                    syn::Fields::Named(ref named) => match named.named.len() {
-                                        1 => {
-                                            if let syn::Type::Path(ref path) = named.named[0].ty {
-                                                if let Some(prim_ident) = path.path.get_ident() {
-                                                    if let Some(ref prim_ty) = primitive_type {
-                                                        if prim_ident.to_string()
-                                                            != prim_ty.to_string()
-                                                        {
-                                                            return Err(syn::Error::new(var.ident.span(), "primitive type does not match enums defined primitive type"));
-                                                        }
-                                                    } else {
-                                                        let mut invalid = Some(EnumVariantBuilder{
-                                                            name: var.ident.clone(),
-                                                            value: EnumVariantBuilderType::CatchPrimitive(if let Some(ref name) = named.named.iter().collect::<Vec<&syn::Field>>()[0].ident{
-                                                                Some(name.clone())
-                                                            }else{
-                                                                return Err(syn::Error::new(var.ident.span(), "named value didn't have name"));
-                                                            }),
-                                                        });
-                                                        std::mem::swap(invalid_found, &mut invalid);
-                                                    }
-                                                }
-                                            } else {
-                                                return Err(syn::Error::new(var.ident.span(), "catch invalid variants with a field must contain a unsigned primitive"));
-                                            }
-                                            let mut invalid = Some(EnumVariantBuilder {
-                                                name: var.ident.clone(),
-                                                value: EnumVariantBuilderType::CatchPrimitive(
-                                                    if let Some(ref name) = named
-                                                        .named
-                                                        .iter()
-                                                        .collect::<Vec<&syn::Field>>()[0]
-                                                        .ident
-                                                    {
-                                                        Some(name.clone())
-                                                    } else {
-                                                        return Err(syn::Error::new(
-                                                            var.ident.span(),
-                                                            "named value didn't have name",
-                                                        ));
-                                                    },
-                                                ),
-                                            });
-                                            std::mem::swap(invalid_found, &mut invalid);
-
-                                            Ok(ParseMetaResult::FoundInvalid)
-                                        }
-                                        _ => {
-                                            return Err(syn::Error::new(var.ident.span(), "Invalid Variants must have either no fields or 1 field containing the primitive type the enum will become"));
-                                        }
-                                    },
-                                    syn::Fields::Unnamed(ref unnamed) => {
-                                        match unnamed.unnamed.len() {
-                                            1 => {
-                                                if let syn::Type::Path(ref path) = unnamed.unnamed[0].ty {
-                                                    if let Some(prim_ident) = path.path.get_ident(){
-                                                        if let Some(ref prim_ty) = primitive_type {
-                                                            if prim_ident.to_string() != prim_ty.to_string() {
-                                                                return Err(syn::Error::new(var.ident.span(), "primitive type does not match enums defined primitive type"));
-                                                            }
-                                                        }else{
-                                                            let mut invalid = Some(EnumVariantBuilder{
-                                                                name: var.ident.clone(),
-                                                                value: EnumVariantBuilderType::CatchPrimitive(None)
-                                                            });
-                                                            std::mem::swap(invalid_found,&mut invalid);
-                                                        }
-                                                    }
-                                                }else{
-                                                    return Err(syn::Error::new(var.ident.span(), "catch invalid variants with a field must contain a unsigned primitive"));
-                                                }
-                                                let mut invalid = Some(EnumVariantBuilder{
-                                                    name: var.ident.clone(),
-                                                    value: EnumVariantBuilderType::CatchPrimitive(None)
-                                                });
-                                                std::mem::swap(invalid_found,&mut invalid);
-                                                Ok(ParseMetaResult::FoundInvalid)
-                                            }
-                                            _ => {
-                                                return Err(syn::Error::new(var.ident.span(), "Variants must have either no fields or 1 field containing the primitive type the enum will become"))
-                                            }
-                                        }
-                                    }
-                                    syn::Fields::Unit => {
-                                        let mut invalid = Some(EnumVariantBuilder {
-                                            name: var.ident.clone(),
-                                            value: EnumVariantBuilderType::CatchAll,
-                                        });
-                                        std::mem::swap(invalid_found, &mut invalid);
-                                        Ok(ParseMetaResult::FoundInvalid)
+        if invalid_found.is_some() {
+            Ok(ParseMetaResult::InvalidConflict(
+                var.ident.span(),
+                var.ident.clone(),
+            ))
+        } else {
+            match var.fields {
+                syn::Fields::Named(ref named) => match named.named.len() {
+                    1 => {
+                        if let syn::Type::Path(ref path) = named.named[0].ty {
+                            if let Some(prim_ident) = path.path.get_ident() {
+                                if let Some(ref prim_ty) = primitive_type {
+                                    if *prim_ty != *prim_ident {
+                                        return Err(syn::Error::new(var.ident.span(), "primitive type does not match enums defined primitive type"));
                                     }
+                                } else {
+                                    let mut invalid = Some(EnumVariantBuilder{
+                                        name: var.ident.clone(),
+                                        value: EnumVariantBuilderType::CatchPrimitive(if let Some(ref name) = named.named.iter().collect::<Vec<&syn::Field>>()[0].ident{
+                                            Some(name.clone())
+                                        }else{
+                                            return Err(syn::Error::new(var.ident.span(), "named value didn't have name"));
+                                        }),
+                                    });
+                                    std::mem::swap(invalid_found, &mut invalid);
                                 }
                             }
+                        } else {
+                            return Err(syn::Error::new(var.ident.span(), "catch invalid variants with a field must contain a primitive"));
                         }
-                        _ => Ok(ParseMetaResult::None),
+                        let mut invalid = Some(EnumVariantBuilder {
+                            name: var.ident.clone(),
+                            value: EnumVariantBuilderType::CatchPrimitive(
+                                if let Some(ref name) = named
+                                    .named
+                                    .iter()
+                                    .collect::<Vec<&syn::Field>>()[0]
+                                    .ident
+                                {
+                                    Some(name.clone())
+                                } else {
+                                    return Err(syn::Error::new(
+                                        var.ident.span(),
+                                        "named value didn't have name",
+                                    ));
+                                },
+                            ),
+                        });
+                        std::mem::swap(invalid_found, &mut invalid);
+
+                        Ok(ParseMetaResult::FoundInvalid)
                     }
-                } else {
-                    Ok(ParseMetaResult::None)
-                }
-            }
-            Meta::List(meta_list) => {
-                if meta_list.path.is_ident("bondrewd_enum") {
-                    for nested_meta in meta_list.nested {
-                        match nested_meta {
-                            NestedMeta::Meta(meta) => {
-                                match Self::parse_meta(meta, invalid_found, primitive_type, var)? {
-                                    ParseMetaResult::FoundInvalid => {
-                                        return Ok(ParseMetaResult::FoundInvalid)
-                                    }
-                                    ParseMetaResult::None => {}
-                                    ParseMetaResult::InvalidConflict(span, name) => {
-                                        return Ok(ParseMetaResult::InvalidConflict(span, name))
+                    _ => {
+                        Err(syn::Error::new(var.ident.span(), "Invalid Variants must have either no fields or 1 field containing the primitive type the enum will become"))
+                    }
+                },
+                syn::Fields::Unnamed(ref unnamed) => {
+                    match unnamed.unnamed.len() {
+                        1 => {
+                            if let syn::Type::Path(ref path) = unnamed.unnamed[0].ty {
+                                if let Some(prim_ident) = path.path.get_ident(){
+                                    if let Some(ref prim_ty) = primitive_type {
+                                        if *prim_ty != *prim_ident {
+                                            return Err(syn::Error::new(var.ident.span(), "primitive type does not match enums defined primitive type"));
+                                        }
+                                    }else{
+                                        let mut invalid = Some(EnumVariantBuilder{
+                                            name: var.ident.clone(),
+                                            value: EnumVariantBuilderType::CatchPrimitive(None)
+                                        });
+                                        std::mem::swap(invalid_found,&mut invalid);
                                     }
                                 }
+                            }else{
+                                return Err(syn::Error::new(var.ident.span(), "catch invalid variants with a field must contain a primitive"));
                             }
-                            NestedMeta::Lit(_) => {}
+                            let mut invalid = Some(EnumVariantBuilder{
+                                name: var.ident.clone(),
+                                value: EnumVariantBuilderType::CatchPrimitive(None)
+                            });
+                            std::mem::swap(invalid_found,&mut invalid);
+                            Ok(ParseMetaResult::FoundInvalid)
+                        }
+                        _ => {
+                            Err(syn::Error::new(var.ident.span(), "Variants must have either no fields or 1 field containing the primitive type the enum will become"))
                         }
                     }
-                    Ok(ParseMetaResult::None)
-                } else {
-                    Ok(ParseMetaResult::None)
+                }
+                syn::Fields::Unit => {
+                    let mut invalid = Some(EnumVariantBuilder {
+                        name: var.ident.clone(),
+                        value: EnumVariantBuilderType::CatchAll,
+                    });
+                    std::mem::swap(invalid_found, &mut invalid);
+                    Ok(ParseMetaResult::FoundInvalid)
                 }
             }
         }
     }
 
     // Parses the Expression, looking for a literal number expression
-    fn parse_lit_discriminant_expr(input: &Expr) -> syn::Result<usize> {
+    pub fn parse_lit_discriminant_expr(input: &Expr) -> syn::Result<i128> {
         match input {
             Expr::Lit(ref lit) => match lit.lit {
                 Lit::Int(ref i) => Ok(i.base10_parse()?),
@@ -222,6 +179,10 @@ impl EnumInfo {
                     "Non-integer literals for custom discriminant are illegal.",
                 )),
             },
+            // negative discriminants, only useful with signed primitives.
+            Expr::Unary(ref unary) if matches!(unary.op, UnOp::Neg(_)) => {
+                Ok(-Self::parse_lit_discriminant_expr(&unary.expr)?)
+            }
             _ => Err(syn::Error::new(
                 input.span(),
                 "non-literal expressions for custom discriminant are illegal.",
@@ -229,31 +190,48 @@ impl EnumInfo {
         }
     }
 
+    /// Returns the inclusive range of values `prim` can hold, or `None` if `prim` is not a
+    /// primitive an enum can be converted to. u128 is capped at `i128::MAX` because discriminants
+    /// are parsed as i128.
+    fn primitive_range(prim: &str) -> Option<(i128, i128)> {
+        Some(match prim {
+            "u8" => (0, u8::MAX as i128),
+            "u16" => (0, u16::MAX as i128),
+            "u32" => (0, u32::MAX as i128),
+            "u64" => (0, u64::MAX as i128),
+            "u128" => (0, i128::MAX),
+            "i8" => (i8::MIN as i128, i8::MAX as i128),
+            "i16" => (i16::MIN as i128, i16::MAX as i128),
+            "i32" => (i32::MIN as i128, i32::MAX as i128),
+            "i64" => (i64::MIN as i128, i64::MAX as i128),
+            "i128" => (i128::MIN, i128::MAX),
+            _ => return None,
+        })
+    }
+
     fn parse_attrs(
-        attrs: &Vec<Attribute>,
+        attrs: &[Attribute],
         var: &Variant,
         primitive_type: &mut Option<Ident>,
         invalid_found: &mut Option<EnumVariantBuilder>,
     ) -> syn::Result<bool> {
+        // invalid is the only variant attribute.
+        if bondrewd_attr_items(attrs, "bondrewd_enum", &VARIANT_ATTRS)?.is_empty() {
+            return Ok(false);
+        }
         let mut temp: Option<Ident> = None;
         let mut temp_invalid = invalid_found.clone();
-        for attr in attrs {
-            match Self::parse_meta(attr.parse_meta()?, &mut temp_invalid, &mut temp, &var)? {
-                ParseMetaResult::FoundInvalid => {
-                    std::mem::swap(&mut temp, primitive_type);
-                    std::mem::swap(&mut temp_invalid, invalid_found);
-                    return Ok(true);
-                }
-                ParseMetaResult::None => {}
-                ParseMetaResult::InvalidConflict(span, name) => {
-                    return Err(syn::Error::new(
-                        span,
-                        format!("Invalid already found [{}]", name),
-                    ));
-                }
+        match Self::parse_invalid(&mut temp_invalid, &mut temp, var)? {
+            ParseMetaResult::FoundInvalid => {
+                std::mem::swap(&mut temp, primitive_type);
+                std::mem::swap(&mut temp_invalid, invalid_found);
+                Ok(true)
             }
+            ParseMetaResult::InvalidConflict(span, name) => Err(syn::Error::new(
+                span,
+                format!("Invalid already found [{}]", name),
+            )),
         }
-        Ok(false)
     }
 
     pub fn parse(input: &DeriveInput) -> syn::Result<Self> {
@@ -266,49 +244,28 @@ impl EnumInfo {
         };
         let mut primitive_type: Option<Ident> = None;
         let mut partial_eq = false;
-        for attr in &input.attrs {
-            match attr.parse_meta()? {
-                Meta::NameValue(_) => {}
-                Meta::Path(_) => {}
-                Meta::List(meta_list) => {
-                    if meta_list.path.is_ident("bondrewd_enum") {
-                        for nested_meta in meta_list.nested {
-                            match nested_meta {
-                                NestedMeta::Meta(meta) => match meta {
-                                    Meta::Path(path) => {
-                                        // Add an additional check to implement `partial_eq` optionally.
-                                        if path.is_ident("u8") {
-                                            primitive_type = Some(quote::format_ident!("u8"));
-                                        } else if path.is_ident("partial_eq") {
-                                            partial_eq = true;
-                                        } else {
-                                            return Err(syn::Error::new(
-                                                input.ident.span(),
-                                                "the only supported enum attributes are u8, partial_eq currently",
-                                            ));
-                                        }
-
-                                        // Have we found all of the relevant attributes?
-                                        if primitive_type.is_some() && partial_eq {
-                                            break;
-                                        }
-                                    }
-                                    _ => {}
-                                },
-                                NestedMeta::Lit(_) => {}
-                            }
-                        }
-                    }
+        let mut strict = false;
+        let mut serde_primitive = false;
+        for item in bondrewd_attr_items(&input.attrs, "bondrewd_enum", &ENUM_ATTRS)? {
+            match item.name().as_str() {
+                "partial_eq" => partial_eq = true,
+                "strict" => strict = true,
+                "serde_primitive" => {
+                    check_serde_feature(&item)?;
+                    serde_primitive = true;
                 }
+                // the rest are the primitive types.
+                _ => primitive_type = Some(item.key),
             }
         }
         // get the list of fields in syn form, error out if unit struct (because they have no data, and
         // data packing/analysis don't seem necessary)
-        let mut literal_variants: std::collections::BTreeMap<usize, EnumVariant> =
+        let mut literal_variants: std::collections::BTreeMap<i128, EnumVariant> =
             Default::default();
         let mut unknown_variants: std::collections::VecDeque<EnumVariantBuilder> =
             Default::default();
         let mut invalid_found: Option<EnumVariantBuilder> = None;
+        let mut explicit_catch = true;
         let last_variant = data.variants.len() - 1;
         for (var, i) in data.variants.iter().zip(0..data.variants.len()) {
             let test_len = literal_variants.len() + unknown_variants.len();
@@ -319,7 +276,7 @@ impl EnumInfo {
                             if let syn::Type::Path(ref path) = named.named[0].ty {
                                 if let Some(prim_ident) = path.path.get_ident(){
                                     if let Some(ref prim_ty) = primitive_type {
-                                        if prim_ident.to_string() != prim_ty.to_string() {
+                                        if *prim_ty != *prim_ident {
                                             return Err(syn::Error::new(var.ident.span(), "primitive type does not match enums defined primitive type"));
                                         }
                                     }else{
@@ -327,7 +284,7 @@ impl EnumInfo {
                                     }
                                 }
                             }else{
-                                return Err(syn::Error::new(var.ident.span(), "catch invalid variants with a field must contain a unsigned primitive"));
+                                return Err(syn::Error::new(var.ident.span(), "catch invalid variants with a field must contain a primitive"));
                             }
                             if let Some(conflict) = invalid_found {
                                 return Err(syn::Error::new(var.ident.span(), format!("conflicting Invalid Variant named [{}]", conflict.name)));
@@ -356,7 +313,7 @@ impl EnumInfo {
                             if let syn::Type::Path(ref path) = unnamed.unnamed[0].ty {
                                 if let Some(prim_ident) = path.path.get_ident(){
                                     if let Some(ref prim_ty) = primitive_type {
-                                        if prim_ident.to_string() != prim_ty.to_string() {
+                                        if *prim_ty != *prim_ident {
                                             return Err(syn::Error::new(var.ident.span(), "primitive type does not match enums defined primitive type"));
                                         }
                                     }else{
@@ -364,7 +321,7 @@ impl EnumInfo {
                                     }
                                 }
                             }else{
-                                return Err(syn::Error::new(var.ident.span(), "catch invalid variants with a field must contain a unsigned primitive"));
+                                return Err(syn::Error::new(var.ident.span(), "catch invalid variants with a field must contain a primitive"));
                             }
                             if let Some(conflict) = invalid_found {
                                 return Err(syn::Error::new(var.ident.span(), format!("conflicting Invalid Variant named [{}]", conflict.name)));
@@ -385,7 +342,12 @@ impl EnumInfo {
                 }
                 _ => {
                     // Check for an invalid already existing, and if this is the last variant in the variants
-                    if invalid_found.is_none() && last_variant == i {
+                    if invalid_found.is_none() && last_variant == i && !strict {
+                        // the last variant catches invalid values either way, but it only counts
+                        // as a catch variant if it was marked as one.
+                        let mut marked_primitive = primitive_type.clone();
+                        let mut marked_invalid = None;
+                        explicit_catch = Self::parse_attrs(&var.attrs, var, &mut marked_primitive, &mut marked_invalid)?;
                         invalid_found = Some(EnumVariantBuilder{
                             name: var.ident.clone(),
                             value: EnumVariantBuilderType::CatchAll,
@@ -393,8 +355,8 @@ impl EnumInfo {
                         if let Some((_, ref discriminant)) = var.discriminant {
                             // Parse the discriminant and validate its able to be used
                             let discriminant_val = Self::parse_lit_discriminant_expr(discriminant)?;
-                            if let Some(_oh_no) = literal_variants.insert(discriminant_val.clone(), EnumVariant{
-                                value: EnumVariantType::Skip(Literal::usize_unsuffixed(discriminant_val)),
+                            if let Some(_oh_no) = literal_variants.insert(discriminant_val, EnumVariant{
+                                value: EnumVariantType::Skip(Literal::i128_unsuffixed(discriminant_val)),
                                 name: var.ident.clone(),
                             }) {
                                 return Err(syn::Error::new(var.ident.span(), "Literal Values conflict"));
@@ -407,27 +369,27 @@ impl EnumInfo {
                         }
                     } else {
                         // This is one of the possible variants to use, check for a custom discriminant
-                         if invalid_found.is_none() && Self::parse_attrs(&var.attrs, &var, &mut primitive_type, &mut invalid_found)? {
+                         if invalid_found.is_none() && Self::parse_attrs(&var.attrs, var, &mut primitive_type, &mut invalid_found)? {
                             if let Some((_, ref discriminant)) = var.discriminant {
                                 // Parse the discriminant and validate its able to be used
                                 let discriminant_val = Self::parse_lit_discriminant_expr(discriminant)?;
                                 if let Some(_oh_no) = literal_variants.insert(discriminant_val, EnumVariant{
-                                    value: EnumVariantType::Skip(Literal::usize_unsuffixed(discriminant_val)),
+                                    value: EnumVariantType::Skip(Literal::i128_unsuffixed(discriminant_val)),
                                     name: var.ident.clone(),
                                 }) {
                                     return Err(syn::Error::new(var.ident.span(), "Literal Values conflict"));
                                 }
                             }else{
-                                literal_variants.insert(i, EnumVariant{
+                                literal_variants.insert(i as i128, EnumVariant{
                                     name: var.ident.clone(),
-                                    value: EnumVariantType::Skip(Literal::usize_unsuffixed(i)),
+                                    value: EnumVariantType::Skip(Literal::i128_unsuffixed(i as i128)),
                                 });
                             }
                         } else if let Some((_, ref discriminant)) = var.discriminant {
                             // Parse the discriminant and validate its able to be used
                             let discriminant_val = Self::parse_lit_discriminant_expr(discriminant)?;
                             if let Some(_oh_no) = literal_variants.insert(discriminant_val, EnumVariant{
-                                value: EnumVariantType::UnsignedValue(Literal::usize_unsuffixed(discriminant_val)),
+                                value: EnumVariantType::UnsignedValue(Literal::i128_unsuffixed(discriminant_val)),
                                 name: var.ident.clone(),
                             }) {
                                 return Err(syn::Error::new(var.ident.span(), "Literal Values conflict"));
@@ -460,8 +422,8 @@ impl EnumInfo {
             ));
         }
         for i in 0..=last_variant {
-            if literal_variants.contains_key(&i) {
-                if let Some(enum_var) = literal_variants.remove(&i) {
+            if literal_variants.contains_key(&(i as i128)) {
+                if let Some(enum_var) = literal_variants.remove(&(i as i128)) {
                     if let EnumVariantType::Skip(ref lit) = enum_var.value {
                         if skipped.is_some() {
                             // CHECK if error is needed
@@ -487,7 +449,7 @@ impl EnumInfo {
                     EnumVariantBuilderType::CatchAll => {
                         variants.push(EnumVariant {
                             name: unknown_variant.name,
-                            value: EnumVariantType::CatchAll(Literal::usize_unsuffixed(i)),
+                            value: EnumVariantType::CatchAll(Literal::i128_unsuffixed(i as i128)),
                         });
                         continue;
                     }
@@ -506,24 +468,20 @@ impl EnumInfo {
                                 "two skips. please open issue for this",
                             ));
                         }
-                        skipped = Some(Literal::usize_unsuffixed(i));
+                        skipped = Some(Literal::i128_unsuffixed(i as i128));
                         continue;
                     }
                     EnumVariantBuilderType::UnsignedValue => {
                         variants.push(EnumVariant {
                             name: unknown_variant.name,
-                            value: EnumVariantType::UnsignedValue(Literal::usize_unsuffixed(i)),
+                            value: EnumVariantType::UnsignedValue(Literal::i128_unsuffixed(i as i128)),
                         });
                         continue;
                     }
                 }
             }
             let key = {
-                if let Some(ref size) = literal_variants.iter().next() {
-                    Some(size.0.clone())
-                } else {
-                    None
-                }
+                literal_variants.iter().next().as_ref().map(|size| *size.0)
             };
             if let Some(lit_index) = key {
                 if let Some(enum_var) = literal_variants.remove(&lit_index) {
@@ -598,35 +556,80 @@ impl EnumInfo {
             }
         }
 
-        let info = EnumInfo {
-            name: input.ident.clone(),
-            partial_eq,
-            primitive: if let Some(prim) = primitive_type {
-                if prim.to_string().as_str() == "u8" {
-                    prim
+        // every discriminant written in code, used to infer the primitive and to check that all
+        // values fit in it. values bondrewd assigns are never larger than the last variant index.
+        let mut literal_values: Vec<(i128, Span)> = Vec::default();
+        for var in data.variants.iter() {
+            if let Some((_, ref discriminant)) = var.discriminant {
+                literal_values.push((
+                    Self::parse_lit_discriminant_expr(discriminant)?,
+                    discriminant.span(),
+                ));
+            }
+        }
+        let min_value = literal_values.iter().map(|(v, _)| *v).fold(0, i128::min);
+        let max_value = literal_values
+            .iter()
+            .map(|(v, _)| *v)
+            .fold(last_variant as i128, i128::max);
+        let primitive = if let Some(prim) = primitive_type {
+            prim
+        } else {
+            let candidates = if min_value < 0 {
+                ["i8", "i16", "i32", "i64", "i128"]
+            } else {
+                ["u8", "u16", "u32", "u64", "u128"]
+            };
+            let found = candidates.iter().find(|prim| {
+                if let Some((min, max)) = Self::primitive_range(prim) {
+                    min <= min_value && max_value <= max
                 } else {
-                    return Err(syn::Error::new(
-                        input.ident.span(),
-                        "primitive_type must be u8 for now",
-                    ));
+                    false
                 }
+            });
+            if let Some(prim) = found {
+                format_ident!("{}", prim)
             } else {
-                match variants.len() {
-                    0_usize..=255_usize => {
-                        format_ident!("u8")
-                    }
-                    256_usize..=65535 => {
-                        format_ident!("u16")
-                    }
-                    65536..=4294967296 => {
-                        format_ident!("u32")
-                    }
-                    _ => {
-                        return Err(syn::Error::new(input.span(), "too many variants?"));
-                    }
-                }
-            },
+                return Err(syn::Error::new(
+                    input.span(),
+                    "no primitive can hold all of the variant values",
+                ));
+            }
+        };
+        let (prim_min, prim_max) = if let Some(range) =
+            Self::primitive_range(&primitive.to_string())
+        {
+            range
+        } else {
+            return Err(syn::Error::new(
+                primitive.span(),
+                format!("{} is not a supported primitive for enums, use u8, u16, u32, u64, u128, i8, i16, i32, i64 or i128", primitive),
+            ));
+        };
+        for (value, span) in literal_values {
+            if value < prim_min || prim_max < value {
+                return Err(syn::Error::new(
+                    span,
+                    format!("discriminant {} does not fit in {}", value, primitive),
+                ));
+            }
+        }
+        if prim_max < last_variant as i128 {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                format!("{} variants do not fit in {}", last_variant + 1, primitive),
+            ));
+        }
+
+        let explicit_catch = explicit_catch && invalid_found.is_some();
+        let info = EnumInfo {
+            name: input.ident.clone(),
+            partial_eq,
+            serde_primitive,
+            primitive,
             variants,
+            explicit_catch,
+            last_variant: data.variants[last_variant].ident.clone(),
         };
 
         Ok(info)
@@ -0,0 +1,23 @@
+use crate::enums::parse::EnumInfo;
+use quote::quote;
+
+/// Generates `Serialize` and `Deserialize` implementations that use the primitive of the enum
+/// instead of the variant name, for `#[bondrewd_enum(serde_primitive)]`.
+pub fn generate_serde(enum_info: &EnumInfo) -> proc_macro2::TokenStream {
+    if !enum_info.serde_primitive {
+        return quote! {};
+    }
+    let enum_name = &enum_info.name;
+    quote! {
+        impl bondrewd::packed_serde::Serialize for #enum_name {
+            fn serialize<S: bondrewd::packed_serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                bondrewd::packed_serde::as_primitive::serialize(self, serializer)
+            }
+        }
+        impl<'de> bondrewd::packed_serde::Deserialize<'de> for #enum_name {
+            fn deserialize<D: bondrewd::packed_serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                bondrewd::packed_serde::as_primitive::deserialize(deserializer)
+            }
+        }
+    }
+}
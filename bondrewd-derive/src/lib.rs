@@ -5,17 +5,22 @@
 //!
 //! # Derive Bitfields
 //! - Implements the [`Bitfields`](https://docs.rs/bondrewd/latest/bondrewd/trait.Bitfields.html) trait
-//! which offers from\into bytes functions that are non-failable and convert the struct from/into sized
-//! u8 arrays ([u8; {total_bit_length * 8}]).
+//!   which offers from\into bytes functions that are non-failable and convert the struct from/into sized
+//!   u8 arrays ([u8; {total_bit_length * 8}]).
 //! - `read` and `write` functions that allow the field to be accessed or overwritten within a sized u8 array.
+//! - Implements the `BitfieldsView` trait, which wraps a buffer in a `{struct_name}View` or
+//!   `{struct_name}ViewMut` with the same `read`/`write` functions taking `&self`, plus `to_owned()`.
+//!   [example](Bitfields#view-example)
+//! - Implements the `BitfieldsPaths` trait, giving accessors that reach fields of nested structures
+//!   without decoding the structures along the way. [example](Bitfields#paths-example)
 //! - More information about how each field is handled (bit length, endianness, ..), as well as structure
-//! wide effects (bit position, default field endianness, ..), can be found on the
-//! [`Bitfields Derive`](Bitfields) page.
+//!   wide effects (bit position, default field endianness, ..), can be found on the
+//!   [`Bitfields Derive`](Bitfields) page.
 //!
 //! For example we can define a data structure with 7 total bytes as:
 //! - A boolean field named one will be the first bit.
 //! - A floating point field named two will be the next 32 bits. floats must be full sized
-//! currently.
+//!   currently.
 //! - A signed integer field named three will be the next 14 bits.
 //! - An unsigned integer field named four will be the next 6 bits.
 //!
@@ -54,10 +59,10 @@
 //! ```
 //! # Derive BitfieldEnum
 //! - Implements the [`BitfieldEnum`](https://docs.rs/bondrewd/latest/bondrewd/trait.BitfieldEnum.html)
-//! trait which offers from\into primitive functions that are non-failable and convert the enum from/into
-//! a primitive type (u8 is the only currently testing primitive).
+//!   trait which offers from\into primitive functions that are non-failable and convert the enum from/into
+//!   a primitive type (any integer primitive other than usize and isize).
 //! - More information about controlling the end result (define variant values, define a catch/invalid
-//! variant) can be found on the [`BitfieldEnum Derive`](BitfieldEnum) page.
+//!   variant) can be found on the [`BitfieldEnum Derive`](BitfieldEnum) page.
 //!
 //! ```
 //! // Users code
@@ -105,9 +110,9 @@
 //! Slice functions are convenience functions for reading/wring single or multiple fields without reading
 //! the entire structure. Bondrewd will provided 2 ways to access the field:
 //! * Single field access. These are functions that are added along side the standard read/write field
-//! functions in the impl for the input structure. read/write slice functions will check the length of
-//! the slice to insure the amount to bytes needed for the field (NOT the entire structure) are present and
-//! return BitfieldSliceError if not enough bytes are present.
+//!   functions in the impl for the input structure. read/write slice functions will check the length of
+//!   the slice to insure the amount to bytes needed for the field (NOT the entire structure) are present and
+//!   return BitfieldSliceError if not enough bytes are present.
 //!     * `fn read_slice_{field}(&[u8]) -> Result<{field_type}, bondrewd::BondrewdSliceError> { .. }`
 //!     * `fn write_slice_{field}(&mut [u8], {field_type}) -> Result<(), bondrewd::BondrewdSliceError> { .. }`
 //! * Multiple field access.
@@ -181,6 +186,46 @@
 //! [hex](https://crates.io/crates/hex) crate's from/into slice functions but with statically sized
 //! arrays so we could eliminate sizing errors.
 //!
+//! `try_fns` implements the `TryBitfields` trait, which adds a `try_from_bytes` function that
+//! returns a `BitfieldDecodeError` naming the field and its bit range instead of quietly decoding
+//! data that can not be valid:
+//! - reserve fields and fill bytes that are not zero.
+//! - chars that are not valid unicode scalar values.
+//! - enum values that do not match a variant. values caught by a variant marked
+//!   `#[bondrewd_enum(invalid)]` (or one holding the primitive) are accepted unless the field has
+//!   the `strict` attribute.
+//!
+//! Nested structures are decoded with their own `try_from_bytes`, so they need it too. Their errors
+//! are reported with the path to the field (`inner.reserve`) and its bits in the outer structure.
+//! Each field also gets a `try_read_{field}` function next to `read_{field}`. Enums are decoded
+//! with `TryBitfieldEnum::try_from_primitive`, which makes `try_from_bytes` the way to decode
+//! structs holding `#[bondrewd_enum(strict)]` enums.
+//!
+//! `dyn_fns` allows the last field of a structure to be a `Vec<u8>` or `&'a [u8]` with its length
+//! read from a field before it, marked `#[bondrewd(length_from = "{FIELD}", offset = {OFFSET})]`.
+//! Such structures do not have a fixed size so they implement `BitfieldsDyn` instead of
+//! `Bitfields`, the fields before the trailing field make up a header which still gets the
+//! `read_{field}`/`write_{field}` functions. [example](Bitfields#length-from-example)
+//!
+//! `std` (on by default) adds `bondrewd::io::{ReadBitfieldsExt, WriteBitfieldsExt}`, which give any
+//! `std::io::Read` a `read_bitfields::<T, SIZE>()` and any `std::io::Write` a `write_bitfields(&value)`.
+//! `embedded_io` adds the same traits over `embedded_io::Read`/`Write` in `bondrewd::embedded` for
+//! `no_std` targets. With `try_fns` there is also `try_read_bitfields`, its `BitfieldIoError` tells
+//! a failed read apart from bytes that did not decode.
+//!
+//! `serde` adds `bondrewd::packed_serde::as_bytes` and `bondrewd::packed_serde::as_primitive` for
+//! `#[serde(with = "...")]`, which (de)serialize a structure as its packed bytes and an enum as its
+//! primitive. `#[bondrewd(serde_bytes)]` and `#[bondrewd_enum(serde_primitive)]` have the derives
+//! implement `Serialize` and `Deserialize` with them. The module is `packed_serde` rather than
+//! `serde` because the usual `use bondrewd::*;` would otherwise bring in a `serde` that is
+//! ambiguous with the serde crate itself.
+//!
+//! `tokio_codec` adds `bondrewd::codec::BitfieldsCodec<T, SIZE>`, a `tokio_util` `Decoder` and
+//! `Encoder` for streams of `SIZE` byte frames. `BitfieldsCodec::with_sync_marker` puts a marker
+//! before every frame and has the decoder skip to the next marker, so it can pick up a stream in
+//! the middle. With `try_fns` `TryBitfieldsCodec<T, SIZE>` decodes with `try_from_bytes`, invalid
+//! frames are `InvalidData` errors.
+//!
 //! ### Full Example Generated Code
 //! ```
 //! use bondrewd::*;
@@ -308,12 +353,14 @@
 //! }
 //! ```
 extern crate proc_macro;
+mod attrs;
 mod enums;
 use enums::parse::EnumInfo;
 mod structs;
 use structs::common::StructInfo;
 use structs::from_bytes::create_from_bytes_field_quotes;
 use structs::into_bytes::create_into_bytes_field_quotes;
+use structs::variants::{create_variants_quotes, VariantsInfo};
 
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
@@ -328,56 +375,142 @@ use syn::{parse_macro_input, DeriveInput};
 ///
 /// # Supported Field Types
 /// - All primitives other than usize and isize (i believe ambiguous sizing is bad for this type of work).
-///     - Floats currently must be full sized.
+///     - Floats can be stored with fewer bits, see `exponent_bits` and `rounding`.
+///       [example](#reduced-float-example)
+///     - `half::f16` and `half::bf16` with the `half` feature.
 ///     - Its important to know that there is a small runtime cost for signed numbers.
 /// - Enums which implement the BitfieldEnum trait in Bondrewd.
 /// - Structs which implement the Bitfield trait in Bondrewd.
 ///
 /// # Struct Attributes
-/// - `default_endianness = {"le" or "be"}` Describes a default endianness for primitive fields. 
-/// [example](#endianness-examples)
+/// - `default_endianness = {"le", "be" or "ne"}` Describes a default endianness for primitive fields. 
+///   [example](#endianness-examples)
 /// - `read_from = {"msb0" or "lsb0"}` Defines bit positioning. which end of the byte array to start at.
-/// [example](#bit-positioning-examples)
+///   [example](#bit-positioning-examples)
 /// - `enforce_bytes = {BYTES}` Adds a check that requires total bytes defined by fields to equal provided
-/// BYTES. [example](#enforce-bits-examples)
+///   BYTES. [example](#enforce-bits-examples)
 /// - `enforce_bits = {BITS}` Adds a check that requires total bits defined by fields to equal provided
-/// BITS. [example](#enforce-bits-examples)
+///   BITS. [example](#enforce-bits-examples)
 /// - `enforce_full_bytes` Adds a check that requires total bits defined by fields to equal a multiple of 8.
-/// [example](#enforce-full-bytes-example)
+///   [example](#enforce-full-bytes-example)
 /// - `fill_bytes = {BYTES}` Will force the output/input byte array size to be the provided SIZE amount of
-/// bytes. [example](#fill-bytes-examples)
+///   bytes. [example](#fill-bytes-examples)
 /// - `reverse` Defines that the entire byte array should be read backward (first byte index becomes last
-/// byte index). This has no runtime cost. [example](#reverse-example)
+///   byte index). This has no runtime cost. [example](#reverse-example)
+/// - `id_bit_length = {BITS}` Only for enums, the amount of bits used by the variant id.
+///   [example](#enum-with-data-example)
+/// - `runtime_endianness` Also generates `from_bytes_le`, `from_bytes_be`, `into_bytes_le`,
+///   `into_bytes_be` and `_le`/`_be` versions of every read and write function, so the byte order can
+///   be picked at runtime. [example](#runtime-endianness-example)
+/// - `allow_gaps` Bits that no field uses are treated like reserve fields instead of being an error.
+///   [example](#bit-range-example)
+/// - `serde_bytes` Implements serde's `Serialize` and `Deserialize` through
+///   `bondrewd::packed_serde::as_bytes`, so the structure is (de)serialized as its packed bytes. Needs the
+///   `serde` feature and `Clone`.
 ///
 /// # Field Attributes
 /// - `bit_length = {BITS}` Define the total amount of bits to use when condensed. [example](#simple-example)
 /// - `byte_length = {BYTES}` Define the total amount of bytes to use when condensed. [example](#simple-example)
-/// - `endianness = {"le" or "be"}` Define per field endianess. [example](#endianness-examples)
+/// - `endianness = {"le", "be" or "ne"}` Define per field endianess. [example](#endianness-examples)
 /// - `block_bit_length = {BITS}` Describes a bit length for the entire array dropping lower indexes first.
-/// [example](#bitfield-array-examples)
+///   [example](#bitfield-array-examples)
 /// - `block_byte_length = {BYTES}` Describes a byte length for the entire array dropping lower indexes
-/// first. [example](#bitfield-array-examples)
+///   first. [example](#bitfield-array-examples)
 /// - `element_bit_length = {BITS}` Describes a bit length for each element of an array. (default array
-/// type). [example](#bitfield-array-examples)
+///   type). [example](#bitfield-array-examples)
 /// - `element_byte_length = {BYTES}` Describes a byte length for each element of an array. (default array
-/// type). [example](#bitfield-array-examples)
-/// - `enum_primitive = "u8"` Defines the size of the enum. the BitfieldEnum currently only supports u8.
-/// [example](#enum-examples)
+///   type). [example](#bitfield-array-examples)
+/// - `enum_primitive = "u8"` Defines the size of the enum. Any integer primitive other than usize and
+///   isize can be used, and it must match the `Primitive` of the enums BitfieldEnum implementation.
+///   [example](#enum-examples)
 /// - `struct_size = {SIZE}` Defines the field as a struct which implements the Bitfield trait and the
-/// BYTE_SIZE const defined in said trait. [example](#bitfield-struct-as-field-examples)
+///   BYTE_SIZE const defined in said trait. Can be left out, the size is then the fewest bytes holding
+///   the `bit_length`, or the sizes of the struct's Bitfields implementation without one. Fields whose
+///   type uses a generic parameter of the structure need one of the two.
+///   [example](#bitfield-struct-as-field-examples)
 /// - `reserve` Defines that this field should be ignored in from and into bytes functions.
-/// [example](#reserve-examples)
+///   [example](#reserve-examples)
 ///     - reserve attribute is only supported for primitive types currently.
-/// - /!Untested!\ `bits = "RANGE"` - define the bit indexes yourself rather than let the proc macro figure
-/// it out. using a rust range in quotes.
+/// - `strict` Only for enum fields. `try_from_bytes` will reject values that land in a catch
+///   variant rather than accepting them. requires the `try_fns` feature to have an effect.
+///   `from_bytes` still decodes those values into the catch variant, and for a
+///   `#[bondrewd_enum(strict)]` enum into its last variant, so only `try_from_bytes` and
+///   `try_read_{field}` notice them.
+/// - `length_from = "{FIELD}"` Only for the last field, which must be a `Vec<u8>` or `&'a [u8]`. The
+///   amount of bytes in the field is the value of the named unsigned field plus `offset`. requires the
+///   `dyn_fns` feature. [example](#length-from-example)
+/// - `offset = {AMOUNT}` Added to the value of the `length_from` field, defaults to 0.
+/// - `overlay` Along with `bits`, the field is another view of bits other fields use. It gets read and
+///   write functions but `from_bytes` sets it to `Default::default()` and `into_bytes` skips it.
+///   [example](#overlay-example)
+/// - `alias_of = "{FIELD}"` An overlay starting where FIELD starts, FIELD has to be declared before it.
+///   The length comes from the type or a `bit_length` like any other field.
+/// - `const_value = {VALUE}` Only for integer and bool fields. `into_bytes` always writes VALUE,
+///   `from_bytes` gives the field VALUE and `try_from_bytes` rejects bytes holding anything else. A
+///   `()` field with a `bit_length` keeps the value out of the structure entirely.
+///   [example](#const-value-example)
+/// - `checksum = {ALGORITHM}` Only for unsigned integer fields. `into_bytes` writes the checksum of
+///   the covered bytes after every other field, and `verify_checksums` checks it. ALGORITHM is
+///   `"crc16_ccitt"`, `"sum8"`, `"xor8"` or a type implementing `BitfieldChecksum`, whose `Output`
+///   has to be the type of the field. [example](#checksum-example)
+/// - `over = {START}..{END}` The bytes a checksum covers, defaults to the bytes before the field.
+///   START and END are byte indexes or names of fields starting on a byte boundary, which stand for
+///   the byte the field starts in. Either can be left out, `"data.."` covers from `data` to the end.
+/// - `exponent_bits = {BITS}` Only for float fields with a `bit_length` smaller than their type. The
+///   amount of exponent bits kept, defaults to the exponent size of the type.
+///   [example](#reduced-float-example)
+/// - `rounding = {"nearest_even" or "toward_zero"}` How a reduced float drops mantissa bits, defaults
+///   to `nearest_even`.
+/// - `scale = {NUMBER}` and `offset = {NUMBER}` Only for `f32` and `f64` fields. The field is stored as
+///   an unsigned integer of `bit_length` bits where `value = raw * scale + offset`.
+///   [example](#scaled-example)
+/// - `q = "Qm.n"` Only for `f32` and `f64` fields. The field is stored as a signed fixed point number
+///   with m integer bits and n fraction bits, plus a sign bit. `"UQm.n"` is unsigned. The bit length
+///   defaults to the size of the format.
+/// - `bits = RANGE` Define the bit indexes yourself rather than let the proc macro figure it out, using
+///   a rust range, `bits = 3..9` or `bits = "3..9"`. Fields without `bits` start where the field
+///   declared before them ends. [example](#bit-range-example)
+///
+/// Number values can be integer literals or arithmetic on them, like `bit_length = 4 * 3`. Sizes can
+/// also name constants, see [sizes from constants](#sizes-from-constants-example).
+///
+/// Attributes that are unknown, misspelled, misplaced or given the wrong kind of value are errors, the
+/// error lists the valid attributes and suggests the closest one, or says where a misplaced one goes.
+/// ```compile_fail
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// // error: "default_endianess" is not a valid structure attribute, did you mean "default_endianness"?
+/// #[bondrewd(default_endianess = "be")]
+/// struct Typo {
+///     one: u16,
+/// }
+/// ```
+/// ```compile_fail
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// // error: enforce_bytes must be an integer literal or arithmetic on integer literals
+/// #[bondrewd(default_endianness = "be", enforce_bytes = "2")]
+/// struct WrongValue {
+///     one: u16,
+/// }
+/// ```
+/// ```compile_fail
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct FieldTypo {
+///     // error: "bit_lenght" is not a valid field attribute, did you mean "bit_length"?
+///     #[bondrewd(bit_lenght = 3)]
+///     one: u8,
+/// }
+/// ```
 /// 
 /// # Simple Example
 /// This example is on the front page for bondrewd-derive. Here i will be adding some asserts to show what
 /// to expect.
 /// I will be defining a data structure with 7 total bytes as:
 /// - A boolean field named one will be the first bit.
-/// - A floating point field named two will be the next 32 bits. floats must be full sized
-/// currently.
+/// - A floating point field named two will be the next 32 bits.
 /// - A signed integer field named three will be the next 14 bits.
 /// - An unsigned integer field named four will be the next 6 bits.
 /// - Because these fields do not add up to a power of 2 the last 3 bits will be unused.
@@ -571,7 +704,7 @@ use syn::{parse_macro_input, DeriveInput};
 /// # Endianness Examples
 /// There are 2 ways to define endianess of fields that require endianness (multi-byte numbers, char, ...)
 /// - Default endianness which will give provided endianness to all fields that require endianness but
-/// do not have it defined.
+///   do not have it defined.
 /// - Per field endianess which defines the endianness of a particular field.
 /// 
 /// Default endianness and per fields endianness can also be used in the same struct
@@ -620,10 +753,33 @@ use syn::{parse_macro_input, DeriveInput};
 ///     assert_eq!(test.into_bytes(),[0b00000000, 0b00000101, 0b00000101, 0b00000000, 0b10000000]);
 /// }
 /// ```
+/// Native endianness (`"ne"` or `"native"`) uses the byte order of the target the code is compiled
+/// for, both orders are generated and `cfg(target_endian)` picks one.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "ne")]
+/// struct SimpleExample {
+///     one: u16,
+///     #[bondrewd(endianness = "be")]
+///     two: u16,
+/// }
+///
+/// fn main() {
+///     let test = SimpleExample {
+///         one: 5,
+///         two: 5,
+///     };
+///     let bytes = test.into_bytes();
+///     assert_eq!(bytes[0..2], 5u16.to_ne_bytes());
+///     assert_eq!(bytes[2..4], [0b00000000, 0b00000101]);
+/// }
+/// ```
 /// # Bitfield Struct as Field Examples
 /// Inner structs must implement the 
-/// [`Bitfields`](https://docs.rs/bondrewd/latest/bondrewd/trait.Bitfields.html) trait and be given the
-/// `struct_size = {BYTE_SIZE}, the BYTE_SIZE being the number of bytes in the outputs byte array or 
+/// [`Bitfields`](https://docs.rs/bondrewd/latest/bondrewd/trait.Bitfields.html) trait. Without
+/// attributes a field uses the BIT_SIZE and BYTE_SIZE of that implementation, or it can be given the
+/// `struct_size = {BYTE_SIZE}`, the BYTE_SIZE being the number of bytes in the outputs byte array or 
 /// value in the traits const BYTE_SIZE.
 /// ```
 /// // this struct uses 52 total bits which means the total BYTE_SIZE is 7.
@@ -648,6 +804,8 @@ use syn::{parse_macro_input, DeriveInput};
 ///     // structs can also be used in arrays.
 ///     #[bondrewd(struct_size = 7)]
 ///     two: [Simple; 2],
+///     // the size is taken from the Bitfields implementation of Simple.
+///     three: Simple,
 /// }
 /// ```
 /// We can also trim the struct to a bit length, this can be very useful for struct that do not use the
@@ -684,12 +842,80 @@ use syn::{parse_macro_input, DeriveInput};
 ///     assert_eq!(SimpleWithStruct::BYTE_SIZE, Simple::BYTE_SIZE);
 /// }
 /// ```
+/// Small structs are packed next to each other, a struct field without a `struct_size` takes the
+/// BIT_SIZE bits of its `Bitfields` implementation, so a 3 bit struct only takes 3 bits and the next
+/// field starts right after it. Arrays of structs do the same for every element. A `bit_length` or
+/// `element_bit_length` overrides it.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields, Clone, PartialEq, Debug)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Flags {
+///     ack: bool,
+///     retry: bool,
+///     last: bool,
+/// }
+///
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Header {
+///     flags: Flags,
+///     history: [Flags; 3],
+///     #[bondrewd(bit_length = 4)]
+///     kind: u8,
+/// }
+///
+/// fn main() {
+///     assert_eq!(Header::BIT_SIZE, 16);
+///     let bytes = [0b1001_0010, 0b1001_0101];
+///     assert_eq!(
+///         Header::read_flags(&bytes),
+///         Flags { ack: true, retry: false, last: false }
+///     );
+///     assert_eq!(Header::read_kind(&bytes), 0b0101);
+/// }
+/// ```
+/// A field given a `bit_length` and no `struct_size` is taken to be a struct with a size of
+/// `bit_length` rounded up to whole bytes. Sizes are checked when compiling, a size that does not
+/// match the inner struct's `Bitfields` implementation or a `bit_length` shorter than its BIT_SIZE is
+/// an error rather than a struct that loses bits.
+/// ```compile_fail
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Simple {
+///     #[bondrewd(bit_length = 2)]
+///     one: u8,
+///     #[bondrewd(bit_length = 2)]
+///     two: u8,
+/// }
+///
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct SimpleWithStruct {
+///     // Simple uses 4 bits.
+///     #[bondrewd(bit_length = 3)]
+///     one: Simple,
+/// }
+/// ```
+/// The sizes taken from a `Bitfields` implementation are constants next to the generated impls,
+/// which can not use the generic parameters of the structure, so a field of a generic type needs a
+/// `struct_size` or `bit_length`.
+/// ```compile_fail
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Tagged<T> {
+///     tag: u8,
+///     inner: T,
+/// }
+/// ```
 /// # Bitfield Array Examples
 /// There are 2 types of arrays in Bondrewd:
 /// - Block Arrays are "bit chucks" that define a total-used-bits amount and will drop bits starting
-/// at the lowest index.
+///   at the lowest index.
 /// - Element Arrays treat each element of the array as its own field and requires a per element
-/// bit-length.
+///   bit-length.
 /// ```
 /// use bondrewd::*;
 /// #[derive(Bitfields)]
@@ -734,10 +960,10 @@ use syn::{parse_macro_input, DeriveInput};
 /// ```
 /// Structures and Enums can also be used in arrays but there are some extra things to consider.
 /// - If bit_length of the structs or enums needs to be smaller than the output of either into_bytes or 
-/// into_primitive then it is recommended to use element arrays.
+///   into_primitive then it is recommended to use element arrays.
 /// - Block Arrays, in my opinion, shouldn't be used for Structs or Enums. because in the below example
-/// if the compressed_structures field was to use `block_bit_length = 104` the array would use
-/// 48 bits for index 0 and 56 bits for index 1. 
+///   if the compressed_structures field was to use `block_bit_length = 104` the array would use
+///   48 bits for index 0 and 56 bits for index 1. 
 /// ```
 /// // this struct uses 52 total bits which means the total
 /// // BYTE_SIZE is 7.
@@ -880,10 +1106,10 @@ use syn::{parse_macro_input, DeriveInput};
 /// ```
 /// Here im going to compare the example above to the closest alternative using a reserve field:
 /// - FilledBytes only has 2 field, so only 2 fields are required for instantiation, where as ReservedBytes
-/// still needs a value for the reserve field despite from/into bytes not using the value anyway.
+///   still needs a value for the reserve field despite from/into bytes not using the value anyway.
 /// - ReservedBytes has 2 extra functions that FilledBytes does not, `write_reserve` and `read_reserve`.
 /// - One more thing to consider is reserve fields are currently confined to primitives, if more than 128
-/// reserve bits are required at the end, fill_bytes is the only supported way of doing this.
+///   reserve bits are required at the end, fill_bytes is the only supported way of doing this.
 /// ```
 /// use bondrewd::*;
 /// #[derive(Bitfields)]
@@ -1085,9 +1311,621 @@ use syn::{parse_macro_input, DeriveInput};
 ///     two: [Simple; 3],
 /// }
 /// ```
+///
+/// # Enum With Data Example
+/// Enums with fields can derive Bitfields when given an `id_bit_length`. Each variant starts with
+/// its id followed by its fields, which take the same attributes as struct fields. Ids are assigned
+/// like the BitfieldEnum derive does: literal discriminants keep their value and the other variants
+/// take the lowest values left. Ids that do not match a variant decode as the variant marked with
+/// `#[bondrewd(invalid)]`, or the last variant. The byte size is the size of the largest variant.
+/// Instead of per field functions only `read_variant_id` is generated.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields, PartialEq, Debug)]
+/// #[bondrewd(default_endianness = "be", id_bit_length = 2)]
+/// enum Frame {
+///     Telemetry {
+///         #[bondrewd(bit_length = 6)]
+///         channel: u8,
+///         value: u16,
+///     },
+///     Command {
+///         opcode: u8,
+///     },
+///     #[bondrewd(invalid)]
+///     Unknown,
+/// }
+///
+/// fn main() {
+///     assert_eq!(3, Frame::BYTE_SIZE);
+///     let bytes = Frame::Command { opcode: 0xAB }.into_bytes();
+///     assert_eq!([0b0110_1010, 0b1100_0000, 0], bytes);
+///     assert_eq!(1, Frame::read_variant_id(&bytes));
+///     assert_eq!(Frame::Command { opcode: 0xAB }, Frame::from_bytes(bytes));
+///     assert_eq!(Frame::Unknown, Frame::from_bytes([0b1100_0000, 0, 0]));
+/// }
+/// ```
+///
+/// # View Example
+/// Views borrow any buffer implementing `AsRef<[u8]>` (and `AsMut<[u8]>` for `ViewMut`) and only
+/// decode the fields that get read. Nested structures that start on a byte and use all of their bits
+/// are returned as views too, `read_{field}_mut` gives a mutable one from a `ViewMut`. Other nested
+/// structures are copied out like the `read_{field}` functions do.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields, PartialEq, Debug)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Header {
+///     #[bondrewd(bit_length = 4)]
+///     version: u8,
+///     #[bondrewd(bit_length = 12)]
+///     length: u16,
+/// }
+///
+/// #[derive(Bitfields, PartialEq, Debug)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Frame {
+///     #[bondrewd(struct_size = 2)]
+///     header: Header,
+///     checksum: u8,
+/// }
+///
+/// fn main() {
+///     let mut bytes = [0x10, 0x20, 0xFF];
+///     let view = Frame::view(&bytes);
+///     assert_eq!(0x020, view.read_header().read_length());
+///     assert_eq!(0xFF, view.read_checksum());
+///
+///     let mut view = Frame::view_mut(&mut bytes);
+///     view.read_header_mut().write_version(2);
+///     view.write_checksum(0);
+///     assert_eq!(2, view.to_owned().header.version);
+///     assert_eq!([0x20, 0x20, 0x00], bytes);
+///
+///     // views can also wrap slices and vectors, which must hold at least BYTE_SIZE bytes.
+///     let view = Frame::view_slice(vec![0x10, 0x20, 0xFF, 0x00]).unwrap();
+///     assert_eq!(1, view.read_header().read_version());
+///     assert!(Frame::view_slice(&bytes[..2]).is_err());
+/// }
+/// ```
+///
+/// # Paths Example
+/// `paths()` returns a `{struct_name}Paths` with a function for each field. Nested structure fields
+/// return the paths of the nested structure, other fields return a `FieldPath` which reads or writes
+/// the field in the bytes of the outermost structure. The rest of the fields along the path are never
+/// decoded, but paths do not read the field straight from the outermost bytes: every nested
+/// structure along the path copies its bytes out of the bytes of the structure holding it, so a
+/// field three structures deep copies three byte arrays to be read, and writing copies each of them
+/// back in on the way out. For fields read or written often, [views](#view-example) of byte aligned
+/// nested structures work on the buffer in place. Paths have `read` and `write` functions of their
+/// own for the whole structure, so fields can not be named `read` or `write`.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields, PartialEq, Debug)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Status {
+///     mtm1: bool,
+///     mtm2: bool,
+///     #[bondrewd(bit_length = 6)]
+///     temperature: u8,
+/// }
+///
+/// #[derive(Bitfields, PartialEq, Debug)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Magnetometers {
+///     #[bondrewd(bit_length = 4)]
+///     count: u8,
+///     #[bondrewd(struct_size = 1)]
+///     status: Status,
+///     #[bondrewd(bit_length = 4)]
+///     mode: u8,
+/// }
+///
+/// fn main() {
+///     let mut bytes = [0b0010_1000, 0b0111_0011];
+///     assert!(Magnetometers::paths().status().mtm1().read(&bytes));
+///     assert_eq!(7, Magnetometers::paths().status().temperature().read(&bytes));
+///
+///     Magnetometers::paths().status().mtm2().write(&mut bytes, true);
+///     assert_eq!([0b0010_1100, 0b0111_0011], bytes);
+/// }
+/// ```
+///
+/// # Generics Example
+/// Structures can be generic. A type parameter used as a nested structure or enum field gets the
+/// bounds the generated code needs (`Bitfields<{struct_size}>` or `BitfieldEnum`), other parameters
+/// are left alone. A const parameter can be an array length if it has a literal default, the
+/// structure is sized with the default so the impls are only for that value. Field types can be
+/// written with their module path or generic arguments.
+/// ```
+/// use bondrewd::*;
+/// mod sensors {
+///     use bondrewd::*;
+///     #[derive(Bitfields, PartialEq, Debug)]
+///     #[bondrewd(default_endianness = "be")]
+///     pub struct Reading {
+///         #[bondrewd(bit_length = 3)]
+///         pub channel: u8,
+///         #[bondrewd(bit_length = 5)]
+///         pub value: u8,
+///     }
+/// }
+///
+/// #[derive(Bitfields, PartialEq, Debug)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Tagged<T, const N: usize = 2> {
+///     tag: u8,
+///     #[bondrewd(struct_size = 1)]
+///     inner: T,
+///     #[bondrewd(element_bit_length = 4)]
+///     nibbles: [u8; N],
+/// }
+///
+/// fn main() {
+///     let tagged: Tagged<sensors::Reading> = Tagged {
+///         tag: 1,
+///         inner: sensors::Reading { channel: 2, value: 3 },
+///         nibbles: [4, 5],
+///     };
+///     assert_eq!(3, Tagged::<sensors::Reading>::BYTE_SIZE);
+///     assert_eq!([1, 0b0100_0011, 0x45], tagged.into_bytes());
+/// }
+/// ```
+///
+/// # Reduced Float Example
+/// A float with a `bit_length` smaller than its type keeps the sign bit, `exponent_bits` exponent
+/// bits and uses the rest for the mantissa. The exponent is rebiased, so a f32 with 5 exponent bits
+/// in 16 bits is stored exactly like an IEEE half. Without `exponent_bits` only the mantissa is
+/// shortened. Values are rounded to nearest with ties to even unless `rounding = "toward_zero"` is
+/// given, and values too large for the smaller exponent become infinity (or the largest finite value
+/// when rounding toward zero).
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Telemetry {
+///     // the top 24 bits of a f32.
+///     #[bondrewd(bit_length = 24, rounding = "toward_zero")]
+///     pressure: f32,
+///     #[bondrewd(bit_length = 16, exponent_bits = 5)]
+///     temperature: f32,
+/// }
+///
+/// fn main() {
+///     let bytes = Telemetry {
+///         pressure: 1013.25,
+///         temperature: -2.5,
+///     }
+///     .into_bytes();
+///     assert_eq!(bytes, [0x44, 0x7D, 0x50, 0xC1, 0x00]);
+///     assert_eq!(Telemetry::read_pressure(&bytes), 1013.25);
+///     assert_eq!(Telemetry::read_temperature(&bytes), -2.5);
+/// }
+/// ```
+///
+/// # Scaled Example
+/// Sensors often report a raw integer that is converted with a linear formula. A float field with
+/// `scale` and `offset` is stored as that raw integer, rounded to nearest. Fixed point numbers use
+/// `q` instead. Values the raw integer can not hold are saturated to the smallest or largest raw
+/// value, and nan is stored as 0.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Sensor {
+///     // engineering = raw * 0.01 - 40
+///     #[bondrewd(bit_length = 14, scale = 0.01, offset = -40.0)]
+///     temperature: f32,
+///     // a sign bit, 1 integer bit and 14 fraction bits.
+///     #[bondrewd(q = "Q1.14")]
+///     gain: f32,
+///     #[bondrewd(bit_length = 2)]
+///     flags: u8,
+/// }
+///
+/// fn main() {
+///     let bytes = Sensor {
+///         temperature: 25.0,
+///         gain: -0.5,
+///         flags: 0,
+///     }
+///     .into_bytes();
+///     // (25 + 40) / 0.01 = 6500
+///     assert_eq!(bytes, [0x65, 0x93, 0x80, 0x00]);
+///     assert_eq!(Sensor::read_gain(&bytes), -0.5);
+///     // too hot for 14 bits.
+///     let mut bytes = bytes;
+///     Sensor::write_temperature(&mut bytes, 500.0);
+///     assert_eq!(Sensor::read_temperature(&bytes), (16383.0f64 * 0.01 - 40.0) as f32);
+/// }
+/// ```
+///
+/// # Runtime Endianness Example
+/// Some devices report their byte order in a header. With `runtime_endianness` one definition gets
+/// functions for both orders, fields without their own `endianness` use the order of the function.
+/// Fields with an `endianness` attribute and nested structures keep their byte order. The
+/// `default_endianness` is still used by the `Bitfields` implementation and the plain read and write
+/// functions.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields, Debug, PartialEq)]
+/// #[bondrewd(default_endianness = "be", runtime_endianness)]
+/// struct Status {
+///     #[bondrewd(endianness = "be")]
+///     magic: u16,
+///     count: u32,
+/// }
+///
+/// fn main() {
+///     let status = Status {
+///         magic: 0xCAFE,
+///         count: 5,
+///     };
+///     let le = status.into_bytes_le();
+///     assert_eq!(le, [0xCA, 0xFE, 5, 0, 0, 0]);
+///     assert_eq!(Status::read_count_le(&le), 5);
+///     assert_eq!(Status::read_count_be(&le), 0x0500_0000);
+///     let decoded = if le[2] != 0 {
+///         Status::from_bytes_le(le)
+///     } else {
+///         Status::from_bytes_be(le)
+///     };
+///     assert_eq!(decoded.count, 5);
+/// }
+/// ```
+///
+/// # Length From Example
+/// A CCSDS space packet header stores the length of the data following it minus 1. The header is
+/// packed like any other structure, `decode` reads it then takes the data from the bytes after it.
+/// `encode_into` writes `packet_data_length` from the length of `data`, so it can not disagree with
+/// the data actually written.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields, PartialEq, Debug)]
+/// #[bondrewd(default_endianness = "be", enforce_bytes = 6)]
+/// struct SpacePacket {
+///     #[bondrewd(bit_length = 3)]
+///     version: u8,
+///     packet_type: bool,
+///     secondary_header: bool,
+///     #[bondrewd(bit_length = 11)]
+///     apid: u16,
+///     #[bondrewd(bit_length = 2)]
+///     sequence_flags: u8,
+///     #[bondrewd(bit_length = 14)]
+///     sequence_count: u16,
+///     packet_data_length: u16,
+///     #[bondrewd(length_from = "packet_data_length", offset = 1)]
+///     data: Vec<u8>,
+/// }
+///
+/// fn main() {
+///     let bytes = [0, 0x11, 0b1100_0000, 0, 0, 1, 0xAB, 0xCD, 0xFF];
+///     let (packet, used) = SpacePacket::decode(&bytes).unwrap();
+///     assert_eq!(8, used);
+///     assert_eq!(0x11, packet.apid);
+///     assert_eq!(vec![0xAB, 0xCD], packet.data);
+///     let mut output = [0u8; 8];
+///     assert_eq!(Ok(8), packet.encode_into(&mut output));
+///     assert_eq!(bytes[..8], output);
+///     assert_eq!(
+///         Err(BitfieldDynError::Slice(4, 6)),
+///         SpacePacket::decode(&bytes[..4])
+///     );
+/// }
+/// ```
+///
+/// # Bit Stream Example
+/// Every [`Bitfields`] structure can be read and written at any bit offset with `read_from_bits` and
+/// `write_to_bits`, which only touch its `BIT_SIZE` bits. `BitReader` and `BitWriter` keep a bit
+/// cursor so records are packed back to back without padding. Structures using `reverse` keep their
+/// bits in the order they have before the bytes get reversed.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields, Debug, PartialEq)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Sample {
+///     #[bondrewd(bit_length = 3)]
+///     channel: u8,
+///     #[bondrewd(bit_length = 9)]
+///     value: u16,
+/// }
+///
+/// fn main() -> Result<(), BitfieldSliceError> {
+///     // two 12 bit samples in 3 bytes.
+///     let mut bytes = [0u8; 3];
+///     let mut writer = BitWriter::new(&mut bytes);
+///     writer.write(Sample { channel: 2, value: 1 })?;
+///     writer.write(Sample { channel: 7, value: 3 })?;
+///     assert_eq!(3, writer.bytes_used());
+///     assert_eq!([0b0100_0000, 0b0001_1110, 0b0000_0011], bytes);
+///
+///     let mut reader = BitReader::new(&bytes);
+///     let first: Sample = reader.read()?;
+///     assert_eq!(Sample { channel: 2, value: 1 }, first);
+///     assert_eq!(Sample { channel: 7, value: 3 }, Sample::read_from_bits(&bytes, 12)?);
+///     Ok(())
+/// }
+/// ```
+///
+/// # Bit Range Example
+/// `bits` places a field at the given bit indexes, so fields can be declared in any order. Fields
+/// can not overlap, and bits before the end of the last field have to belong to a field.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Ranges {
+///     #[bondrewd(bits = 8..16)]
+///     id: u8,
+///     // starts where id ends.
+///     #[bondrewd(bit_length = 4)]
+///     flags: u8,
+///     #[bondrewd(bits = 0..8)]
+///     length: u8,
+/// }
+///
+/// fn main() {
+///     let bytes = Ranges {
+///         id: 0x12,
+///         flags: 0xF,
+///         length: 0x34,
+///     }
+///     .into_bytes();
+///     assert_eq!([0x34, 0x12, 0xF0], bytes);
+/// }
+/// ```
+/// Overlapping fields are an error naming both fields.
+/// ```compile_fail
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Overlap {
+///     #[bondrewd(bits = 0..8)]
+///     one: u8,
+///     #[bondrewd(bits = 4..12)]
+///     two: u8,
+/// }
+/// ```
+/// So are unused bits, unless the structure has `allow_gaps`.
+/// ```compile_fail
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Gap {
+///     #[bondrewd(bits = 0..4)]
+///     one: u8,
+///     #[bondrewd(bits = 8..12)]
+///     two: u8,
+/// }
+/// ```
+/// With `allow_gaps` the unused bits are written as zeros and ignored by `from_bytes`, and
+/// `try_from_bytes` rejects them when they are not zero like it does for reserve fields.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be", allow_gaps)]
+/// struct Gap {
+///     #[bondrewd(bits = 0..4)]
+///     one: u8,
+///     #[bondrewd(bits = 8..12)]
+///     two: u8,
+/// }
+///
+/// fn main() {
+///     assert_eq!(2, Gap::BYTE_SIZE);
+///     assert_eq!([0xA0, 0xB0], Gap { one: 0xA, two: 0xB }.into_bytes());
+///     assert_eq!(0xB, Gap::from_bytes([0xAF, 0xBF]).two);
+/// }
+/// ```
+///
+/// # Overlay Example
+/// A register whose 16 bits are either one number or a channel and a ready flag depending on the
+/// mode. Overlays only read and write bits, the fields they overlay own them in `from_bytes` and
+/// `into_bytes`, so they do not count toward the size of the structure or move the fields after them.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Register {
+///     mode: u8,
+///     raw: u16,
+///     #[bondrewd(alias_of = "raw")]
+///     signed: i16,
+///     #[bondrewd(overlay, bits = 8..12)]
+///     channel: u8,
+///     #[bondrewd(overlay, bits = 23..24)]
+///     ready: bool,
+/// }
+///
+/// fn main() {
+///     assert_eq!(3, Register::BYTE_SIZE);
+///     let mut bytes = [1, 0xFF, 0xFE];
+///     assert_eq!(-2, Register::read_signed(&bytes));
+///     assert_eq!(0xF, Register::read_channel(&bytes));
+///     Register::write_ready(&mut bytes, true);
+///     assert_eq!(0xFFFF, Register::read_raw(&bytes));
+///     assert_eq!(0, Register::from_bytes(bytes).signed);
+/// }
+/// ```
+///
+/// # Const Value Example
+/// A frame starting with a sync marker and version bits that can not be set wrong. The version is a
+/// `()` field, its read and write functions use the smallest unsigned integer holding its bits.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Frame {
+///     #[bondrewd(const_value = 0x1ACFFC1D)]
+///     sync: u32,
+///     #[bondrewd(const_value = 0b01, bit_length = 2)]
+///     version: (),
+///     #[bondrewd(bit_length = 6)]
+///     id: u8,
+/// }
+///
+/// fn main() {
+///     let bytes = Frame { sync: 0, version: (), id: 5 }.into_bytes();
+///     assert_eq!([0x1A, 0xCF, 0xFC, 0x1D, 0b0100_0101], bytes);
+///     assert_eq!(0b01, Frame::read_version(&bytes));
+///     assert_eq!(0x1ACFFC1D, Frame::from_bytes([0; 5]).sync);
+///     assert_eq!(
+///         Err(BitfieldDecodeError {
+///             field: "sync".into(),
+///             bits: 0..32,
+///             kind: BitfieldDecodeErrorKind::ConstMismatch {
+///                 expected: 0x1ACFFC1D,
+///                 actual: 0,
+///             },
+///         }),
+///         Frame::try_from_bytes([0; 5]).map(|frame| frame.id)
+///     );
+/// }
+/// ```
+/// A value that does not fit in the bits of the field fails to build.
+/// ```compile_fail
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Frame {
+///     #[bondrewd(const_value = 0b100, bit_length = 2)]
+///     version: (),
+///     #[bondrewd(bit_length = 6)]
+///     id: u8,
+/// }
+/// # fn main() { let _ = Frame::from_bytes([0]); }
+/// ```
+///
+/// # Checksum Example
+/// A frame ending in a CRC over every byte before it, and a single byte sum over the header.
+/// Checksums are filled in order, one covering another checksum is filled after it. Checksum fields
+/// can not be used in `reverse` structures, enum variants or along with `length_from`, and
+/// `try_from_bytes` does not check them.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Frame {
+///     id: u8,
+///     length: u8,
+///     #[bondrewd(checksum = "sum8", over = "id..header_sum")]
+///     header_sum: u8,
+///     payload: [u8; 2],
+///     #[bondrewd(checksum = "crc16_ccitt")]
+///     crc: u16,
+/// }
+///
+/// fn main() {
+///     let frame = Frame {
+///         id: 1,
+///         length: 2,
+///         header_sum: 0,
+///         payload: [0xAB, 0xCD],
+///         crc: 0,
+///     };
+///     let mut bytes = frame.into_bytes();
+///     assert_eq!(3, bytes[2]);
+///     assert_eq!(Crc16Ccitt::checksum(&bytes[..5]), Frame::read_crc(&bytes));
+///     assert!(Frame::verify_checksums(&bytes).is_ok());
+///     bytes[3] = 0;
+///     assert_eq!("crc", Frame::verify_checksums(&bytes).unwrap_err().field);
+/// }
+/// ```
+/// A checksum can not cover its own bytes.
+/// ```compile_fail
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Frame {
+///     id: u8,
+///     #[bondrewd(checksum = "sum8", over = 0..2)]
+///     sum: u8,
+/// }
+/// # fn main() { let _ = Frame::from_bytes([0; 2]); }
+/// ```
+///
+/// # Sizes From Constants Example
+/// `bit_length`, `byte_length`, `bits`, `element_bit_length`, `element_byte_length`,
+/// `block_bit_length`, `block_byte_length`, `struct_size`, `enforce_bits`, `enforce_bytes` and
+/// `fill_bytes` can name constants or call `const fn`s, and array lengths can be constants. Those
+/// values are only known to the compiler, so the positions of the fields are generated as constant
+/// expressions and the checks bondrewd makes on literal sizes become `const` assertions, which fail
+/// the build with the same messages. Every other attribute works the same with sizes from
+/// constants, and so do enums with data.
+/// ```
+/// use bondrewd::*;
+/// const ID_BITS: usize = 11;
+/// const HEADER_BYTES: usize = 4;
+/// const SAMPLES: usize = 3;
+///
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be", enforce_bytes = HEADER_BYTES)]
+/// struct Header {
+///     #[bondrewd(bit_length = ID_BITS)]
+///     id: u16,
+///     #[bondrewd(bit_length = HEADER_BYTES * 8 - ID_BITS - SAMPLES * 4)]
+///     flags: u16,
+///     #[bondrewd(element_bit_length = 4)]
+///     samples: [u8; SAMPLES],
+/// }
+///
+/// fn main() {
+///     assert_eq!(HEADER_BYTES, Header::BYTE_SIZE);
+///     let bytes = Header {
+///         id: 0x7FF,
+///         flags: 0,
+///         samples: [1, 2, 3],
+///     }
+///     .into_bytes();
+///     assert_eq!([0xFF, 0xE0, 0x01, 0x23], bytes);
+/// }
+/// ```
+/// A size that does not fit the type fails when the constant is evaluated.
+/// ```compile_fail
+/// use bondrewd::*;
+/// const NINE: usize = 9;
+///
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct TooLong {
+///     #[bondrewd(bit_length = NINE)]
+///     one: u8,
+/// }
+/// ```
 #[proc_macro_derive(Bitfields, attributes(bondrewd,))]
 pub fn derive_bitfields(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    if let syn::Data::Enum(_) = input.data {
+        return derive_bitfields_variants(&input);
+    }
+    let slice_fns: bool;
+    #[cfg(not(feature = "slice_fns"))]
+    {
+        slice_fns = false;
+    }
+    #[cfg(feature = "slice_fns")]
+    {
+        slice_fns = true;
+    }
+    let try_fns: bool;
+    #[cfg(not(feature = "try_fns"))]
+    {
+        try_fns = false;
+    }
+    #[cfg(feature = "try_fns")]
+    {
+        try_fns = true;
+    }
+    let hex;
+    #[cfg(feature = "hex_fns")]
+    {
+        hex = true;
+    }
+    #[cfg(not(feature = "hex_fns"))]
+    {
+        hex = false;
+    }
     // parse the input into a StructInfo which contains all the information we
     // along with some helpful structures to generate our Bitfield code.
     let struct_info = match StructInfo::parse(&input) {
@@ -1097,28 +1935,23 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
         }
     };
     // println!("{:?}", struct_info);
-    // get the struct size and name so we can use them in a quote.
+    // get the struct size and name so we can use them in a quote. sizes from constants are
+    // named constants, `size_consts` declares them.
     let struct_size = struct_info.total_bytes();
+    let size_consts = struct_info.size_consts_quote();
     let struct_name = format_ident!("{}", struct_info.name);
+    // generic structures use their full type with the generic arguments in the impls.
+    let struct_type = struct_info.type_quote();
+    let generics = struct_info.impl_generics(|_| None);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
 
-    // get a list of all fields from_bytes logic which gets there bytes from an array called
-    // input_byte_buffer.
-    let slice_fns: bool;
-    #[cfg(not(feature = "slice_fns"))]
-    {
-        slice_fns = false;
-    }
-    #[cfg(feature = "slice_fns")]
-    {
-        slice_fns = true;
-    }
     // get a list of all fields into_bytes logic which puts there bytes into an array called
     // output_byte_buffer.
     let fields_into_bytes = match create_into_bytes_field_quotes(&struct_info, slice_fns) {
         Ok(ftb) => ftb,
         Err(err) => return TokenStream::from(err.to_compile_error()),
     };
-    let fields_from_bytes = match create_from_bytes_field_quotes(&struct_info, slice_fns) {
+    let fields_from_bytes = match create_from_bytes_field_quotes(&struct_info, slice_fns, try_fns) {
         Ok(ffb) => ffb,
         Err(err) => return TokenStream::from(err.to_compile_error()),
     };
@@ -1134,6 +1967,15 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
     }
 
     let from_bytes_quote = fields_from_bytes.from_bytes_fn;
+    let try_from_bytes_quote = if let Some(try_from_bytes_fn) = fields_from_bytes.try_from_bytes_fn {
+        quote! {
+            impl #impl_generics TryBitfields<#struct_size> for #struct_type #where_clause {
+                #try_from_bytes_fn
+            }
+        }
+    } else {
+        quote! {}
+    };
     let mut peek_quotes = fields_from_bytes.peek_field_fns;
 
     if let Some(peek_slice_quote) = fields_from_bytes.peek_slice_field_fns {
@@ -1163,68 +2005,29 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    let checksums_quote = match structs::checksum::verify_checksums_quote(
+        &struct_info,
+        &format_ident!("verify_checksums"),
+    ) {
+        Ok(checksums_quote) => checksums_quote,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+
     let getter_setters_quotes = quote! {
-        impl #struct_name {
+        impl #impl_generics #struct_type #where_clause {
             #peek_quotes
             #set_quotes
             #setters_quote
+            #checksums_quote
         }
     };
-    let hex;
-    #[cfg(feature = "hex_fns")]
-    {
-        hex = true;
-    }
-    #[cfg(not(feature = "hex_fns"))]
-    {
-        hex = false;
-    }
-    let hex_size = struct_size * 2;
     let hex_fns_quote = if hex {
-        quote! {
-            impl BitfieldHex<#hex_size> for #struct_name {
-                fn from_hex(hex: [u8;#hex_size]) -> Result<Self, BitfieldHexError> {
-                    let bytes: [u8; #struct_size] = [0;#struct_size];
-                    let mut bytes: [u8; Self::BYTE_SIZE] = [0;Self::BYTE_SIZE];
-                    for i in 0usize..#struct_size {
-                        let index = i * 2;
-                        let index2 = index + 1;
-                        let decode_nibble = |c, c_i| match c {
-                            b'A'..=b'F' => Ok(c - b'A' + 10u8),
-                            b'a'..=b'f' => Ok(c - b'a' + 10u8),
-                            b'0'..=b'9' => Ok(c - b'0'),
-                            _ => return Err(BitfieldHexError(
-                                c as char,
-                                c_i,
-                            )),
-                        };
-                        bytes[i] = ((decode_nibble(hex[index], index)? & 0b00001111) << 4) | decode_nibble(hex[index2], index2)?;
-                    }
-                    Ok(Self::from_bytes(bytes))
-
-                }
-
-                fn into_hex_upper(self) -> [u8;#hex_size] {
-                    let bytes = self.into_bytes();
-                    let mut output: [u8;#hex_size] = [0; #hex_size];
-                    for (i, byte) in (0..#hex_size).step_by(2).zip(bytes) {
-                        output[i] = (Self::UPPERS[((byte & 0b11110000) >> 4) as usize]);
-                        output[i + 1] = (Self::UPPERS[(byte & 0b00001111) as usize]);
-                    }
-                    output
-                }
-
-                fn into_hex_lower(self) -> [u8;#hex_size] {
-                    let bytes = self.into_bytes();
-                    let mut output: [u8;#hex_size] = [0; #hex_size];
-                    for (i, byte) in (0..#hex_size).step_by(2).zip(bytes) {
-                        output[i] = (Self::LOWERS[((byte & 0b11110000) >> 4) as usize]);
-                        output[i + 1] = (Self::LOWERS[(byte & 0b00001111) as usize]);
-                    }
-                    output
-                }
-            }
-        }
+        hex_fns_quote(
+            &generics,
+            &struct_type,
+            &struct_size,
+            (struct_size.clone() * 2).const_arg(),
+        )
     } else {
         quote! {}
     };
@@ -1238,78 +2041,343 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
     // that buffer.
     // from_bytes is essentially the same minus a variable because input_byte_buffer is the input.
     // slap peek quotes inside a impl block at the end and we good to go
-    let to_bytes_quote = quote! {
-        impl Bitfields<#struct_size> for #struct_name {
-            const BIT_SIZE: usize = #bit_size;
-            #into_bytes_quote
-            #from_bytes_quote
+    let to_bytes_quote = if let Some(ref trailing) = struct_info.trailing {
+        // the size is not fixed so only the header gets read and write functions.
+        let dyn_fns: bool;
+        #[cfg(not(feature = "dyn_fns"))]
+        {
+            dyn_fns = false;
+        }
+        #[cfg(feature = "dyn_fns")]
+        {
+            dyn_fns = true;
+        }
+        if !dyn_fns {
+            return TokenStream::from(
+                syn::Error::new(
+                    trailing.ident.span(),
+                    "length_from fields require the dyn_fns feature",
+                )
+                .to_compile_error(),
+            );
+        }
+        if struct_info.runtime_endianness {
+            return TokenStream::from(
+                syn::Error::new(
+                    trailing.ident.span(),
+                    "runtime_endianness can not be used along with a length_from field",
+                )
+                .to_compile_error(),
+            );
+        }
+        if struct_info.serde_bytes {
+            return TokenStream::from(
+                syn::Error::new(
+                    trailing.ident.span(),
+                    "serde_bytes can not be used along with a length_from field",
+                )
+                .to_compile_error(),
+            );
+        }
+        let dyn_quote = match structs::dynamic::create_dyn_quotes(&struct_info, trailing) {
+            Ok(dyn_quote) => dyn_quote,
+            Err(err) => return TokenStream::from(err.to_compile_error()),
+        };
+        quote! {
+            #size_consts
+            #dyn_quote
+            #getter_setters_quotes
+        }
+    } else {
+        let view_quote = match structs::view::create_view_quotes(&struct_info) {
+            Ok(view_quote) => view_quote,
+            Err(err) => return TokenStream::from(err.to_compile_error()),
+        };
+        let paths_quote = match structs::paths::create_paths_quotes(&struct_info) {
+            Ok(paths_quote) => paths_quote,
+            Err(err) => return TokenStream::from(err.to_compile_error()),
+        };
+        let byte_order_quote = if struct_info.runtime_endianness {
+            match structs::byte_order::create_byte_order_quotes(&input, &struct_info) {
+                Ok(byte_order_quote) => byte_order_quote,
+                Err(err) => return TokenStream::from(err.to_compile_error()),
+            }
+        } else {
+            quote! {}
+        };
+        let bits_fns_quote = if struct_info.flip {
+            structs::common::reversed_bits_fns_quote(&quote! {#struct_size})
+        } else {
+            quote! {}
+        };
+        let serde_quote = if struct_info.serde_bytes {
+            serde_bytes_quote(&generics, &struct_type)
+        } else {
+            quote! {}
+        };
+        quote! {
+            #size_consts
+            impl #impl_generics Bitfields<#struct_size> for #struct_type #where_clause {
+                const BIT_SIZE: usize = #bit_size;
+                #into_bytes_quote
+                #from_bytes_quote
+                #bits_fns_quote
+            }
+            #try_from_bytes_quote
+            #getter_setters_quotes
+            #hex_fns_quote
+            #view_quote
+            #paths_quote
+            #byte_order_quote
+            #serde_quote
         }
-        #getter_setters_quotes
-        #hex_fns_quote
     };
 
     if slice_fns {
-        let vis = struct_info.vis;
-        let checked_ident = format_ident!("{}Checked", &struct_name);
-        let checked_mut_ident = format_ident!("{}CheckedMut", &struct_name);
-        let unchecked_functions = fields_from_bytes.peek_slice_field_unchecked_fns;
-        let unchecked_mut_functions = fields_into_bytes.set_slice_field_unchecked_fns;
-        let to_bytes_quote = quote!{
+        // the checked structures carry the generics of the structure so nested generic fields can
+        // be read, the marker keeps unused parameters legal.
+        let (lifetime, checked_generics) = struct_info.checked_generics();
+        let checked_quote = checked_structs_quote(
+            &struct_info.vis,
+            &struct_name,
+            &struct_type,
+            &lifetime,
+            &checked_generics,
+            fields_from_bytes.peek_slice_field_unchecked_fns,
+            fields_into_bytes.set_slice_field_unchecked_fns,
+        );
+        TokenStream::from(quote! {
             #to_bytes_quote
-            #vis struct #checked_ident<'a> {
-                buffer: &'a [u8],
+            #checked_quote
+        })
+    }else{
+        TokenStream::from(to_bytes_quote)
+    }
+}
+
+/// `{name}Checked` and `{name}CheckedMut`, which read and write the fields of a slice that was
+/// checked to be long enough.
+fn checked_structs_quote(
+    vis: &syn::Visibility,
+    struct_name: &syn::Ident,
+    struct_type: &proc_macro2::TokenStream,
+    lifetime: &syn::Lifetime,
+    checked_generics: &syn::Generics,
+    unchecked_functions: Option<proc_macro2::TokenStream>,
+    unchecked_mut_functions: Option<proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let checked_ident = format_ident!("{}Checked", struct_name);
+    let checked_mut_ident = format_ident!("{}CheckedMut", struct_name);
+    let (checked_impl_generics, checked_ty_generics, checked_where_clause) =
+        checked_generics.split_for_impl();
+    quote!{
+        #vis struct #checked_ident #checked_impl_generics #checked_where_clause {
+            buffer: &#lifetime [u8],
+            _marker: core::marker::PhantomData<fn() -> #struct_type>,
+        }
+        impl #checked_impl_generics #checked_ident #checked_ty_generics #checked_where_clause {
+            #unchecked_functions
+            pub fn from_unchecked_slice(data: &#lifetime [u8]) -> Self {
+                Self{
+                    buffer: data,
+                    _marker: core::marker::PhantomData,
+                }
             }
-            impl<'a> #checked_ident<'a> {
-                #unchecked_functions
-                pub fn from_unchecked_slice(data: &'a [u8]) -> Self {
-                    Self{
-                        buffer: data
-                    }
+        }
+        #vis struct #checked_mut_ident #checked_impl_generics #checked_where_clause {
+            buffer: &#lifetime mut [u8],
+            _marker: core::marker::PhantomData<fn() -> #struct_type>,
+        }
+        impl #checked_impl_generics #checked_mut_ident #checked_ty_generics #checked_where_clause {
+            #unchecked_functions
+            #unchecked_mut_functions
+            pub fn from_unchecked_slice(data: &#lifetime mut [u8]) -> Self {
+                Self{
+                    buffer: data,
+                    _marker: core::marker::PhantomData,
                 }
             }
-            #vis struct #checked_mut_ident<'a> {
-                buffer: &'a mut [u8],
+        }
+    }
+}
+
+/// `BitfieldHex` for a structure of `struct_size` bytes, `hex_size` has to be twice that.
+fn hex_fns_quote(
+    generics: &syn::Generics,
+    struct_type: &proc_macro2::TokenStream,
+    struct_size: impl quote::ToTokens,
+    hex_size: impl quote::ToTokens,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    quote! {
+    impl #impl_generics BitfieldHex<#hex_size> for #struct_type #where_clause {
+        fn from_hex(hex: [u8;#hex_size]) -> Result<Self, BitfieldHexError> {
+            let mut bytes: [u8; #struct_size] = [0;#struct_size];
+            for i in 0usize..#struct_size {
+                let index = i * 2;
+                let index2 = index + 1;
+                let decode_nibble = |c, c_i| match c {
+                    b'A'..=b'F' => Ok(c - b'A' + 10u8),
+                    b'a'..=b'f' => Ok(c - b'a' + 10u8),
+                    b'0'..=b'9' => Ok(c - b'0'),
+                    _ => return Err(BitfieldHexError(
+                        c as char,
+                        c_i,
+                    )),
+                };
+                bytes[i] = ((decode_nibble(hex[index], index)? & 0b00001111) << 4) | decode_nibble(hex[index2], index2)?;
             }
-            impl<'a> #checked_mut_ident<'a> {
-                #unchecked_functions
-                #unchecked_mut_functions
-                pub fn from_unchecked_slice(data: &'a mut [u8]) -> Self {
-                    Self{
-                        buffer: data
-                    }
-                }
+            Ok(Self::from_bytes(bytes))
+
+        }
+
+        fn into_hex_upper(self) -> [u8;#hex_size] {
+            let bytes = self.into_bytes();
+            let mut output: [u8;#hex_size] = [0; #hex_size];
+            for (i, byte) in (0..#hex_size).step_by(2).zip(bytes) {
+                output[i] = (Self::UPPERS[((byte & 0b11110000) >> 4) as usize]);
+                output[i + 1] = (Self::UPPERS[(byte & 0b00001111) as usize]);
             }
-        };
-        TokenStream::from(to_bytes_quote)
-    }else{
-        TokenStream::from(to_bytes_quote)
+            output
+        }
+
+        fn into_hex_lower(self) -> [u8;#hex_size] {
+            let bytes = self.into_bytes();
+            let mut output: [u8;#hex_size] = [0; #hex_size];
+            for (i, byte) in (0..#hex_size).step_by(2).zip(bytes) {
+                output[i] = (Self::LOWERS[((byte & 0b11110000) >> 4) as usize]);
+                output[i + 1] = (Self::LOWERS[(byte & 0b00001111) as usize]);
+            }
+            output
+        }
+    }
+    }
+}
+
+/// `Serialize` and `Deserialize` through `bondrewd::packed_serde::as_bytes`, for `#[bondrewd(serde_bytes)]`.
+fn serde_bytes_quote(
+    generics: &syn::Generics,
+    struct_type: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, syn::parse_quote! {'de});
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+    quote! {
+        impl #impl_generics bondrewd::packed_serde::Serialize for #struct_type #where_clause {
+            fn serialize<S: bondrewd::packed_serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                bondrewd::packed_serde::as_bytes::serialize(self, serializer)
+            }
+        }
+        impl #de_impl_generics bondrewd::packed_serde::Deserialize<'de> for #struct_type #where_clause {
+            fn deserialize<D: bondrewd::packed_serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                bondrewd::packed_serde::as_bytes::deserialize(deserializer)
+            }
+        }
     }
+}
 
-    
+/// Bitfields for enums with data, each variant starts with its id followed by its fields.
+fn derive_bitfields_variants(input: &DeriveInput) -> TokenStream {
+    let variants_info = match VariantsInfo::parse(input) {
+        Ok(parsed_enum) => parsed_enum,
+        Err(err) => {
+            return TokenStream::from(err.to_compile_error());
+        }
+    };
+    let try_fns: bool;
+    #[cfg(not(feature = "try_fns"))]
+    {
+        try_fns = false;
+    }
+    #[cfg(feature = "try_fns")]
+    {
+        try_fns = true;
+    }
+    let quotes = match create_variants_quotes(&variants_info, try_fns) {
+        Ok(q) => q,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let struct_size = variants_info.total_bytes();
+    let bit_size = variants_info.total_bits();
+    let size_consts = variants_info.size_consts_quote();
+    let enum_name = &variants_info.name;
+    let into_bytes_quote = quotes.into_bytes_fn;
+    let from_bytes_quote = quotes.from_bytes_fn;
+    let read_variant_id_quote = quotes.read_variant_id_fn;
+    let view_quote = structs::view::create_variants_view_quotes(&variants_info);
+    let paths_quote = structs::paths::create_variants_paths_quotes(&variants_info);
+    let bits_fns_quote = if variants_info.flip {
+        structs::common::reversed_bits_fns_quote(&quote! {#struct_size})
+    } else {
+        quote! {}
+    };
+    let try_from_bytes_quote = if let Some(try_from_bytes_fn) = quotes.try_from_bytes_fn {
+        quote! {
+            impl TryBitfields<#struct_size> for #enum_name {
+                #try_from_bytes_fn
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let serde_quote = if variants_info.serde_bytes {
+        serde_bytes_quote(&syn::Generics::default(), &quote! {#enum_name})
+    } else {
+        quote! {}
+    };
+    TokenStream::from(quote! {
+        #size_consts
+        impl Bitfields<#struct_size> for #enum_name {
+            const BIT_SIZE: usize = #bit_size;
+            #into_bytes_quote
+            #from_bytes_quote
+            #bits_fns_quote
+        }
+        #try_from_bytes_quote
+        impl #enum_name {
+            #read_variant_id_quote
+        }
+        #view_quote
+        #paths_quote
+        #serde_quote
+    })
 }
 
 /// Generates an implementation of bondrewd::BitfieldEnum trait.
 ///   
-/// Important Note: u8 is the only primitive type i have tested. My newest code should be able to handle
-/// all primitive types but, to reiterate, i have NOT tested any primitive type other than u8.
+/// The primitive can be defined with a `#[bondrewd_enum(u16)]` attribute, any integer primitive other
+/// than usize and isize is supported. If the primitive is not defined it will be inferred as the smallest
+/// unsigned primitive able to hold every variant value (u8 for up to 256 variants), or the smallest signed
+/// primitive if a variant has a negative literal. Literal values that do not fit in the primitive are a
+/// compile error. [example](#wide-primitive-example)
 ///
 /// # Features
 /// - Generates code for the BitfieldEnum trait which allows an enum to be used by Bitfield structs.
 /// - Literal values. [example](#literal-example)
 /// - Automatic Value Assignment for non-literal variants. Variants are assigned values starting from 0
-/// incrementing by 1 skipping values taken by literal definitions (That means you can mix and match
-/// inferred values a code defined literal values). [example](#typical-example)
+///   incrementing by 1 skipping values taken by literal definitions (That means you can mix and match
+///   inferred values a code defined literal values). [example](#typical-example)
 /// - Catch Variants
 ///     - Catch All variant is used to insure that Results are not needed. Catch all will generate a
-///     `_ => {..}` match arm so that enums don't need to have as many variants as there are values in
-///     the defined primitive. Catch all can be defined with a `#[bondrewd_enum(invalid)]` attribute or last variant will
-///     Automatically become a catch all if no Catch is defined. [example](#custom-catch-all-example)
+///       `_ => {..}` match arm so that enums don't need to have as many variants as there are values in
+///       the defined primitive. Catch all can be defined with a `#[bondrewd_enum(invalid)]` attribute or last variant will
+///       Automatically become a catch all if no Catch is defined. [example](#custom-catch-all-example)
 ///     - Catch Value is a variant that will store values that don't match the reset of the variants.
-///     using a Catch Value is as simple as making a variant with a primitive value (if the bondrewd_enum
-///     attribute is present the primitive types must match). [example](#catch-value-example)
+///       using a Catch Value is as simple as making a variant with a primitive value (if the bondrewd_enum
+///       attribute is present the primitive types must match). [example](#catch-value-example)
+/// - Strict enums. `#[bondrewd_enum(strict)]` stops the last variant from becoming a catch all.
+///   **`from_primitive` can not fail, so on a strict enum it quietly gives the last variant for
+///   values without a variant**, and so does `from_bytes` for structs holding the enum. Decode
+///   strict enums with `TryBitfieldEnum::try_from_primitive`, or `try_from_bytes` when used in a
+///   struct, to notice those values.
+///   [example](#strict-example)
 ///
 /// # Other Features
-/// - Support for implementation of [`std::cmp::PartialEq`] for the given primitive (currently only u8)
+/// - Support for implementation of [`std::cmp::PartialEq`] for the given primitive
+/// - `#[bondrewd_enum(serde_primitive)]` implements serde's `Serialize` and `Deserialize` with the
+///   primitive instead of the variant name, values without a variant fail to deserialize. Needs the
+///   `serde` feature and `Clone`.
 ///
 /// # Typical Example
 /// Here i am letting the Derive do all of the work. The primitive type will be assumed to be u8 because
@@ -1346,6 +2414,7 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
 /// - Cover all possible values in the primitive type with a variant each.
 /// - Mark the variant you would like to be the catch all [example](#custom-catch-all-example).
 /// - Add a catch primitive variant [example](#catch-value-example)
+/// - Make the enum strict [example](#strict-example)
 /// # Literal Example
 /// ```
 /// use bondrewd::BitfieldEnum;
@@ -1507,6 +2576,75 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+/// # Strict Example
+/// `try_from_primitive` is implemented for every enum, values only an implicit catch all would
+/// take are errors there. With `strict` the last variant is a normal variant.
+///
+/// **Warning:** `strict` does not change `from_primitive`, which has no way to report an error.
+/// It still decodes values without a variant as the last variant, `PacketType::Idle` below, and
+/// `from_bytes` and `read_{field}` do the same for strict enum fields. Use `try_from_primitive`,
+/// or `try_from_bytes` and `try_read_{field}` with the `try_fns` feature, wherever an invalid value
+/// has to be noticed.
+/// ```
+/// use bondrewd::{BitfieldEnum, BitfieldEnumError, TryBitfieldEnum};
+/// #[derive(BitfieldEnum, PartialEq, Debug)]
+/// #[bondrewd_enum(strict)]
+/// enum PacketType {
+///     Telemetry,
+///     Telecommand,
+///     Idle = 7,
+/// }
+///
+/// fn main(){
+///     assert_eq!(PacketType::Idle.into_primitive(), 7);
+///     assert_eq!(PacketType::try_from_primitive(7), Ok(PacketType::Idle));
+///     assert_eq!(PacketType::try_from_primitive(1), Ok(PacketType::Telecommand));
+///     assert_eq!(PacketType::try_from_primitive(2), Err(BitfieldEnumError(2)));
+///     assert_eq!(PacketType::from_primitive(2), PacketType::Idle);
+/// }
+/// ```
+/// Unknown enum and variant attributes are errors.
+/// ```compile_fail
+/// use bondrewd::BitfieldEnum;
+/// #[derive(BitfieldEnum)]
+/// // error: "stricter" is not a valid enum attribute, did you mean "strict"?
+/// #[bondrewd_enum(u8, stricter)]
+/// enum PacketType {
+///     Telemetry,
+///     Telecommand,
+/// }
+/// ```
+/// # Wide Primitive Example
+/// 12 bit command codes need a u16, and negative literals will make the inferred primitive signed.
+/// Enums that mix literal values with a Catch Value need a `repr` for rust to accept them.
+/// ```
+/// use bondrewd::BitfieldEnum;
+/// #[derive(BitfieldEnum, PartialEq, Debug)]
+/// #[bondrewd_enum(u16)]
+/// enum CommandCode {
+///     Noop,
+///     Reset = 0x0A0,
+///     Dump = 0xFFE,
+///     Invalid,
+/// }
+///
+/// #[derive(BitfieldEnum, PartialEq, Debug)]
+/// #[repr(i8)]
+/// enum Trend {
+///     Falling = -1,
+///     Steady = 0,
+///     Rising = 1,
+///     Unknown(i8),
+/// }
+///
+/// fn main(){
+///     assert_eq!(CommandCode::Dump.into_primitive(), 0xFFE);
+///     assert_eq!(CommandCode::Reset, CommandCode::from_primitive(0x0A0));
+///     assert_eq!(CommandCode::Invalid, CommandCode::from_primitive(0xFFF));
+///     assert_eq!(Trend::Falling, Trend::from_primitive(-1));
+///     assert_eq!(Trend::Unknown(-5), Trend::from_primitive(-5));
+/// }
+/// ```
 #[proc_macro_derive(BitfieldEnum, attributes(bondrewd_enum))]
 pub fn derive_bondrewd_enum(input: TokenStream) -> TokenStream {
     // TODO added the ability to give a Catch Value Variant a Literal value.
@@ -1525,7 +2663,13 @@ pub fn derive_bondrewd_enum(input: TokenStream) -> TokenStream {
         Ok(f) => f,
         Err(err) => return TokenStream::from(err.to_compile_error()),
     };
+    let try_from = match enums::from_bytes::generate_try_from_primitive(&enum_info) {
+        Ok(f) => f,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let is_variant_value = enums::from_bytes::generate_is_variant_value(&enum_info);
     let partial_eq = enums::partial_eq::generate_partial_eq(&enum_info);
+    let serde = enums::serde::generate_serde(&enum_info);
     let enum_name = enum_info.name;
     let primitive = enum_info.primitive;
     TokenStream::from(quote! {
@@ -1533,8 +2677,15 @@ pub fn derive_bondrewd_enum(input: TokenStream) -> TokenStream {
             type Primitive = #primitive;
             #into
             #from
+            #is_variant_value
+        }
+
+        impl bondrewd::TryBitfieldEnum for #enum_name {
+            #try_from
         }
 
         #partial_eq
+
+        #serde
     })
 }
@@ -0,0 +1,120 @@
+use crate::structs::common::{Endianness, StructInfo};
+use crate::structs::{checksum, from_bytes, into_bytes};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+/// functions that read and write the structure with a byte order chosen at runtime. the structure
+/// is parsed again for each byte order so every field without its own endianness uses it.
+pub fn create_byte_order_quotes(input: &DeriveInput, info: &StructInfo) -> syn::Result<TokenStream> {
+    let struct_type = info.type_quote();
+    let impl_generics = info.impl_generics(|_| None);
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+    let mut fns = quote! {};
+    for (suffix, endianness) in [("le", Endianness::Little), ("be", Endianness::Big)] {
+        let order_info = StructInfo::parse_with_endianness(input, endianness)?;
+        let order_fns = byte_order_fns(&order_info, suffix)?;
+        fns = quote! {
+            #fns
+            #order_fns
+        };
+    }
+    Ok(quote! {
+        impl #impl_generics #struct_type #where_clause {
+            #fns
+        }
+    })
+}
+
+fn byte_order_fns(info: &StructInfo, suffix: &str) -> syn::Result<TokenStream> {
+    let struct_size = info.total_bytes();
+    let flip = if info.flip {
+        Some(struct_size.clone() - 1)
+    } else {
+        None
+    };
+    let mut read_write_fns = quote! {};
+    let mut from_bytes_quote = quote! {};
+    let mut from_bytes_struct_quote = quote! {};
+    let mut into_bytes_quote = quote! {};
+    for field in info.fields.iter() {
+        if field.attrs.reserve.is_fake_field() {
+            continue;
+        }
+        let field_name = &field.ident;
+        let type_ident = field.ty.type_quote();
+        let read_name = format_ident!("read_{}_{}", field_name.as_ref(), suffix);
+        let write_name = format_ident!("write_{}_{}", field_name.as_ref(), suffix);
+        let field_extractor = from_bytes::get_field_quote(field, &flip, false)?;
+        let (field_setter, clear_quote) = into_bytes::get_field_quote(field, &flip, false)?;
+        read_write_fns = quote! {
+            #read_write_fns
+            #[inline]
+            pub fn #read_name(input_byte_buffer: &[u8;#struct_size]) -> #type_ident {
+                #field_extractor
+            }
+            #[inline]
+            pub fn #write_name(output_byte_buffer: &mut [u8;#struct_size], mut #field_name: #type_ident) {
+                #clear_quote
+                #field_setter
+            }
+        };
+        if field.attrs.reserve.is_reserve_field() {
+            from_bytes_quote = quote! {
+                #from_bytes_quote
+                let #field_name = 0 as #type_ident;
+            };
+        } else if field.attrs.reserve.is_overlay() {
+            from_bytes_quote = quote! {
+                #from_bytes_quote
+                let #field_name = core::default::Default::default();
+            };
+        } else {
+            let read_call = if let Some(ref const_value) = field.attrs.const_value {
+                const_value.struct_value()
+            } else {
+                quote! {Self::#read_name(&input_byte_buffer)}
+            };
+            from_bytes_quote = quote! {
+                #from_bytes_quote
+                let #field_name = #read_call;
+            };
+            if field.attrs.checksum.is_none() {
+                let value = into_bytes::field_value_quote(field);
+                into_bytes_quote = quote! {
+                    #into_bytes_quote
+                    let #field_name = #value;
+                    #field_setter
+                };
+            }
+        }
+        from_bytes_struct_quote = quote! {
+            #from_bytes_struct_quote
+            #field_name,
+        };
+    }
+    let size_assertions = info.size_assertions();
+    let from_bytes_name = format_ident!("from_bytes_{}", suffix);
+    let into_bytes_name = format_ident!("into_bytes_{}", suffix);
+    let checksums_quote = checksum::write_checksums_quote(info)?;
+    let verify_checksums_quote =
+        checksum::verify_checksums_quote(info, &format_ident!("verify_checksums_{}", suffix))?;
+    Ok(quote! {
+        pub fn #from_bytes_name(input_byte_buffer: [u8;#struct_size]) -> Self {
+            #size_assertions
+            #from_bytes_quote
+            Self{
+                #from_bytes_struct_quote
+            }
+        }
+        pub fn #into_bytes_name(self) -> [u8;#struct_size] {
+            #size_assertions
+            let mut output_byte_buffer: [u8;#struct_size] = [0u8;#struct_size];
+            #into_bytes_quote
+            #checksums_quote
+            output_byte_buffer
+        }
+        #verify_checksums_quote
+        #read_write_fns
+    })
+}
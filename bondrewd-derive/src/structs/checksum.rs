@@ -0,0 +1,77 @@
+use crate::structs::common::StructInfo;
+use crate::structs::{from_bytes, into_bytes};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// fills the checksum fields of `output_byte_buffer`, goes at the end of `into_bytes` so the bytes
+/// they cover are already written.
+pub fn write_checksums_quote(info: &StructInfo) -> syn::Result<TokenStream> {
+    let mut write_quote = quote! {};
+    for field in info.checksum_fields() {
+        let checksum = if let Some(ref checksum) = field.attrs.checksum {
+            checksum
+        } else {
+            continue;
+        };
+        let field_name = &field.ident;
+        let type_quote = field.ty.type_quote();
+        let algorithm = &checksum.algorithm;
+        let (start, end) = (&checksum.bytes.start, &checksum.bytes.end);
+        let (field_setter, _) = into_bytes::get_field_quote(field, &None, false)?;
+        // the value in the structure is replaced, it is still read so it does not warn as unused.
+        write_quote = quote! {
+            #write_quote
+            let _ = &self.#field_name;
+            let #field_name: #type_quote = <#algorithm as BitfieldChecksum>::checksum(&output_byte_buffer[#start..#end]);
+            #field_setter
+        };
+    }
+    Ok(write_quote)
+}
+
+/// `fn_name` checks every checksum field holds the checksum of the bytes it covers, nothing is
+/// generated for structures without checksum fields.
+pub fn verify_checksums_quote(info: &StructInfo, fn_name: &Ident) -> syn::Result<TokenStream> {
+    let mut checks = quote! {};
+    for field in info.checksum_fields() {
+        let checksum = if let Some(ref checksum) = field.attrs.checksum {
+            checksum
+        } else {
+            continue;
+        };
+        let field_name = field.ident.to_string();
+        let type_quote = field.ty.type_quote();
+        let algorithm = &checksum.algorithm;
+        let (start, end) = (&checksum.bytes.start, &checksum.bytes.end);
+        let field_extractor = from_bytes::get_field_quote(field, &None, false)?;
+        // only the bits of the checksum that fit in the field are compared.
+        let mask = field.attrs.bit_length().mask_quote();
+        checks = quote! {
+            #checks
+            {
+                let expected: u128 = <#algorithm as BitfieldChecksum>::checksum(&input_byte_buffer[#start..#end]).into();
+                let expected = expected & #mask;
+                let actual: #type_quote = {#field_extractor};
+                let actual: u128 = actual.into();
+                if expected != actual {
+                    return Err(BitfieldChecksumError {
+                        field: #field_name,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        };
+    }
+    if checks.is_empty() {
+        return Ok(quote! {});
+    }
+    let struct_size = info.total_bytes();
+    Ok(quote! {
+        pub fn #fn_name(input_byte_buffer: &[u8;#struct_size]) -> Result<(), BitfieldChecksumError> {
+            #checks
+            Ok(())
+        }
+    })
+}
@@ -1,11 +1,17 @@
+use crate::attrs::{
+    bondrewd_attr_items, check_serde_feature, names_constants, AttrItem, STRUCT_ATTRS,
+    VARIANTS_ATTRS,
+};
+use crate::structs::dynamic::{check_trailing_field, TrailingFieldInfo};
 use crate::structs::parse::{
-    FieldAttrBuilder, FieldAttrBuilderType, FieldBuilderRange, TryFromAttrBuilderError,
+    ChecksumBound, FieldAttrBuilder, FieldAttrBuilderType, FieldBuilderRange, FloatRounding,
+    TryFromAttrBuilderError,
 };
 use proc_macro2::Span;
-use quote::quote;
+use quote::{quote, ToTokens};
 use std::ops::Range;
 use syn::parse::Error;
-use syn::{DeriveInput, Ident, Lit, Meta, NestedMeta, Type};
+use syn::{DeriveInput, Ident, Type};
 
 /// Returns a u8 mask with provided `num` amount of 1's on the left side (most significant bit)
 pub fn get_left_and_mask(num: usize) -> u8 {
@@ -44,7 +50,7 @@ pub fn get_right_and_mask(num: usize) -> u8 {
 /// # Arguments
 /// * `amount_of_bits` - amount of bits the field will be after into_bytes.
 /// * `right_rotation` - amount of bit Rotations to preform on the field. Note if rotation is not needed
-///                         to retain all used bits then a shift could be used.
+///   to retain all used bits then a shift could be used.
 /// * `last_index` - total struct bytes size minus 1.
 #[inline]
 pub fn get_be_starting_index(
@@ -61,6 +67,229 @@ pub fn get_be_starting_index(
     }
 }
 
+/// a size or bit position. sizes from constants, ex. `bit_length = ID_BITS`, are only known to the
+/// compiler, so they are kept as an expression and the generated code has the compiler work them
+/// out.
+#[derive(Clone, Debug)]
+pub enum Size {
+    Literal(usize),
+    Const(proc_macro2::TokenStream),
+}
+
+impl Size {
+    pub fn literal(&self) -> Option<usize> {
+        match self {
+            Self::Literal(value) => Some(*value),
+            Self::Const(_) => None,
+        }
+    }
+
+    pub fn is_literal(&self) -> bool {
+        matches!(self, Self::Literal(_))
+    }
+
+    /// the size as a const generic argument, anything but a single identifier needs braces there.
+    pub fn const_arg(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Const(ref expr) if syn::parse2::<Ident>(expr.clone()).is_err() => {
+                quote! {{#expr}}
+            }
+            _ => quote! {#self},
+        }
+    }
+
+    /// the size as a `u32` expression.
+    pub fn u32_quote(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Literal(value) => {
+                let value = *value as u32;
+                quote! {#value}
+            }
+            Self::Const(_) => quote! {#self as u32},
+        }
+    }
+
+    /// a `u128` with the low `self` bits set.
+    pub fn mask_quote(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Literal(bits) => {
+                let mask = if *bits >= 128 {
+                    u128::MAX
+                } else {
+                    (1u128 << bits) - 1
+                };
+                quote! {#mask}
+            }
+            Self::Const(_) => quote! {(u128::MAX >> (128 - #self))},
+        }
+    }
+
+    pub fn div_ceil(&self, rhs: usize) -> Self {
+        match self {
+            Self::Literal(value) => Self::Literal(value.div_ceil(rhs)),
+            _ if rhs == 1 => self.clone(),
+            Self::Const(_) => Self::Const(quote! {#self.div_ceil(#rhs)}),
+        }
+    }
+
+    pub fn max(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Self::Literal(a), Self::Literal(b)) => Self::Literal(*a.max(b)),
+            _ => Self::Const(quote! {if #self > #other { #self } else { #other }}),
+        }
+    }
+
+    fn compare(
+        &self,
+        other: &Self,
+        op: proc_macro2::TokenStream,
+        literal: impl Fn(usize, usize) -> bool,
+    ) -> Condition {
+        match (self, other) {
+            (Self::Literal(a), Self::Literal(b)) => Condition::Literal(literal(*a, *b)),
+            _ => Condition::Const(quote! {#self #op #other}),
+        }
+    }
+
+    pub fn less_than(&self, other: &Self) -> Condition {
+        self.compare(other, quote! {<}, |a, b| a < b)
+    }
+
+    pub fn at_most(&self, other: &Self) -> Condition {
+        self.compare(other, quote! {<=}, |a, b| a <= b)
+    }
+
+    pub fn equals(&self, other: &Self) -> Condition {
+        self.compare(other, quote! {==}, |a, b| a == b)
+    }
+
+    pub fn multiple_of(&self, rhs: usize) -> Condition {
+        (self.clone() % rhs).equals(&Self::Literal(0))
+    }
+
+    /// the expression needs parentheses to be used as an operand.
+    fn needs_parens(expr: &proc_macro2::TokenStream) -> bool {
+        !matches!(
+            syn::parse2(expr.clone()),
+            Ok(syn::Expr::Path(_)
+                | syn::Expr::Lit(_)
+                | syn::Expr::Call(_)
+                | syn::Expr::MethodCall(_)
+                | syn::Expr::Paren(_))
+        )
+    }
+}
+
+impl From<usize> for Size {
+    fn from(value: usize) -> Self {
+        Self::Literal(value)
+    }
+}
+
+impl quote::ToTokens for Size {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Literal(value) => value.to_tokens(tokens),
+            Self::Const(ref expr) if Self::needs_parens(expr) => {
+                tokens.extend(quote! {(#expr)});
+            }
+            Self::Const(ref expr) => expr.to_tokens(tokens),
+        }
+    }
+}
+
+impl std::fmt::Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal(value) => write!(f, "{}", value),
+            Self::Const(ref expr) => write!(f, "{}", expr),
+        }
+    }
+}
+
+/// `Size` arithmetic, literals are worked out and expressions are only made for constants.
+macro_rules! size_op {
+    ($trait:ident, $fn:ident, $op:tt, $identity:expr, $commutative:expr) => {
+        impl<T: Into<Size>> std::ops::$trait<T> for Size {
+            type Output = Size;
+            fn $fn(self, rhs: T) -> Size {
+                let rhs: Size = rhs.into();
+                match (self, rhs) {
+                    (Size::Literal(a), Size::Literal(b)) => Size::Literal(a $op b),
+                    (lhs, Size::Literal(b)) if Some(b) == $identity => lhs,
+                    (Size::Literal(a), rhs) if $commutative && Some(a) == $identity => rhs,
+                    (lhs, rhs) => Size::Const(quote! {#lhs $op #rhs}),
+                }
+            }
+        }
+    };
+}
+size_op!(Add, add, +, Some(0), true);
+size_op!(Sub, sub, -, Some(0), false);
+size_op!(Mul, mul, *, Some(1), true);
+size_op!(Div, div, /, Some(1), false);
+size_op!(Rem, rem, %, None::<usize>, false);
+
+/// a check on sizes, known while parsing when the sizes are literals.
+#[derive(Clone, Debug)]
+pub enum Condition {
+    Literal(bool),
+    Const(proc_macro2::TokenStream),
+}
+
+impl Condition {
+    pub fn and(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Literal(false), _) | (_, Self::Literal(false)) => Self::Literal(false),
+            (Self::Literal(true), other) | (other, Self::Literal(true)) => other,
+            (Self::Const(a), Self::Const(b)) => Self::Const(quote! {(#a) && (#b)}),
+        }
+    }
+
+    pub fn not(self) -> Self {
+        match self {
+            Self::Literal(value) => Self::Literal(!value),
+            Self::Const(a) => Self::Const(quote! {!(#a)}),
+        }
+    }
+}
+
+/// the checks on sizes from constants, they become `const` assertions next to the generated impls.
+#[derive(Clone, Debug, Default)]
+pub struct SizeChecks(Vec<proc_macro2::TokenStream>);
+
+impl SizeChecks {
+    /// a false literal condition is an error, an expression is asserted when compiling.
+    pub fn require(
+        &mut self,
+        condition: Condition,
+        span: Span,
+        message: impl FnOnce() -> String,
+    ) -> syn::Result<()> {
+        match condition {
+            Condition::Literal(true) => Ok(()),
+            Condition::Literal(false) => Err(Error::new(span, message())),
+            Condition::Const(condition) => {
+                let message = message().replace('{', "{{").replace('}', "}}");
+                self.0.push(quote::quote_spanned! {span=> assert!(#condition, #message);});
+                Ok(())
+            }
+        }
+    }
+
+    pub fn quote(&self) -> proc_macro2::TokenStream {
+        if self.0.is_empty() {
+            return quote! {};
+        }
+        let checks = &self.0;
+        quote! {
+            const _: () = {
+                #(#checks)*
+            };
+        }
+    }
+}
+
 pub struct BitMath {
     pub amount_of_bits: usize,
     pub zeros_on_left: usize,
@@ -70,11 +299,12 @@ pub struct BitMath {
 
 impl BitMath {
     pub fn from_field(field: &FieldInfo) -> Result<Self, syn::Error> {
+        let bit_range = field.literal_range()?;
         // get the total number of bits the field uses.
-        let amount_of_bits = field.attrs.bit_length();
+        let amount_of_bits = bit_range.end - bit_range.start;
         // amount of zeros to have for the right mask. (right mask meaning a mask to keep data on the
         // left)
-        let zeros_on_left = field.attrs.bit_range.start % 8;
+        let zeros_on_left = bit_range.start % 8;
         // NOTE endianness is only for determining how to get the bytes we will apply to the output.
         // calculate how many of the bits will be inside the most significant byte we are adding to.
         if 7 < zeros_on_left {
@@ -85,7 +315,7 @@ impl BitMath {
         }
         let available_bits_in_first_byte = 8 - zeros_on_left;
         // calculate the starting byte index in the outgoing buffer
-        let starting_inject_byte: usize = field.attrs.bit_range.start / 8;
+        let starting_inject_byte: usize = bit_range.start / 8;
         Ok(Self {
             amount_of_bits,
             zeros_on_left,
@@ -109,20 +339,30 @@ impl BitMath {
 pub enum Endianness {
     Little,
     Big,
+    /// the byte order of the target, the generated code has both orders behind `cfg(target_endian)`.
+    Native,
     None,
 }
 
 impl Endianness {
-    fn has_endianness(&self) -> bool {
-        if let Self::None = self {
-            false
-        } else {
-            true
+    /// the value of an `endianness` or `default_endianness` attribute.
+    pub fn from_lit(val: &syn::LitStr) -> syn::Result<Self> {
+        match val.value().as_str() {
+            "le" | "lsb" | "little" | "lil" => Ok(Self::Little),
+            "be" | "msb" | "big" => Ok(Self::Big),
+            "ne" | "native" => Ok(Self::Native),
+            other => Err(Error::new(
+                val.span(),
+                format!("\"{}\" is not a valid endianness, use \"le\", \"be\" or \"ne\"", other),
+            )),
         }
     }
-    fn perhaps_endianness(&mut self, size: usize) -> bool {
+    pub fn has_endianness(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+    fn perhaps_endianness(&mut self, size: &Size) -> bool {
         if let Self::None = self {
-            if size == 1 {
+            if size.literal() == Some(1) {
                 let mut swap = Self::Big;
                 std::mem::swap(&mut swap, self);
                 true
@@ -141,41 +381,218 @@ pub enum NumberSignage {
     Unsigned,
 }
 
+/// the format of a float field stored with fewer bits than its type, the sign bit is always kept.
+#[derive(Clone, Debug)]
+pub struct ReducedFloat {
+    pub exponent_bits: usize,
+    pub mantissa_bits: Size,
+    pub rounding: FloatRounding,
+}
+
+impl ReducedFloat {
+    /// `full_bits`, `full_exponent` and `full_mantissa` describe the rust type of the field. `None`
+    /// when the field uses the same format as its type.
+    fn from_attrs(
+        attrs: &FieldAttrBuilder,
+        type_name: &str,
+        full_exponent: usize,
+        full_mantissa: usize,
+        field_span: Span,
+        checks: &mut SizeChecks,
+    ) -> syn::Result<Option<Self>> {
+        let full_bits = 1 + full_exponent + full_mantissa;
+        let exponent_bits = attrs.exponent_bits.unwrap_or(full_exponent);
+        let bit_length = match attrs.bit_range {
+            FieldBuilderRange::Range(ref range) => match (range.start.literal(), range.end.literal()) {
+                (Some(start), Some(end)) => end.saturating_sub(start),
+                // a length from a constant is always stored as a reduced float, at the full size it
+                // keeps every bit.
+                _ => {
+                    let bit_length = range.end.clone() - range.start.clone();
+                    if exponent_bits < 2 || exponent_bits > full_exponent {
+                        return Err(Error::new(
+                            field_span,
+                            format!("exponent_bits for {} must be between 2 and {}, provided exponent_bits = {}", type_name, full_exponent, exponent_bits),
+                        ));
+                    }
+                    checks.require(
+                        Size::Literal(exponent_bits)
+                            .less_than(&bit_length)
+                            .and(bit_length.at_most(&Size::Literal(exponent_bits + 1 + full_mantissa))),
+                        field_span,
+                        || format!(
+                            "a {} with {} exponent bits must have a bit_length between {} and {}",
+                            type_name,
+                            exponent_bits,
+                            exponent_bits + 1,
+                            exponent_bits + 1 + full_mantissa,
+                        ),
+                    )?;
+                    return Ok(Some(Self {
+                        exponent_bits,
+                        mantissa_bits: bit_length - (1 + exponent_bits),
+                        rounding: attrs.rounding.unwrap_or(FloatRounding::NearestEven),
+                    }));
+                }
+            },
+            _ => full_bits,
+        };
+        if bit_length == full_bits && exponent_bits == full_exponent {
+            if attrs.rounding.is_some() {
+                return Err(Error::new(
+                    field_span,
+                    format!("rounding has no effect on a full sized {}, reduce the bit_length to use it", type_name),
+                ));
+            }
+            return Ok(None);
+        }
+        if exponent_bits < 2 || exponent_bits > full_exponent {
+            return Err(Error::new(
+                field_span,
+                format!("exponent_bits for {} must be between 2 and {}, provided exponent_bits = {}", type_name, full_exponent, exponent_bits),
+            ));
+        }
+        if bit_length <= exponent_bits || bit_length - 1 - exponent_bits > full_mantissa {
+            return Err(Error::new(
+                field_span,
+                format!(
+                    "a {} with {} exponent bits must have a bit_length between {} and {}, provided bit length = {}",
+                    type_name,
+                    exponent_bits,
+                    exponent_bits + 1,
+                    exponent_bits + 1 + full_mantissa,
+                    bit_length
+                ),
+            ));
+        }
+        Ok(Some(Self {
+            exponent_bits,
+            mantissa_bits: Size::Literal(bit_length - 1 - exponent_bits),
+            rounding: attrs.rounding.unwrap_or(FloatRounding::NearestEven),
+        }))
+    }
+}
+
+/// a float field stored as an integer, `value = raw * scale + offset`.
+#[derive(Clone, Debug)]
+pub struct ScaledFloat {
+    pub scale: f64,
+    pub offset: f64,
+    /// the raw integer is signed, only for fixed point formats.
+    pub signed: bool,
+}
+
+/// how a float field is stored when it is not stored as its own bits.
+#[derive(Clone, Debug)]
+pub enum FloatFormat {
+    Reduced(ReducedFloat),
+    Scaled(ScaledFloat),
+}
+
+impl FloatFormat {
+    fn from_attrs(
+        attrs: &mut FieldAttrBuilder,
+        type_name: &str,
+        full_exponent: usize,
+        full_mantissa: usize,
+        field_span: Span,
+        checks: &mut SizeChecks,
+    ) -> syn::Result<Option<Self>> {
+        if attrs.scale.is_none() && attrs.offset.is_none() && attrs.q.is_none() {
+            return Ok(
+                ReducedFloat::from_attrs(attrs, type_name, full_exponent, full_mantissa, field_span, checks)?
+                    .map(Self::Reduced),
+            );
+        }
+        if attrs.exponent_bits.is_some() || attrs.rounding.is_some() {
+            return Err(Error::new(
+                field_span,
+                "exponent_bits and rounding can not be used along with scale, offset or q",
+            ));
+        }
+        let full_bits = 1 + full_exponent + full_mantissa;
+        if full_bits < 32 {
+            return Err(Error::new(
+                field_span,
+                "scale, offset and q can only be used on f32 and f64 fields",
+            ));
+        }
+        let (scale, signed) = if let Some(q) = attrs.q {
+            if attrs.scale.is_some() {
+                return Err(Error::new(
+                    field_span,
+                    "scale can not be used along with q, the fraction bits of q set the scale",
+                ));
+            }
+            let bits = q.bit_length();
+            match attrs.bit_range {
+                FieldBuilderRange::LastEnd(ref start) => {
+                    attrs.bit_range = FieldBuilderRange::Range(start.clone()..start.clone() + bits);
+                }
+                FieldBuilderRange::Range(ref range) => {
+                    let length = range.end.clone() - range.start.clone();
+                    checks.require(length.equals(&Size::Literal(bits)), field_span, || {
+                        format!("the q format uses {} bits, provided bit length = {}", bits, length)
+                    })?;
+                }
+                _ => {}
+            }
+            (0.5f64.powi(q.fraction_bits as i32), q.signed)
+        } else {
+            (attrs.scale.unwrap_or(1.0), false)
+        };
+        if let FieldBuilderRange::Range(ref range) = attrs.bit_range {
+            let length = range.end.clone() - range.start.clone();
+            checks.require(
+                length.at_most(&Size::Literal(full_bits)).and(range.start.less_than(&range.end)),
+                field_span,
+                || format!("a scaled {} must use between 1 and {} bits, provided bit length = {}", type_name, full_bits, length),
+            )?;
+        }
+        Ok(Some(Self::Scaled(ScaledFloat {
+            scale,
+            offset: attrs.offset.unwrap_or(0.0),
+            signed,
+        })))
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FieldDataType {
     Boolean,
     /// first field is byte size for number
     Number(usize, NumberSignage, proc_macro2::TokenStream),
-    Float(usize, proc_macro2::TokenStream),
+    /// the format is `None` when the float is stored as its own bits.
+    Float(usize, proc_macro2::TokenStream, Option<FloatFormat>),
     /// first value is primitive type byte size of enum value in bytes.
     Enum(proc_macro2::TokenStream, usize, proc_macro2::TokenStream),
     /// first field is size in BYTES of the entire struct
-    Struct(usize, proc_macro2::TokenStream),
+    Struct(Size, proc_macro2::TokenStream),
     Char(usize, proc_macro2::TokenStream),
     // array types are Subfield info, array length, ident
-    ElementArray(Box<SubFieldInfo>, usize, proc_macro2::TokenStream),
-    BlockArray(Box<SubFieldInfo>, usize, proc_macro2::TokenStream),
+    ElementArray(Box<SubFieldInfo>, Size, proc_macro2::TokenStream),
+    BlockArray(Box<SubFieldInfo>, Size, proc_macro2::TokenStream),
 }
 
 impl FieldDataType {
     /// byte size of actual rust type .
-    pub fn size(&self) -> usize {
+    pub fn size(&self) -> Size {
         match self {
-            Self::Number(ref size, _, _) => size.clone(),
-            Self::Float(ref size, _) => size.clone(),
-            Self::Enum(_, ref size, _) => size.clone(),
+            Self::Number(ref size, _, _) => Size::Literal(*size),
+            Self::Float(ref size, _, _) => Size::Literal(*size),
+            Self::Enum(_, ref size, _) => Size::Literal(*size),
             Self::Struct(ref size, _) => size.clone(),
-            Self::Char(ref size, _) => size.clone(),
-            Self::ElementArray(ref fields, ref length, _) => fields.ty.size() * length,
-            Self::BlockArray(ref fields, size, _) => fields.ty.size() * size,
-            Self::Boolean => 1,
+            Self::Char(ref size, _) => Size::Literal(*size),
+            Self::ElementArray(ref fields, ref length, _) => fields.ty.size() * length.clone(),
+            Self::BlockArray(ref fields, ref length, _) => fields.ty.size() * length.clone(),
+            Self::Boolean => Size::Literal(1),
         }
     }
 
     pub fn type_quote(&self) -> proc_macro2::TokenStream {
         match self {
             Self::Number(_, _, ref ident) => ident.clone(),
-            Self::Float(_, ref ident) => ident.clone(),
+            Self::Float(_, ref ident, _) => ident.clone(),
             Self::Enum(_, _, ref ident) => ident.clone(),
             Self::Struct(_, ref ident) => ident.clone(),
             Self::Char(_, ref ident) => ident.clone(),
@@ -184,10 +601,64 @@ impl FieldDataType {
             Self::Boolean => quote! {bool},
         }
     }
+    /// byte size and signage of types that are read and written as integers, enums use the
+    /// signage of their primitive.
+    pub fn number_signage(&self) -> Option<(usize, NumberSignage)> {
+        match self {
+            Self::Number(size, ref sign, _) => Some((*size, sign.clone())),
+            Self::Enum(ref prim, size, _) => {
+                if prim.to_string().starts_with('i') {
+                    Some((*size, NumberSignage::Signed))
+                } else {
+                    Some((*size, NumberSignage::Unsigned))
+                }
+            }
+            Self::Float(size, _, Some(FloatFormat::Scaled(ref scaled))) if scaled.signed => {
+                Some((*size, NumberSignage::Signed))
+            }
+            _ => None,
+        }
+    }
+    /// the integer type a number, enum or float is read and written as. floats are read as their
+    /// bits, or as the raw integer of a scaled float.
+    pub fn primitive_quote(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Self::Number(_, _, ref ident) => Some(ident.clone()),
+            Self::Enum(ref prim, _, _) => Some(prim.clone()),
+            Self::Float(size, _, ref format) => {
+                let signed = matches!(format, Some(FloatFormat::Scaled(ScaledFloat { signed: true, .. })));
+                match (*size, signed) {
+                    (2, _) => Some(quote! {u16}),
+                    (4, false) => Some(quote! {u32}),
+                    (4, true) => Some(quote! {i32}),
+                    (8, false) => Some(quote! {u64}),
+                    (8, true) => Some(quote! {i64}),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+    /// true for enums and arrays of enums, the only types the strict attribute applies to.
+    pub fn is_enum(&self) -> bool {
+        match self {
+            Self::Enum(_, _, _) => true,
+            Self::ElementArray(ref ty, _, _) | Self::BlockArray(ref ty, _, _) => ty.ty.is_enum(),
+            _ => false,
+        }
+    }
+    /// true for floats and arrays of floats, the only types exponent_bits and rounding apply to.
+    pub fn is_float(&self) -> bool {
+        match self {
+            Self::Float(_, _, _) => true,
+            Self::ElementArray(ref ty, _, _) | Self::BlockArray(ref ty, _, _) => ty.ty.is_float(),
+            _ => false,
+        }
+    }
     pub fn is_number(&self) -> bool {
         // TODO put Arrays in here
         match self {
-            Self::Enum(_, _, _) | Self::Number(_, _, _) | Self::Float(_, _) | Self::Char(_, _) => {
+            Self::Enum(_, _, _) | Self::Number(_, _, _) | Self::Float(_, _, _) | Self::Char(_, _) => {
                 true
             }
             Self::Boolean | Self::Struct(_, _) => false,
@@ -196,16 +667,18 @@ impl FieldDataType {
             }
         }
     }
-    fn get_element_bit_length(&self) -> usize {
+    /// the bits a field of the type uses when it has no bit length, arrays use it for every element.
+    fn default_bit_length(&self) -> Size {
         match self {
-            Self::Boolean => 1,
-            Self::Char(_,_) => 32,
-            Self::Number(ref size, _, _) => size * 8,
-            Self::Enum(_, ref size, _) => size * 8,
-            Self::Float(ref size, _) => size * 8,
-            Self::Struct(ref size, _) => size * 8,
-            Self::BlockArray(sub, _, _) => sub.as_ref().ty.get_element_bit_length(),
-            Self::ElementArray(sub, _, _) => sub.as_ref().ty.get_element_bit_length(),
+            Self::Boolean => Size::Literal(1),
+            Self::Char(_,_) => Size::Literal(32),
+            Self::Number(ref size, _, _) => Size::Literal(size * 8),
+            Self::Enum(_, ref size, _) => Size::Literal(size * 8),
+            Self::Float(ref size, _, _) => Size::Literal(size * 8),
+            Self::Struct(ref size, _) => size.clone() * 8,
+            Self::BlockArray(ref sub, ref length, _) | Self::ElementArray(ref sub, ref length, _) => {
+                sub.ty.default_bit_length() * length.clone()
+            }
         }
     }
 
@@ -214,207 +687,159 @@ impl FieldDataType {
         attrs: &mut FieldAttrBuilder,
         ident: &Ident,
         default_endianess: &Endianness,
+        generics: &syn::Generics,
+        checks: &mut SizeChecks,
     ) -> syn::Result<FieldDataType> {
         let data_type = match ty {
             Type::Path(ref path) => match attrs.ty {
-                FieldAttrBuilderType::Struct(ref size) => FieldDataType::Struct(
-                    size.clone(),
-                    if let Some(last_segment) = path.path.segments.last() {
-                        let asdf = &last_segment.ident;
-                        quote! {#asdf}
-                    } else {
-                        return Err(syn::Error::new(ident.span(), "field has no Type?"));
-                    },
-                ),
-                FieldAttrBuilderType::Enum(ref size, ref prim) => FieldDataType::Enum(
-                    quote! {#prim},
-                    size.clone(),
-                    if let Some(last_segment) = path.path.segments.last() {
-                        let asdf = &last_segment.ident;
-                        quote! {#asdf}
-                    } else {
-                        return Err(syn::Error::new(ident.span(), "field has no Type?"));
-                    },
-                ),
-                _ => Self::parse_path(&path.path, attrs, ident.span())?,
+                // the full path is kept so types from other modules and generic types work.
+                FieldAttrBuilderType::Struct(ref size) => {
+                    FieldDataType::Struct(size.clone(), quote! {#path})
+                }
+                FieldAttrBuilderType::Enum(ref size, ref prim) => {
+                    FieldDataType::Enum(quote! {#prim}, *size, quote! {#path})
+                }
+                _ => Self::parse_path(path, attrs, ident.span(), generics, checks)?,
             },
             Type::Array(ref array_path) => {
-                // arrays must use a literal for length, because its would be hard any other way.
-                if let syn::Expr::Lit(ref lit_expr) = array_path.len {
-                    if let syn::Lit::Int(ref lit_int) = lit_expr.lit {
-                        if let Ok(array_length) = lit_int.base10_parse::<usize>() {
-                            match attrs.ty {
-                                FieldAttrBuilderType::ElementArray(
-                                    ref element_bit_size,
-                                    ref sub,
-                                ) => {
-                                    attrs.bit_range = match std::mem::take(&mut attrs.bit_range) {
-                                        FieldBuilderRange::Range(ref range) => {
-                                            if range.end < range.start {
-                                                return Err(syn::Error::new(
-                                                    ident.span(),
-                                                    "range end is less than range start",
-                                                ));
-                                            }
-                                            if range.end - range.start
-                                                != *element_bit_size * array_length
-                                            {
-                                                return Err(
-                                                    syn::Error::new(
-                                                        ident.span(),
-                                                        "Element arrays bit range didn't match (element bit size * array length)"
-                                                    )
-                                                );
-                                            }
-                                            FieldBuilderRange::Range(range.clone())
-                                        }
-                                        FieldBuilderRange::LastEnd(ref last_end) => {
-                                            FieldBuilderRange::Range(
-                                                *last_end
-                                                    ..last_end + (array_length * *element_bit_size),
-                                            )
-                                        }
-                                        _ => {
-                                            return Err(syn::Error::new(
-                                                ident.span(),
-                                                "failed getting Range for element array",
-                                            ));
-                                        }
-                                    };
-                                    let mut sub_attrs = attrs.clone();
-                                    if let Type::Array(_) = array_path.elem.as_ref() {
-                                    } else {
-                                        if let Some(ref ty) = sub.as_ref() {
-                                            sub_attrs.ty = ty.clone();
-                                        } else {
-                                            sub_attrs.ty = FieldAttrBuilderType::None;
-                                        }
-                                    }
-                                    let sub_ty = Self::parse(
-                                        &array_path.elem,
-                                        &mut sub_attrs,
-                                        &ident,
-                                        default_endianess,
-                                    )?;
-
-                                    let type_ident = &sub_ty.type_quote();
-                                    FieldDataType::ElementArray(
-                                        Box::new(SubFieldInfo { ty: sub_ty }),
-                                        array_length,
-                                        quote! {[#type_ident;#array_length]},
-                                    )
-                                }
-                                FieldAttrBuilderType::BlockArray(_) => {
-                                    let mut sub_attrs = attrs.clone();
-                                    if let Type::Array(_) = array_path.elem.as_ref() {
-                                    } else {
-                                        sub_attrs.ty = FieldAttrBuilderType::None;
-                                    }
-
-                                    let sub_ty = Self::parse(
-                                        &array_path.elem,
-                                        &mut sub_attrs,
-                                        &ident,
-                                        default_endianess,
-                                    )?;
-                                    attrs.endianness = sub_attrs.endianness;
-                                    let type_ident = &sub_ty.type_quote();
-                                    FieldDataType::BlockArray(
-                                        Box::new(SubFieldInfo { ty: sub_ty }),
-                                        array_length,
-                                        quote! {[#type_ident;#array_length]},
-                                    )
-                                }
-                                FieldAttrBuilderType::Enum(_, _)
-                                | FieldAttrBuilderType::Struct(_) => {
-                                    let mut sub_attrs = attrs.clone();
-                                    if let Type::Array(_) = array_path.elem.as_ref() {
-                                    } else {
-                                        sub_attrs.ty = attrs.ty.clone();
-                                    }
-
-                                    let sub_ty = Self::parse(
-                                        &array_path.elem,
-                                        &mut sub_attrs,
-                                        &ident,
-                                        default_endianess,
-                                    )?;
-                                    attrs.endianness = sub_attrs.endianness;
-                                    let type_ident = &sub_ty.type_quote();
-                                    FieldDataType::BlockArray(
-                                        Box::new(SubFieldInfo { ty: sub_ty }),
-                                        array_length,
-                                        quote! {[#type_ident;#array_length]},
-                                    )
-                                }
-                                FieldAttrBuilderType::None => {
-                                    
-                                    let mut sub_attrs = attrs.clone();
-                                    if let Type::Array(_) = array_path.elem.as_ref() {
-                                    } else {
-                                        sub_attrs.ty = FieldAttrBuilderType::None;
-                                    }
-                                    let sub_ty = Self::parse(
-                                        &array_path.elem,
-                                        &mut sub_attrs,
-                                        &ident,
-                                        default_endianess,
-                                    )?;
-                                    attrs.bit_range = match std::mem::take(&mut attrs.bit_range) {
-                                        FieldBuilderRange::Range(ref range) => {
-                                            if range.end < range.start {
-                                                return Err(syn::Error::new(
-                                                    ident.span(),
-                                                    "range end is less than range start",
-                                                ));
-                                            }
-                                            if range.end - range.start % array_length != 0
-                                            {
-                                                return Err(
-                                                    syn::Error::new(
-                                                        ident.span(),
-                                                        "Array Inference failed because given total bit_length does not split up evenly between elements"
-                                                    )
-                                                );
-                                            }
-                                            FieldBuilderRange::Range(range.clone())
-                                        }
-                                        FieldBuilderRange::LastEnd(ref last_end) => {
-                                            let element_bit_length = sub_ty.get_element_bit_length();
-                                            FieldBuilderRange::Range(
-                                                *last_end
-                                                    ..last_end + (array_length * element_bit_length),
-                                            )
-                                        }
-                                        _ => {
-                                            return Err(syn::Error::new(
-                                                ident.span(),
-                                                "failed getting Range for element array",
-                                            ));
-                                        }
-                                    };
-                                    let type_ident = &sub_ty.type_quote();
-                                    FieldDataType::ElementArray(
-                                        Box::new(SubFieldInfo { ty: sub_ty }),
-                                        array_length,
-                                        quote! {[#type_ident;#array_length]},
-                                    )
-                                }
-                            }
+                let array_length = Self::array_len(array_path, generics)?;
+                let elem_is_array = matches!(array_path.elem.as_ref(), Type::Array(_));
+                match attrs.ty.clone() {
+                    FieldAttrBuilderType::ElementArray(element_bit_size, sub) => {
+                        let mut sub_attrs = attrs.clone();
+                        if elem_is_array {
+                            // the inner array lays out its own elements with element_bit_length.
+                            sub_attrs.bit_range = FieldBuilderRange::LastEnd(Size::Literal(0));
                         } else {
-                            return Err(Error::new(
-                                array_path.bracket_token.span,
-                                "failed parsing array length as literal integer",
-                            ));
+                            sub_attrs.ty = sub.unwrap_or(FieldAttrBuilderType::None);
+                            // nested structures without a struct_size and reduced floats get their
+                            // size from the bits of one element.
+                            sub_attrs.bit_range =
+                                FieldBuilderRange::Range(Size::Literal(0)..element_bit_size);
                         }
-                    } else {
-                        return Err(Error::new(array_path.bracket_token.span, "Couldn't determine Array length, literal array lengths must be an integer"));
+                        let sub_ty = Self::parse(
+                            &array_path.elem,
+                            &mut sub_attrs,
+                            ident,
+                            default_endianess,
+                            generics,
+                            checks,
+                        )?;
+                        let array_bits = Self::parsed_bit_length(&sub_attrs, &sub_ty) * array_length.clone();
+                        attrs.bit_range = match std::mem::take(&mut attrs.bit_range) {
+                            FieldBuilderRange::Range(range) => {
+                                checks.require(range.start.at_most(&range.end), ident.span(), || {
+                                    "range end is less than range start".to_string()
+                                })?;
+                                let length = range.end.clone() - range.start.clone();
+                                checks.require(length.equals(&array_bits), ident.span(), || {
+                                    "Element arrays bit range didn't match (element bit size * array length)".to_string()
+                                })?;
+                                FieldBuilderRange::Range(range)
+                            }
+                            FieldBuilderRange::LastEnd(last_end) => {
+                                FieldBuilderRange::Range(last_end.clone()..last_end + array_bits)
+                            }
+                            _ => {
+                                return Err(syn::Error::new(
+                                    ident.span(),
+                                    "failed getting Range for element array",
+                                ));
+                            }
+                        };
+                        let type_ident = &sub_ty.type_quote();
+                        FieldDataType::ElementArray(
+                            Box::new(SubFieldInfo { ty: sub_ty }),
+                            array_length.clone(),
+                            quote! {[#type_ident;#array_length]},
+                        )
+                    }
+                    FieldAttrBuilderType::BlockArray(_) => {
+                        let mut sub_attrs = attrs.clone();
+                        if !elem_is_array {
+                            sub_attrs.ty = FieldAttrBuilderType::None;
+                        }
+
+                        let sub_ty = Self::parse(
+                            &array_path.elem,
+                            &mut sub_attrs,
+                            ident,
+                            default_endianess,
+                            generics,
+                            checks,
+                        )?;
+                        attrs.endianness = sub_attrs.endianness;
+                        let type_ident = &sub_ty.type_quote();
+                        FieldDataType::BlockArray(
+                            Box::new(SubFieldInfo { ty: sub_ty }),
+                            array_length.clone(),
+                            quote! {[#type_ident;#array_length]},
+                        )
+                    }
+                    FieldAttrBuilderType::Enum(_, _) | FieldAttrBuilderType::Struct(_) => {
+                        let mut sub_attrs = attrs.clone();
+
+                        let sub_ty = Self::parse(
+                            &array_path.elem,
+                            &mut sub_attrs,
+                            ident,
+                            default_endianess,
+                            generics,
+                            checks,
+                        )?;
+                        attrs.endianness = sub_attrs.endianness;
+                        let type_ident = &sub_ty.type_quote();
+                        FieldDataType::BlockArray(
+                            Box::new(SubFieldInfo { ty: sub_ty }),
+                            array_length.clone(),
+                            quote! {[#type_ident;#array_length]},
+                        )
+                    }
+                    FieldAttrBuilderType::None => {
+                        // a bit length is split up evenly between the elements, without one every
+                        // element uses the bits of its type.
+                        let mut sub_attrs = attrs.clone();
+                        sub_attrs.bit_range = match attrs.bit_range {
+                            FieldBuilderRange::Range(ref range) => {
+                                checks.require(range.start.at_most(&range.end), ident.span(), || {
+                                    "range end is less than range start".to_string()
+                                })?;
+                                let length = range.end.clone() - range.start.clone();
+                                checks.require((length.clone() % array_length.clone()).equals(&Size::Literal(0)), ident.span(), || {
+                                    "Array Inference failed because given total bit_length does not split up evenly between elements".to_string()
+                                })?;
+                                FieldBuilderRange::Range(Size::Literal(0)..length / array_length.clone())
+                            }
+                            _ => FieldBuilderRange::LastEnd(Size::Literal(0)),
+                        };
+                        let sub_ty = Self::parse(
+                            &array_path.elem,
+                            &mut sub_attrs,
+                            ident,
+                            default_endianess,
+                            generics,
+                            checks,
+                        )?;
+                        attrs.bit_range = match std::mem::take(&mut attrs.bit_range) {
+                            FieldBuilderRange::Range(range) => FieldBuilderRange::Range(range),
+                            FieldBuilderRange::LastEnd(last_end) => {
+                                let array_bits = Self::parsed_bit_length(&sub_attrs, &sub_ty) * array_length.clone();
+                                FieldBuilderRange::Range(last_end.clone()..last_end + array_bits)
+                            }
+                            _ => {
+                                return Err(syn::Error::new(
+                                    ident.span(),
+                                    "failed getting Range for element array",
+                                ));
+                            }
+                        };
+                        let type_ident = &sub_ty.type_quote();
+                        FieldDataType::ElementArray(
+                            Box::new(SubFieldInfo { ty: sub_ty }),
+                            array_length.clone(),
+                            quote! {[#type_ident;#array_length]},
+                        )
                     }
-                } else {
-                    return Err(Error::new(
-                        array_path.bracket_token.span,
-                        "Couldn't determine Array length, must be literal",
-                    ));
                 }
             }
             _ => {
@@ -423,12 +848,12 @@ impl FieldDataType {
         };
         // if the type is a number and its endianess is None (numbers should have endianess) then we
         // apply the structs default (which might also be None)
-        if data_type.is_number() {
-            if !attrs.endianness.perhaps_endianness(data_type.size()) {
+        if data_type.is_number()
+            && !attrs.endianness.perhaps_endianness(&data_type.size()) {
                 if default_endianess.has_endianness() {
-                    attrs.endianness = Box::new(default_endianess.clone());
+                    *attrs.endianness = default_endianess.clone();
                 } else {
-                    if data_type.size() == 1 {
+                    if data_type.size().literal() == Some(1) {
                         let mut big = Endianness::Big;
                         std::mem::swap(attrs.endianness.as_mut(), &mut big);
                     } else {
@@ -436,29 +861,96 @@ impl FieldDataType {
                     }
                 }
             }
-        }
 
         Ok(data_type)
     }
 
+    /// the bits of one array element once it is parsed, elements without a length use the bits of
+    /// their type.
+    fn parsed_bit_length(attrs: &FieldAttrBuilder, ty: &FieldDataType) -> Size {
+        match attrs.bit_range {
+            FieldBuilderRange::Range(ref range) => range.end.clone() - range.start.clone(),
+            _ => ty.default_bit_length(),
+        }
+    }
+
+    /// the length of an array type. const generic parameters with a default are resolved to the
+    /// default, other lengths naming constants are left to the compiler.
+    fn array_len(array_path: &syn::TypeArray, generics: &syn::Generics) -> syn::Result<Size> {
+        if let Some(value) = Self::const_generic_len(&array_path.len, generics)? {
+            return Ok(Size::Literal(value));
+        }
+        match array_path.len {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(ref lit_int),
+                ..
+            }) => lit_int.base10_parse::<usize>().map(Size::Literal).map_err(|_| {
+                Error::new(
+                    array_path.bracket_token.span.join(),
+                    "failed parsing array length as literal integer",
+                )
+            }),
+            syn::Expr::Lit(_) => Err(Error::new(
+                array_path.bracket_token.span.join(),
+                "Couldn't determine Array length, literal array lengths must be an integer",
+            )),
+            ref len if names_constants(len) => Ok(Size::Const(quote! {#len})),
+            _ => Err(Error::new(
+                array_path.bracket_token.span.join(),
+                "Couldn't determine Array length, must be literal or name constants",
+            )),
+        }
+    }
+
+    /// the default value of the const generic parameter used as an array length. `None` if the
+    /// length is not a const generic parameter.
+    fn const_generic_len(len: &syn::Expr, generics: &syn::Generics) -> syn::Result<Option<usize>> {
+        let len_ident = if let syn::Expr::Path(ref path) = len {
+            if let Some(ident) = path.path.get_ident() {
+                ident
+            } else {
+                return Ok(None);
+            }
+        } else {
+            return Ok(None);
+        };
+        for param in generics.const_params() {
+            if &param.ident == len_ident {
+                return match param.default {
+                    Some(syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(ref lit_int),
+                        ..
+                    })) => Ok(Some(lit_int.base10_parse()?)),
+                    _ => Err(Error::new(
+                        param.ident.span(),
+                        "const generic parameters used as array lengths need a literal default value, the size of the structure is made with it",
+                    )),
+                };
+            }
+        }
+        Ok(None)
+    }
+
     fn parse_path(
-        path: &syn::Path,
+        path: &syn::TypePath,
         attrs: &mut FieldAttrBuilder,
         field_span: Span,
+        generics: &syn::Generics,
+        checks: &mut SizeChecks,
     ) -> syn::Result<FieldDataType> {
         // TODO added attribute consideration for recognizing structs and enums.
         // TODO impl enum logic.
         // TODO impl struct logic
         match attrs.ty {
             FieldAttrBuilderType::None => {
-                if let Some(last_segment) = path.segments.last() {
+                if let Some(last_segment) = path.path.segments.last() {
                     let type_quote = &last_segment.ident;
                     let field_type_name = last_segment.ident.to_string();
                     match field_type_name.as_str() {
                         "bool" => match attrs.bit_range {
-                            FieldBuilderRange::LastEnd(start) => {
+                            FieldBuilderRange::LastEnd(ref start) => {
                                 attrs.bit_range =
-                                    FieldBuilderRange::Range(start.clone()..start + 1);
+                                    FieldBuilderRange::Range(start.clone()..start.clone() + 1);
                                 Ok(FieldDataType::Boolean)
                             }
                             _ => Ok(FieldDataType::Boolean),
@@ -483,14 +975,11 @@ impl FieldDataType {
                             NumberSignage::Signed,
                             quote! {#type_quote},
                         )),
-                        "f32" => {
-                            if let FieldBuilderRange::Range(ref span) = attrs.bit_range {
-                                if 32 != span.end - span.start {
-                                    return Err(syn::Error::new(field_span, format!("f32 must be full sized, if this is a problem for you open an issue.. provided bit length = {}.", span.end - span.start)));
-                                }
-                            }
-                            Ok(FieldDataType::Float(4, quote! {#type_quote}))
-                        }
+                        "f32" => Ok(FieldDataType::Float(
+                            4,
+                            quote! {#type_quote},
+                            FloatFormat::from_attrs(attrs, "f32", 8, 23, field_span, checks)?,
+                        )),
                         "u32" => Ok(FieldDataType::Number(
                             4,
                             NumberSignage::Unsigned,
@@ -502,13 +991,35 @@ impl FieldDataType {
                             quote! {#type_quote},
                         )),
                         "char" => Ok(FieldDataType::Char(4, quote! {#type_quote})),
-                        "f64" => {
-                            if let FieldBuilderRange::Range(ref span) = attrs.bit_range {
-                                if 64 != span.end - span.start {
-                                    return Err(syn::Error::new(field_span, format!("f64 must be full sized, if this is a problem for you open an issue. provided bit length = {}.", span.end - span.start)));
-                                }
+                        "f64" => Ok(FieldDataType::Float(
+                            8,
+                            quote! {#type_quote},
+                            FloatFormat::from_attrs(attrs, "f64", 11, 52, field_span, checks)?,
+                        )),
+                        // the half types are kept as written, they are not in the prelude.
+                        "f16" | "bf16" => {
+                            let half_enabled: bool;
+                            #[cfg(feature = "half")]
+                            {
+                                half_enabled = true;
+                            }
+                            #[cfg(not(feature = "half"))]
+                            {
+                                half_enabled = false;
+                            }
+                            if !half_enabled {
+                                return Err(Error::new(
+                                    field_span,
+                                    "f16 and bf16 fields require the \"half\" feature of bondrewd",
+                                ));
                             }
-                            Ok(FieldDataType::Float(8, quote! {#type_quote}))
+                            let exponent = if field_type_name == "f16" { 5 } else { 8 };
+                            let mantissa = if field_type_name == "f16" { 10 } else { 7 };
+                            Ok(FieldDataType::Float(
+                                2,
+                                quote! {#path},
+                                FloatFormat::from_attrs(attrs, &field_type_name, exponent, mantissa, field_span, checks)?,
+                            ))
                         }
                         "u64" => Ok(FieldDataType::Number(
                             8,
@@ -532,35 +1043,52 @@ impl FieldDataType {
                         )),
                         "usize" | "isize" => Err(Error::new(
                             field_span,
-                            format!("usize and isize are not supported due to ambiguous sizing"),
-                        )),
-                        _ => Err(Error::new(
-                            field_span,
-                            format!("unknown primitive type [{}]", field_type_name),
+                            "usize and isize are not supported due to ambiguous sizing".to_string(),
                         )),
+                        // the size of another structure can not be seen from here, with a bit
+                        // length the field is a structure using the fewest bytes that fit it.
+                        // the size is checked against the structure's impl when compiling.
+                        _ => match attrs.bit_range {
+                            FieldBuilderRange::Range(ref range)
+                                if !matches!(range.start.less_than(&range.end), Condition::Literal(false)) =>
+                            {
+                                Ok(FieldDataType::Struct(
+                                    (range.end.clone() - range.start.clone()).div_ceil(8),
+                                    quote! {#path},
+                                ))
+                            }
+                            // without one the structure uses the `BIT_SIZE` of its impl. the sizes
+                            // are constants next to the impls, which can't name generic parameters.
+                            FieldBuilderRange::LastEnd(ref start) => {
+                                if generics.type_params().any(|param| {
+                                    tokens_contain_ident(quote! {#path}, &param.ident.to_string())
+                                }) {
+                                    return Err(Error::new(
+                                        field_span,
+                                        "nested generic structures need a struct_size or bit_length attribute",
+                                    ));
+                                }
+                                let bits = Size::Const(quote! {<#path as Bitfields<_>>::BIT_SIZE});
+                                attrs.bit_range =
+                                    FieldBuilderRange::Range(start.clone()..start.clone() + bits.clone());
+                                Ok(FieldDataType::Struct(bits.div_ceil(8), quote! {#path}))
+                            }
+                            _ => Err(Error::new(
+                                field_span,
+                                format!("unknown primitive type [{}], nested structures need a struct_size or bit_length attribute", field_type_name),
+                            )),
+                        },
                     }
                 } else {
                     Err(syn::Error::new(field_span, "field has no Type?"))
                 }
             }
-            FieldAttrBuilderType::Struct(size) => {
-                if let Some(ident) = path.get_ident() {
-                    Ok(FieldDataType::Struct(size, quote! {#ident}))
-                } else {
-                    Err(syn::Error::new(field_span, "field has no Type?"))
-                }
-            }
-            FieldAttrBuilderType::Enum(size, ref type_ident) => {
-                if let Some(ident) = path.get_ident() {
-                    Ok(FieldDataType::Enum(
-                        quote! {#type_ident},
-                        size,
-                        quote! {#ident},
-                    ))
-                } else {
-                    Err(syn::Error::new(field_span, "field has no Type?"))
-                }
-            }
+            FieldAttrBuilderType::Struct(ref size) => Ok(FieldDataType::Struct(size.clone(), quote! {#path})),
+            FieldAttrBuilderType::Enum(size, ref type_ident) => Ok(FieldDataType::Enum(
+                quote! {#type_ident},
+                size,
+                quote! {#path},
+            )),
             _ => Err(syn::Error::new(
                 field_span,
                 "Array did not get detected properly, found Path",
@@ -574,6 +1102,9 @@ pub enum ReserveFieldOption {
     NotReserve,
     ReserveField,
     FakeReserveField,
+    /// another view of bits owned by other fields. it has read and write functions but is left out
+    /// of from and into bytes.
+    Overlay,
 }
 
 impl ReserveFieldOption {
@@ -581,7 +1112,7 @@ impl ReserveFieldOption {
         match self {
             Self::FakeReserveField => true,
             Self::ReserveField => true,
-            Self::NotReserve => false,
+            Self::NotReserve | Self::Overlay => false,
         }
     }
 
@@ -589,21 +1120,62 @@ impl ReserveFieldOption {
         match self {
             Self::FakeReserveField => true,
             Self::ReserveField => false,
-            Self::NotReserve => false,
+            Self::NotReserve | Self::Overlay => false,
+        }
+    }
+
+    pub fn is_overlay(&self) -> bool {
+        matches!(self, Self::Overlay)
+    }
+}
+
+/// the value a `const_value` field always holds.
+#[derive(Clone, Debug)]
+pub struct ConstValue {
+    pub value: proc_macro2::TokenStream,
+    /// the field is a `()` marker, its bits are read and written as the smallest unsigned integer
+    /// holding them.
+    pub marker: bool,
+}
+
+impl ConstValue {
+    /// the value the field of the structure is given.
+    pub fn struct_value(&self) -> proc_macro2::TokenStream {
+        if self.marker {
+            quote! {()}
+        } else {
+            self.value.clone()
         }
     }
 }
 
+/// a field holding the checksum of other bytes of the structure.
+#[derive(Clone, Debug)]
+pub struct Checksum {
+    /// the type implementing `BitfieldChecksum`.
+    pub algorithm: proc_macro2::TokenStream,
+    pub start: ChecksumBound,
+    pub end: ChecksumBound,
+    /// the bytes `start..end` stand for, known once every field has its place.
+    pub bytes: Range<Size>,
+}
+
 #[derive(Clone, Debug)]
 pub struct FieldAttrs {
     pub endianness: Box<Endianness>,
-    pub bit_range: Range<usize>,
+    pub bit_range: Range<Size>,
     pub reserve: ReserveFieldOption,
+    /// enum values that land in a catch variant are rejected by `try_from_bytes`.
+    pub strict: bool,
+    /// the field is always written with this value.
+    pub const_value: Option<ConstValue>,
+    /// the field is always written with the checksum of other bytes.
+    pub checksum: Option<Checksum>,
 }
 
 impl FieldAttrs {
-    pub fn bit_length(&self) -> usize {
-        self.bit_range.end - self.bit_range.start
+    pub fn bit_length(&self) -> Size {
+        self.bit_range.end.clone() - self.bit_range.start.clone()
     }
 }
 
@@ -613,27 +1185,31 @@ pub struct SubFieldInfo {
 }
 
 pub struct ElementSubFieldIter {
+    pub outer_name: Ident,
     pub outer_ident: Box<Ident>,
     pub endianness: Box<Endianness>,
+    pub strict: bool,
     // this range is elements in the array, not bit range
     pub range: Range<usize>,
-    pub starting_bit_index: usize,
+    pub starting_bit_index: Size,
     pub ty: FieldDataType,
-    pub outer_name: Ident,
-    pub element_bit_size: usize,
+    pub element_bit_size: Size,
 }
 
 impl Iterator for ElementSubFieldIter {
     type Item = FieldInfo;
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(index) = self.range.next() {
-            let start = self.starting_bit_index + (index * self.element_bit_size);
+            let start = self.starting_bit_index.clone() + self.element_bit_size.clone() * index;
             let attrs = FieldAttrs {
-                bit_range: start..start + self.element_bit_size,
+                bit_range: start.clone()..start + self.element_bit_size.clone(),
                 endianness: self.endianness.clone(),
                 reserve: ReserveFieldOption::NotReserve,
+                strict: self.strict,
+                const_value: None,
+                checksum: None,
             };
-            let name = quote::format_ident!("{}_{}", self.outer_ident.as_ref(), index);
+            let name = quote::format_ident!("{}_{}", self.outer_name, index);
             Some(FieldInfo {
                 ident: self.outer_ident.clone(),
                 attrs,
@@ -646,47 +1222,46 @@ impl Iterator for ElementSubFieldIter {
     }
 }
 
+/// the elements of a block array. every element uses the bits of its type except the first, which
+/// gets what is left.
 #[derive(Debug)]
 pub struct BlockSubFieldIter {
+    pub outer_name: Ident,
     pub outer_ident: Box<Ident>,
     pub endianness: Box<Endianness>,
-    //array length
-    pub length: usize,
-    pub starting_bit_index: usize,
+    pub strict: bool,
+    // this range is elements in the array, not bit range
+    pub range: Range<usize>,
+    pub bit_range: Range<Size>,
     pub ty: FieldDataType,
-    pub outer_name: Ident,
-    pub bit_length: usize,
-    pub total_bytes: usize,
 }
 
 impl Iterator for BlockSubFieldIter {
     type Item = FieldInfo;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.length != 0 {
-            let mut ty_size = self.ty.size() * 8;
-            if self.bit_length % ty_size != 0 {
-                ty_size = self.bit_length % ty_size;
-            }
-            let start = self.starting_bit_index;
-            self.starting_bit_index = start + ty_size;
-            let attrs = FieldAttrs {
-                bit_range: start..(start + ty_size),
-                endianness: self.endianness.clone(),
-                reserve: ReserveFieldOption::NotReserve,
-            };
-            self.bit_length -= ty_size;
-            let index = self.total_bytes - self.length;
-            let name = quote::format_ident!("{}_{}", self.outer_ident.as_ref(), index);
-            self.length -= 1;
-            Some(FieldInfo {
-                ident: self.outer_ident.clone(),
-                attrs,
-                name,
-                ty: self.ty.clone(),
-            })
+        let index = self.range.next()?;
+        let ty_bits = self.ty.size() * 8;
+        let after = self.range.end - 1 - index;
+        let start = if index == 0 {
+            self.bit_range.start.clone()
         } else {
-            None
-        }
+            self.bit_range.end.clone() - ty_bits.clone() * (after + 1)
+        };
+        let attrs = FieldAttrs {
+            bit_range: start..self.bit_range.end.clone() - ty_bits * after,
+            endianness: self.endianness.clone(),
+            reserve: ReserveFieldOption::NotReserve,
+            strict: self.strict,
+            const_value: None,
+            checksum: None,
+        };
+        let name = quote::format_ident!("{}_{}", self.outer_name, index);
+        Some(FieldInfo {
+            ident: self.outer_ident.clone(),
+            attrs,
+            name,
+            ty: self.ty.clone(),
+        })
     }
 }
 
@@ -699,56 +1274,91 @@ pub struct FieldInfo {
 }
 
 impl FieldInfo {
-    fn overlapping(&self, other: &Self) -> bool {
-        // check that self's start is not within other's range
-        if self.attrs.bit_range.start >= other.attrs.bit_range.start {
-            if self.attrs.bit_range.start == other.attrs.bit_range.start
-                || self.attrs.bit_range.start < other.attrs.bit_range.end
-            {
-                return true;
-            }
-        }
-        // check that other's start is not within self's range
-        if other.attrs.bit_range.start >= self.attrs.bit_range.start {
-            if other.attrs.bit_range.start == self.attrs.bit_range.start
-                || other.attrs.bit_range.start < self.attrs.bit_range.end
-            {
-                return true;
-            }
-        }
-        if self.attrs.bit_range.end > other.attrs.bit_range.start {
-            if self.attrs.bit_range.end <= other.attrs.bit_range.end {
-                return true;
-            }
-        }
-        if other.attrs.bit_range.end > self.attrs.bit_range.start {
-            if other.attrs.bit_range.end <= self.attrs.bit_range.end {
-                return true;
-            }
-        }
-        false
+    fn overlapping(&self, other: &Self) -> Condition {
+        self.attrs
+            .bit_range
+            .start
+            .less_than(&other.attrs.bit_range.end)
+            .and(other.attrs.bit_range.start.less_than(&self.attrs.bit_range.end))
     }
 
     #[inline]
-    pub fn bit_size(&self) -> usize {
-        self.attrs.bit_range.end - self.attrs.bit_range.start
+    pub fn bit_size(&self) -> Size {
+        self.attrs.bit_length()
     }
 
+    /// the byte size of the field's type, only for fields with a `literal_range`.
     #[inline]
-    pub fn struct_byte_size(&self) -> usize {
-        self.ty.size()
+    pub fn struct_byte_size(&self) -> syn::Result<usize> {
+        self.ty.size().literal().ok_or_else(|| {
+            syn::Error::new(self.ident.span(), "the size of this field is only known to the compiler")
+        })
+    }
+
+    /// the bit range of a field whose position does not come from constants.
+    pub fn literal_range(&self) -> syn::Result<Range<usize>> {
+        match (self.attrs.bit_range.start.literal(), self.attrs.bit_range.end.literal()) {
+            (Some(start), Some(end)) => Ok(start..end),
+            _ => Err(syn::Error::new(
+                self.ident.span(),
+                "the position of this field is only known to the compiler",
+            )),
+        }
+    }
+
+    /// true when the position and size of the field are literals, the generated code does the byte
+    /// math for these fields itself. the others are read and written through `bitfield_layout`
+    /// with positions the compiler works out.
+    pub fn has_literal_layout(&self, flip: &Option<Size>) -> bool {
+        self.literal_range().is_ok()
+            && self.ty.size().is_literal()
+            && flip.as_ref().is_none_or(Size::is_literal)
+    }
+
+    /// the `bitfield_layout` function reading or writing the field, `prefix` is `read` or `write`.
+    pub fn layout_fn(&self, prefix: &str) -> Ident {
+        let suffix = match self.attrs.endianness.as_ref() {
+            Endianness::Little => "le",
+            Endianness::Native => "ne",
+            Endianness::Big | Endianness::None => "be",
+        };
+        quote::format_ident!("{}_{}", prefix, suffix)
+    }
+
+    /// the start, length and flip arguments of the `bitfield_layout` functions for the field.
+    pub fn layout_args(
+        &self,
+        flip: &Option<Size>,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let start = &self.attrs.bit_range.start;
+        let length = self.attrs.bit_length();
+        let flip = match flip {
+            Some(ref flip) => quote! {Some(#flip)},
+            None => quote! {None},
+        };
+        (quote! {#start}, quote! {#length}, flip)
+    }
+
+    /// a copy of the field using `endianness`, native fields generate code for both byte orders.
+    pub fn with_endianness(&self, endianness: Endianness) -> Self {
+        let mut field = self.clone();
+        *field.attrs.endianness = endianness;
+        field
     }
 
     pub fn get_element_iter(&self) -> Result<ElementSubFieldIter, syn::Error> {
         if let FieldDataType::ElementArray(ref sub_field, ref array_length, _) = self.ty {
+            let array_length = array_length.literal().ok_or_else(|| {
+                syn::Error::new(self.ident.span(), "array lengths from constants can not be iterated")
+            })?;
             Ok(ElementSubFieldIter {
                 outer_name: self.name.clone(),
                 outer_ident: self.ident.clone(),
                 endianness: self.attrs.endianness.clone(),
-                element_bit_size: (self.attrs.bit_range.end - self.attrs.bit_range.start)
-                    / array_length,
-                starting_bit_index: self.attrs.bit_range.start,
-                range: 0..*array_length,
+                strict: self.attrs.strict,
+                element_bit_size: self.attrs.bit_length() / array_length,
+                starting_bit_index: self.attrs.bit_range.start.clone(),
+                range: 0..array_length,
                 ty: sub_field.ty.clone(),
             })
         } else {
@@ -761,16 +1371,17 @@ impl FieldInfo {
 
     pub fn get_block_iter(&self) -> Result<BlockSubFieldIter, syn::Error> {
         if let FieldDataType::BlockArray(ref sub_field, ref array_length, _) = self.ty {
-            let bit_length = self.attrs.bit_range.end - self.attrs.bit_range.start;
+            let array_length = array_length.literal().ok_or_else(|| {
+                syn::Error::new(self.ident.span(), "array lengths from constants can not be iterated")
+            })?;
             Ok(BlockSubFieldIter {
                 outer_name: self.name.clone(),
                 outer_ident: self.ident.clone(),
                 endianness: self.attrs.endianness.clone(),
-                bit_length,
-                starting_bit_index: self.attrs.bit_range.start,
-                length: array_length.clone(),
+                strict: self.attrs.strict,
+                bit_range: self.attrs.bit_range.clone(),
+                range: 0..array_length,
                 ty: sub_field.ty.clone(),
-                total_bytes: array_length.clone(),
             })
         } else {
             Err(syn::Error::new(
@@ -780,37 +1391,165 @@ impl FieldInfo {
         }
     }
 
-    pub fn from_syn_field(field: &syn::Field, struct_info: &StructInfo) -> syn::Result<Self> {
+    /// the element of an array with a length from a constant at the index held by `index`, the
+    /// generated code loops over the elements instead of unrolling them.
+    pub fn element_at(&self, index: &Ident) -> syn::Result<FieldInfo> {
+        let range = &self.attrs.bit_range;
+        let (sub_field, bit_range) = match self.ty {
+            FieldDataType::ElementArray(ref sub_field, ref array_length, _) => {
+                let element_bits = self.attrs.bit_length() / array_length.clone();
+                let start = range.start.clone() + Size::Const(quote! {#index * #element_bits});
+                (sub_field, start.clone()..start + element_bits)
+            }
+            FieldDataType::BlockArray(ref sub_field, ref array_length, _) => {
+                let ty_bits = sub_field.ty.size() * 8;
+                let end = range.end.clone()
+                    - Size::Const(quote! {(#array_length - 1 - #index) * #ty_bits});
+                let (first, end_quote) = (&range.start, &end);
+                let start = Size::Const(quote! {
+                    if #index == 0 { #first } else { #end_quote - #ty_bits }
+                });
+                (sub_field, start..end)
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    self.ident.span(),
+                    "This field was trying to get used like an array",
+                ))
+            }
+        };
+        Ok(FieldInfo {
+            name: quote::format_ident!("{}_element", self.name),
+            ident: self.ident.clone(),
+            ty: sub_field.ty.clone(),
+            attrs: FieldAttrs {
+                bit_range,
+                endianness: self.attrs.endianness.clone(),
+                reserve: ReserveFieldOption::NotReserve,
+                strict: self.attrs.strict,
+                const_value: None,
+                checksum: None,
+            },
+        })
+    }
+
+    pub fn from_syn_field(
+        field: &syn::Field,
+        struct_info: &StructInfo,
+        checks: &mut SizeChecks,
+    ) -> syn::Result<Self> {
         let ident: Box<Ident> = if let Some(ref name) = field.ident {
             Box::new(name.clone())
         } else {
             return Err(Error::new(Span::call_site(), "all fields must be named"));
         };
-        // parse all attrs. which will also give us the bit locations
-        let mut attrs_builder =
-            FieldAttrBuilder::parse(&field, struct_info.fields.last(), ident.clone())?;
+        // parse all attrs. which will also give us the bit locations. overlays do not move the
+        // fields after them.
+        let last_field = struct_info
+            .fields
+            .iter()
+            .rev()
+            .find(|field| !field.attrs.reserve.is_overlay());
+        let mut attrs_builder = FieldAttrBuilder::parse(field, last_field, ident.clone(), checks)?;
+        if attrs_builder.overlay || attrs_builder.alias_of.is_some() {
+            Self::place_overlay(&mut attrs_builder, struct_info, &ident)?;
+        }
+        let inferred_start = match attrs_builder.bit_range {
+            FieldBuilderRange::LastEnd(ref start) => start.clone(),
+            _ => Size::Literal(0),
+        };
+        let const_value = attrs_builder.const_value.take();
+        let checksum = attrs_builder.checksum.take();
+        let over = attrs_builder.over.take();
+        let marker = matches!(field.ty, syn::Type::Tuple(ref tuple) if tuple.elems.is_empty());
+        let marker_ty: syn::Type;
+        let field_ty = if marker && const_value.is_some() {
+            marker_ty = Self::marker_type(&attrs_builder, &ident, checks)?;
+            &marker_ty
+        } else {
+            &field.ty
+        };
         // check the field for supported types.
         let data_type = FieldDataType::parse(
-            &field.ty,
+            field_ty,
             &mut attrs_builder,
             &ident,
             &struct_info.default_endianess,
+            &struct_info.generics,
+            checks,
         )?;
 
+        let has_float_attrs = attrs_builder.exponent_bits.is_some()
+            || attrs_builder.rounding.is_some()
+            || attrs_builder.scale.is_some()
+            || attrs_builder.offset.is_some()
+            || attrs_builder.q.is_some();
         let attr_result: std::result::Result<FieldAttrs, TryFromAttrBuilderError> =
             attrs_builder.try_into();
 
-        let attrs = match attr_result {
+        let mut attrs = match attr_result {
             Ok(attr) => attr,
             Err(fix_me) => {
-                let mut start = 0;
-                if let Some(last_value) = struct_info.fields.last() {
-                    start = last_value.attrs.bit_range.end;
-                }
-                fix_me.fix(start..start + (data_type.size() * 8))
+                fix_me.fix(inferred_start.clone()..inferred_start + (data_type.size() * 8))
             }
         };
+        Self::check_type_bits(&data_type, &attrs.bit_length(), &ident, checks)?;
 
+        if attrs.strict && !data_type.is_enum() {
+            return Err(Error::new(
+                ident.span(),
+                "strict can only be used on enum fields",
+            ));
+        }
+        if has_float_attrs && !data_type.is_float() {
+            return Err(Error::new(
+                ident.span(),
+                "exponent_bits, rounding, scale, offset and q can only be used on float fields",
+            ));
+        }
+        if let Some(value) = const_value {
+            if !matches!(data_type, FieldDataType::Number(..) | FieldDataType::Boolean) {
+                return Err(Error::new(
+                    ident.span(),
+                    "const_value can only be used on integer and bool fields, or () with a bit_length",
+                ));
+            }
+            if !matches!(attrs.reserve, ReserveFieldOption::NotReserve) {
+                return Err(Error::new(
+                    ident.span(),
+                    "const_value can not be used on reserve or overlay fields",
+                ));
+            }
+            attrs.const_value = Some(ConstValue { value, marker });
+        }
+        if let Some(algorithm) = checksum {
+            if !matches!(data_type, FieldDataType::Number(_, NumberSignage::Unsigned, _)) {
+                return Err(Error::new(
+                    ident.span(),
+                    "checksum can only be used on unsigned integer fields",
+                ));
+            }
+            if !matches!(attrs.reserve, ReserveFieldOption::NotReserve) || attrs.const_value.is_some() {
+                return Err(Error::new(
+                    ident.span(),
+                    "checksum can not be used on reserve, overlay or const_value fields",
+                ));
+            }
+            // without over the checksum covers every byte before the field.
+            let (start, end) =
+                over.unwrap_or((ChecksumBound::Byte(0), ChecksumBound::Field(ident.as_ref().clone())));
+            attrs.checksum = Some(Checksum {
+                algorithm,
+                start,
+                end,
+                bytes: Size::Literal(0)..Size::Literal(0),
+            });
+        } else if over.is_some() {
+            return Err(Error::new(
+                ident.span(),
+                "over can only be used along with checksum",
+            ));
+        }
         // construct the field we are parsed.
         let new_field = FieldInfo {
             name: ident.as_ref().clone(),
@@ -819,17 +1558,209 @@ impl FieldInfo {
             attrs,
         };
         // check to verify there are no overlapping bit ranges from previously parsed fields.
-        for (parsed_field, i) in struct_info.fields.iter().zip(0..struct_info.fields.len()) {
-            if parsed_field.overlapping(&new_field) {
-                return Err(Error::new(
-                    Span::call_site(),
-                    format!("fields {} and {} overlap", i, struct_info.fields.len()),
-                ));
+        for parsed_field in struct_info.fields.iter() {
+            if new_field.attrs.reserve.is_overlay() || parsed_field.attrs.reserve.is_overlay() {
+                continue;
             }
+            let (old, new) = (&parsed_field.attrs.bit_range, &new_field.attrs.bit_range);
+            checks.require(parsed_field.overlapping(&new_field).not(), ident.span(), || {
+                format!(
+                    "fields {} (bits {}..{}) and {} (bits {}..{}) overlap",
+                    parsed_field.ident, old.start, old.end, ident, new.start, new.end
+                )
+            })?;
         }
 
         Ok(new_field)
     }
+
+    /// numbers, enums, chars and floats can not use more bits than their type, and nested
+    /// structures more bits than their bytes. arrays check their elements.
+    fn check_type_bits(
+        ty: &FieldDataType,
+        bits: &Size,
+        ident: &Ident,
+        checks: &mut SizeChecks,
+    ) -> syn::Result<()> {
+        match ty {
+            FieldDataType::Number(size, _, _)
+            | FieldDataType::Enum(_, size, _)
+            | FieldDataType::Char(size, _)
+            | FieldDataType::Float(size, _, _) => {
+                checks.require(bits.at_most(&Size::Literal(size * 8)), ident.span(), || {
+                    format!("{} has more bits than its type", ident)
+                })
+            }
+            FieldDataType::Struct(ref size, _) => {
+                checks.require(bits.at_most(&(size.clone() * 8)), ident.span(), || {
+                    format!("{} has more bits than the structure it holds", ident)
+                })
+            }
+            FieldDataType::ElementArray(_, ref length, _)
+            | FieldDataType::BlockArray(_, ref length, _)
+                if length.literal() == Some(0) =>
+            {
+                Ok(())
+            }
+            FieldDataType::ElementArray(ref sub, ref length, _) => {
+                Self::check_type_bits(&sub.ty, &(bits.clone() / length.clone()), ident, checks)
+            }
+            FieldDataType::BlockArray(ref sub, ref length, _) => {
+                let ty_bits = sub.ty.size() * 8;
+                checks.require(bits.at_most(&(ty_bits.clone() * length.clone())), ident.span(), || {
+                    format!("{} has more bits than its type", ident)
+                })?;
+                // the first element gets the bits the others leave.
+                checks.require((ty_bits * (length.clone() - 1)).less_than(bits), ident.span(), || {
+                    format!("{} does not have a bit for every element of the block array", ident)
+                })
+            }
+            FieldDataType::Boolean => Ok(()),
+        }
+    }
+
+    /// the type a `()` field with a `const_value` is read and written as.
+    fn marker_type(
+        attrs_builder: &FieldAttrBuilder,
+        ident: &Ident,
+        checks: &mut SizeChecks,
+    ) -> syn::Result<syn::Type> {
+        let bits = match attrs_builder.bit_range {
+            FieldBuilderRange::Range(ref range) => range.end.clone() - range.start.clone(),
+            _ => {
+                return Err(Error::new(
+                    ident.span(),
+                    "a () field with a const_value needs a bit_length",
+                ))
+            }
+        };
+        // a length from a constant is read as the largest integer.
+        let bits = match bits {
+            Size::Literal(bits) => bits,
+            Size::Const(_) => {
+                checks.require(bits.at_most(&Size::Literal(128)), ident.span(), || {
+                    "a () field with a const_value can not use more than 128 bits".to_string()
+                })?;
+                128
+            }
+        };
+        if let Some(size) = [8, 16, 32, 64, 128].into_iter().find(|size| *size >= bits) {
+            let ty = quote::format_ident!("u{}", size);
+            Ok(syn::parse_quote! {#ty})
+        } else {
+            Err(Error::new(
+                ident.span(),
+                "a () field with a const_value can not use more than 128 bits",
+            ))
+        }
+    }
+
+    /// a const block checking the `const_value` of the field fits in its bits.
+    pub fn const_value_assertion(&self) -> Option<proc_macro2::TokenStream> {
+        let value = &self.attrs.const_value.as_ref()?.value;
+        let bits = match self.attrs.bit_length() {
+            Size::Literal(bits) => bits,
+            bits => return self.const_value_const_assertion(value, &bits),
+        };
+        let message = format!("the const_value of {} does not fit in {} bits", self.ident, bits);
+        match self.ty {
+            FieldDataType::Number(size, NumberSignage::Unsigned, _) if bits < size * 8 => Some(quote! {
+                const { assert!((#value as u128) >> #bits == 0, #message) };
+            }),
+            FieldDataType::Number(size, NumberSignage::Signed, _) if bits < size * 8 => {
+                let min = proc_macro2::Literal::i128_suffixed(-(1i128 << (bits - 1)));
+                let max = proc_macro2::Literal::i128_suffixed((1i128 << (bits - 1)) - 1);
+                Some(quote! {
+                    const { assert!(#min <= (#value as i128) && (#value as i128) <= #max, #message) };
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// [`Self::const_value_assertion`] for a field with a length from constants, the check is
+    /// skipped when the bits cover the whole type.
+    fn const_value_const_assertion(
+        &self,
+        value: &proc_macro2::TokenStream,
+        bits: &Size,
+    ) -> Option<proc_macro2::TokenStream> {
+        let message = format!("the const_value of {} does not fit in {} bits", self.ident, bits)
+            .replace('{', "{{")
+            .replace('}', "}}");
+        match self.ty {
+            FieldDataType::Number(size, NumberSignage::Unsigned, _) => {
+                let type_bits = size * 8;
+                Some(quote! {
+                    const { assert!(#bits >= #type_bits || (#value as u128) >> #bits == 0, #message) };
+                })
+            }
+            FieldDataType::Number(size, NumberSignage::Signed, _) => {
+                let type_bits = size * 8;
+                Some(quote! {
+                    const {
+                        assert!(
+                            #bits >= #type_bits || {
+                                let half = 1i128 << (#bits - 1);
+                                -half <= (#value as i128) && (#value as i128) < half
+                            },
+                            #message
+                        )
+                    };
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// overlays need a position from `bits`, an alias starts where the field it aliases starts.
+    fn place_overlay(
+        attrs_builder: &mut FieldAttrBuilder,
+        struct_info: &StructInfo,
+        ident: &Ident,
+    ) -> syn::Result<()> {
+        if attrs_builder.reserve {
+            return Err(Error::new(
+                ident.span(),
+                "reserve fields can not be overlays",
+            ));
+        }
+        let target = if let Some(ref target) = attrs_builder.alias_of {
+            target
+        } else if attrs_builder.explicit_bits {
+            return Ok(());
+        } else {
+            return Err(Error::new(
+                ident.span(),
+                "overlay fields need a bits range, or use alias_of = \"field\" to start where another field starts",
+            ));
+        };
+        if attrs_builder.explicit_bits {
+            return Err(Error::new(
+                ident.span(),
+                format!("alias_of and bits can not be used together, {} starts where {} starts", ident, target),
+            ));
+        }
+        let start = if let Some(target_field) = struct_info
+            .fields
+            .iter()
+            .find(|field| field.ident.as_ref() == target)
+        {
+            target_field.attrs.bit_range.start.clone()
+        } else {
+            return Err(Error::new(
+                target.span(),
+                format!("alias_of field {} was not found, it has to be declared before {}", target, ident),
+            ));
+        };
+        attrs_builder.bit_range = match attrs_builder.bit_range {
+            FieldBuilderRange::Range(ref range) => {
+                FieldBuilderRange::Range(start.clone()..start + (range.end.clone() - range.start.clone()))
+            }
+            _ => FieldBuilderRange::LastEnd(start),
+        };
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -839,7 +1770,7 @@ pub enum StructEnforcement {
     /// enforce the BIT_SIZE equals BYTE_SIZE * 8
     EnforceFullBytes,
     /// enforce an amount of bits total that need to be used.
-    EnforceBitAmount(usize),
+    EnforceBitAmount(Size),
 }
 
 pub struct StructInfo {
@@ -853,134 +1784,318 @@ pub struct StructInfo {
     pub enforcement: StructEnforcement,
     pub fields: Vec<FieldInfo>,
     pub default_endianess: Endianness,
-    pub fill_bits: Option<usize>,
+    pub fill_bits: Option<Size>,
     pub vis: syn::Visibility,
+    /// the last field when it has a length read from another field, it is not in `fields`.
+    pub trailing: Option<TrailingFieldInfo>,
+    pub generics: syn::Generics,
+    /// generate `_le` and `_be` versions of the byte functions, for fields without an endianness.
+    pub runtime_endianness: bool,
+    /// bits no field uses are reserved instead of being an error.
+    pub allow_gaps: bool,
+    /// implement `Serialize` and `Deserialize` through `bondrewd::packed_serde::as_bytes`.
+    pub serde_bytes: bool,
+    /// the bits of the structure with fill_bytes, worked out once the fields are parsed.
+    pub bits: Size,
+    /// the checks on sizes from constants the compiler has to make.
+    pub size_checks: SizeChecks,
 }
 
 impl StructInfo {
-    pub fn total_bits(&self) -> usize {
-        let mut total: usize = 0;
-        for field in self.fields.iter() {
-            total += field.attrs.bit_length();
+    /// the bits used by the fields that are not overlays.
+    pub fn used_bits(&self) -> Size {
+        self.fields
+            .iter()
+            .filter(|field| !field.attrs.reserve.is_overlay())
+            .fold(Size::Literal(0), |total, field| total + field.attrs.bit_length())
+    }
+
+    /// `BIT_SIZE`, when it comes from constants this is the constant `size_consts_quote` defines.
+    pub fn total_bits(&self) -> Size {
+        if self.bits.is_literal() {
+            self.bits.clone()
+        } else {
+            Size::Const(self.size_const("BITS").into_token_stream())
         }
-        total
     }
 
-    pub fn total_bytes(&self) -> usize {
-        (self.total_bits() as f64 / 8.0f64).ceil() as usize
+    pub fn total_bytes(&self) -> Size {
+        if self.bits.is_literal() {
+            self.bits.div_ceil(8)
+        } else {
+            Size::Const(self.size_const("BYTES").into_token_stream())
+        }
     }
-    fn parse_struct_attrs_meta(info: &mut StructInfo, meta: Meta) -> Result<(), syn::Error> {
-        match meta {
-            Meta::NameValue(value) => {
-                if value.path.is_ident("read_from") {
-                    if let Lit::Str(val) = value.lit {
-                        match val.value().as_str() {
-                            "lsb0" => info.lsb_zero = true,
-                            "msb0" => info.lsb_zero = false,
-                            _ => return Err(Error::new(
-                                val.span(),
-                                "Expected literal str \"lsb0\" or \"msb0\" for read_from attribute.",
-                            )),
-                        }
-                    }
-                } else if value.path.is_ident("default_endianness") {
-                    if let Lit::Str(val) = value.lit {
-                        match val.value().as_str() {
-                            "le" | "lsb" | "little" | "lil" => {
-                                info.default_endianess = Endianness::Little
-                            }
-                            "be" | "msb" | "big" => info.default_endianess = Endianness::Big,
-                            "ne" | "native" => info.default_endianess = Endianness::None,
-                            _ => {}
-                        }
-                    }
-                } else if value.path.is_ident("enforce_bytes") {
-                    if let Lit::Int(val) = value.lit {
-                        match val.base10_parse::<usize>() {
-                            Ok(value) => {
-                                info.enforcement = StructEnforcement::EnforceBitAmount(value * 8);
-                            }
-                            Err(err) => {
-                                return Err(syn::Error::new(
-                                    info.name.span(),
-                                    format!("failed parsing enforce_bytes value [{}]", err),
-                                ))
-                            }
-                        }
-                    }
-                } else if value.path.is_ident("enforce_bits") {
-                    if let Lit::Int(val) = value.lit {
-                        match val.base10_parse::<usize>() {
-                            Ok(value) => {
-                                info.enforcement = StructEnforcement::EnforceBitAmount(value);
-                            }
-                            Err(err) => {
-                                return Err(syn::Error::new(
-                                    info.name.span(),
-                                    format!("failed parsing enforce_bits value [{}]", err),
-                                ))
-                            }
-                        }
-                    }
-                } else if value.path.is_ident("fill_bytes") {
-                    if let Lit::Int(val) = value.lit {
-                        match val.base10_parse::<usize>() {
-                            Ok(value) => {
-                                if info.fill_bits.is_none() {
-                                    info.fill_bits = Some(value * 8);
-                                } else {
-                                    return Err(syn::Error::new(
-                                        info.name.span(),
-                                        format!("multiple fill_bits values"),
-                                    ));
-                                }
-                            }
-                            Err(err) => {
-                                return Err(syn::Error::new(
-                                    info.name.span(),
-                                    format!("failed parsing fill_bits value [{}]", err),
-                                ))
-                            }
-                        }
+
+    /// `BONDREWD_{NAME}_{suffix}`, a module level constant holding a size of the structure.
+    pub fn size_const(&self, suffix: &str) -> Ident {
+        let mut name = String::from("BONDREWD_");
+        for (i, c) in self.name.to_string().chars().enumerate() {
+            if c.is_uppercase() && i != 0 {
+                name.push('_');
+            }
+            name.push(c.to_ascii_uppercase());
+        }
+        quote::format_ident!("{}_{}", name, suffix)
+    }
+
+    /// the constants `total_bits` and `total_bytes` name and the assertions on sizes from
+    /// constants, nothing for structures with literal sizes.
+    pub fn size_consts_quote(&self) -> proc_macro2::TokenStream {
+        let checks = self.size_checks.quote();
+        if self.bits.is_literal() {
+            return checks;
+        }
+        let bits = &self.bits;
+        let bits_const = self.size_const("BITS");
+        let bytes_const = self.size_const("BYTES");
+        quote! {
+            #[doc(hidden)]
+            const #bits_const: usize = #bits;
+            #[doc(hidden)]
+            const #bytes_const: usize = #bits_const.div_ceil(8);
+            #checks
+        }
+    }
+    /// parses one item of a `#[bondrewd(...)]` list on the structure.
+    fn parse_struct_attrs_item(info: &mut StructInfo, item: AttrItem) -> Result<(), syn::Error> {
+        let key = item.name();
+        match key.as_str() {
+            "read_from" => {
+                if let Some(val) = item.lit_str() {
+                    match val.value().as_str() {
+                        "lsb0" => info.lsb_zero = true,
+                        "msb0" => info.lsb_zero = false,
+                        _ => return Err(Error::new(
+                            val.span(),
+                            "Expected literal str \"lsb0\" or \"msb0\" for read_from attribute.",
+                        )),
                     }
+                } else {
+                    return Err(Error::new(
+                        item.span(),
+                        "read_from must use a literal str, ex. read_from = \"msb0\"",
+                    ));
                 }
             }
-            Meta::Path(value) => {
-                if let Some(ident) = value.get_ident() {
-                    match ident.to_string().as_str() {
-                        "reverse" => {
-                            info.flip = true;
-                        }
-                        "enforce_full_bytes" => {
-                            info.enforcement = StructEnforcement::EnforceFullBytes;
-                        }
-                        _ => {}
-                    }
+            "default_endianness" => {
+                if let Some(val) = item.lit_str() {
+                    info.default_endianess = Endianness::from_lit(val)?;
+                } else {
+                    return Err(Error::new(
+                        item.span(),
+                        "default_endianness must use a literal str, ex. default_endianness = \"be\"",
+                    ));
                 }
             }
-            Meta::List(meta_list) => {
-                if meta_list.path.is_ident("bondrewd") {
-                    for nested_meta in meta_list.nested {
-                        match nested_meta {
-                            NestedMeta::Meta(meta) => {
-                                Self::parse_struct_attrs_meta(info, meta)?;
-                            }
-                            NestedMeta::Lit(_) => {}
-                        }
-                    }
+            "enforce_bytes" => {
+                info.enforcement = StructEnforcement::EnforceBitAmount(item.size_value()? * 8);
+            }
+            "enforce_bits" => {
+                info.enforcement = StructEnforcement::EnforceBitAmount(item.size_value()?);
+            }
+            "fill_bytes" => {
+                if info.fill_bits.is_none() {
+                    info.fill_bits = Some(item.size_value()? * 8);
+                } else {
+                    return Err(syn::Error::new(
+                        info.name.span(),
+                        "multiple fill_bits values".to_string(),
+                    ));
                 }
             }
+            "reverse" => {
+                info.flip = true;
+            }
+            "enforce_full_bytes" => {
+                info.enforcement = StructEnforcement::EnforceFullBytes;
+            }
+            "runtime_endianness" => {
+                info.runtime_endianness = true;
+            }
+            "allow_gaps" => {
+                info.allow_gaps = true;
+            }
+            "serde_bytes" => {
+                check_serde_feature(&item)?;
+                info.serde_bytes = true;
+            }
+            // id_bit_length is read by the enum parsing.
+            _ => {}
         }
         Ok(())
     }
-    pub fn parse(input: &DeriveInput) -> syn::Result<StructInfo> {
-        // get the struct, error out if not a struct
-        let data = match input.data {
-            syn::Data::Struct(ref data) => data,
-            _ => {
-                return Err(Error::new(Span::call_site(), "input must be a struct"));
+    /// the structure's type with its generic arguments, const generic parameters with a default
+    /// are filled in with the default.
+    pub fn type_quote(&self) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        if self.generics.params.is_empty() {
+            return quote! {#name};
+        }
+        let args = self.generics.params.iter().map(|param| match param {
+            syn::GenericParam::Lifetime(def) => {
+                let lifetime = &def.lifetime;
+                quote! {#lifetime}
             }
-        };
+            syn::GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                quote! {#ident}
+            }
+            syn::GenericParam::Const(konst) => {
+                if let Some(ref default) = konst.default {
+                    quote! {{#default}}
+                } else {
+                    let ident = &konst.ident;
+                    quote! {#ident}
+                }
+            }
+        });
+        quote! {#name<#(#args),*>}
+    }
+
+    /// generics for impls of the structure. const generic parameters with a default are left out
+    /// because `type_quote` fills them in. type parameters used as nested structures or enums are
+    /// bounded by the bondrewd traits the generated code uses, `extra` can add a bound depending on
+    /// the field type.
+    pub fn impl_generics(
+        &self,
+        extra: impl Fn(&FieldDataType) -> Option<proc_macro2::TokenStream>,
+    ) -> syn::Generics {
+        let try_fns: bool;
+        #[cfg(not(feature = "try_fns"))]
+        {
+            try_fns = false;
+        }
+        #[cfg(feature = "try_fns")]
+        {
+            try_fns = true;
+        }
+        let mut generics = self.generics.clone();
+        generics.params = generics
+            .params
+            .into_iter()
+            .filter(|param| !matches!(param, syn::GenericParam::Const(konst) if konst.default.is_some()))
+            .collect();
+        let type_params: Vec<Ident> = generics.type_params().map(|ty| ty.ident.clone()).collect();
+        let mut predicates: Vec<syn::WherePredicate> = Vec::new();
+        for field in self.fields.iter() {
+            let mut ty = &field.ty;
+            while let FieldDataType::ElementArray(ref sub, _, _) | FieldDataType::BlockArray(ref sub, _, _) = ty {
+                ty = &sub.ty;
+            }
+            let (mut bounds, type_quote) = match ty {
+                FieldDataType::Struct(ref size, ref type_quote) => {
+                    let size = size.const_arg();
+                    let mut bounds = vec![quote! {Bitfields<#size>}];
+                    if try_fns {
+                        bounds.push(quote! {TryBitfields<#size>});
+                    }
+                    (bounds, type_quote)
+                }
+                FieldDataType::Enum(ref prim, _, ref type_quote) => {
+                    let mut bounds = vec![quote! {BitfieldEnum<Primitive = #prim>}];
+                    if try_fns {
+                        bounds.push(quote! {TryBitfieldEnum});
+                    }
+                    (bounds, type_quote)
+                }
+                _ => continue,
+            };
+            if let Some(extra) = extra(ty) {
+                bounds.push(extra);
+            }
+            let type_string = type_quote.to_string();
+            if type_params.iter().any(|param| *param == type_string) {
+                predicates.push(syn::parse_quote! {#type_quote: #(#bounds)+*});
+            }
+        }
+        if !predicates.is_empty() {
+            generics.make_where_clause().predicates.extend(predicates);
+        }
+        generics
+    }
+
+    /// `impl_generics` with `param` added, for the types generated along with the structure.
+    pub fn wrapper_generics(
+        &self,
+        param: syn::GenericParam,
+        extra: impl Fn(&FieldDataType) -> Option<proc_macro2::TokenStream>,
+    ) -> syn::Generics {
+        let mut generics = self.impl_generics(extra);
+        insert_generic_param(&mut generics, param);
+        generics
+    }
+
+    /// compile time checks that nested structures fit in the bits given to them, a nested structure
+    /// with a `bit_length` shorter than its `BIT_SIZE` would lose its last bits. the const blocks
+    /// are evaluated for every type the structure is used with, so generic fields are checked too.
+    pub fn size_assertions(&self) -> proc_macro2::TokenStream {
+        let mut assertions = quote! {};
+        for field in self.fields.iter() {
+            if let Some(assertion) = field.const_value_assertion() {
+                assertions = quote! {
+                    #assertions
+                    #assertion
+                };
+            }
+            let (size, ty, bit_length) = match field.ty {
+                FieldDataType::Struct(ref size, ref ty) => (size, ty, field.attrs.bit_length()),
+                FieldDataType::ElementArray(ref sub, ref length, _) => {
+                    if let FieldDataType::Struct(ref size, ref ty) = sub.ty {
+                        (size, ty, field.attrs.bit_length() / length.clone())
+                    } else {
+                        continue;
+                    }
+                }
+                _ => continue,
+            };
+            let size = size.const_arg();
+            let message = format!(
+                "{} has fewer bits than the structure it holds",
+                field.ident.as_ref()
+            );
+            assertions = quote! {
+                #assertions
+                const { assert!(<#ty as Bitfields<#size>>::BIT_SIZE <= #bit_length, #message) };
+            };
+        }
+        assertions
+    }
+
+    /// the lifetime of the checked slice structures and their generics.
+    pub fn checked_generics(&self) -> (syn::Lifetime, syn::Generics) {
+        let lifetime = syn::Lifetime::new(
+            &format!("'{}", self.unused_ident("a")),
+            proc_macro2::Span::call_site(),
+        );
+        let generics = self.wrapper_generics(
+            syn::GenericParam::Lifetime(syn::LifetimeParam::new(lifetime.clone())),
+            |_| None,
+        );
+        (lifetime, generics)
+    }
+
+    /// `base`, with underscores added until the structure, its generic parameters and the types of
+    /// its fields do not use the name.
+    pub fn unused_ident(&self, base: &str) -> Ident {
+        let mut name = base.to_string();
+        while self.name == name
+            || self
+                .fields
+                .iter()
+                .any(|field| tokens_contain_ident(field.ty.type_quote(), &name))
+            || self.generics.params.iter().any(|param| match param {
+            syn::GenericParam::Lifetime(def) => def.lifetime.ident == name,
+            syn::GenericParam::Type(ty) => ty.ident == name,
+            syn::GenericParam::Const(konst) => konst.ident == name,
+        }) {
+            name.push('_');
+        }
+        quote::format_ident!("{}", name)
+    }
+
+    /// a StructInfo with no fields, only the structure wide attributes parsed.
+    pub fn from_attrs(input: &DeriveInput) -> syn::Result<StructInfo> {
         let mut info = StructInfo {
             name: input.ident.clone(),
             lsb_zero: false,
@@ -990,85 +2105,356 @@ impl StructInfo {
             default_endianess: Endianness::None,
             fill_bits: None,
             vis: input.vis.clone(),
+            trailing: None,
+            generics: input.generics.clone(),
+            runtime_endianness: false,
+            allow_gaps: false,
+            serde_bytes: false,
+            bits: Size::Literal(0),
+            size_checks: SizeChecks::default(),
         };
-        for attr in input.attrs.iter() {
-            let meta = attr.parse_meta()?;
-            Self::parse_struct_attrs_meta(&mut info, meta)?;
+        let keys = if let syn::Data::Enum(_) = input.data {
+            &VARIANTS_ATTRS
+        } else {
+            &STRUCT_ATTRS
+        };
+        for item in bondrewd_attr_items(&input.attrs, "bondrewd", keys)? {
+            Self::parse_struct_attrs_item(&mut info, item)?;
+        }
+        Ok(info)
+    }
+
+    pub fn check_enforcement(&self, bit_size: &Size, checks: &mut SizeChecks) -> syn::Result<()> {
+        match self.enforcement {
+            StructEnforcement::NoRules => {}
+            StructEnforcement::EnforceFullBytes => {
+                checks.require(bit_size.multiple_of(8), self.name.span(), || {
+                    "BIT_SIZE modulus 8 is not zero".to_string()
+                })?;
+            }
+            StructEnforcement::EnforceBitAmount(ref expected_total_bits) => {
+                checks.require(bit_size.equals(expected_total_bits), self.name.span(), || {
+                    format!(
+                        "Bit Enforcement failed because bondrewd detected {} total bits used by defined fields, but the bit enforcement attribute is defined as {} bits.",
+                        bit_size, expected_total_bits
+                    )
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// a reserve field that is not part of the struct, used to pad from `first_bit` to `fill_bits`.
+    pub fn fill_field(first_bit: Size, fill_bits: Size) -> FieldInfo {
+        Self::fake_reserve_field(quote::format_ident!("bondrewd_fill_bits"), first_bit..fill_bits)
+    }
+
+    /// a reserve field that is not part of the struct covering `bit_range`.
+    fn fake_reserve_field(ident: Ident, bit_range: Range<Size>) -> FieldInfo {
+        let fill_bytes_size = (bit_range.end.clone() - bit_range.start.clone()).div_ceil(8);
+        FieldInfo {
+            name: ident.clone(),
+            ident: Box::new(ident),
+            attrs: FieldAttrs {
+                bit_range,
+                endianness: Box::new(Endianness::Big),
+                reserve: ReserveFieldOption::FakeReserveField,
+                strict: false,
+                const_value: None,
+                checksum: None,
+            },
+            ty: FieldDataType::BlockArray(
+                Box::new(SubFieldInfo {
+                    ty: FieldDataType::Number(1, NumberSignage::Unsigned, quote! {u8}),
+                }),
+                fill_bytes_size.clone(),
+                quote! {[u8;#fill_bytes_size]},
+            ),
+        }
+    }
+
+    /// finds the bits before the end of the last field that no field uses. with `allow_gaps` they
+    /// become reserve fields, otherwise they are an error because a forgotten field would be
+    /// silently skipped. positions from constants can not be sorted, so when a field has one the
+    /// fields have to be in the order of their bits.
+    pub fn reserve_gaps(&mut self, checks: &mut SizeChecks) -> syn::Result<()> {
+        let mut fields: Vec<&FieldInfo> = self
+            .fields
+            .iter()
+            .filter(|field| !field.attrs.reserve.is_overlay())
+            .collect();
+        let literal = fields
+            .iter()
+            .all(|field| field.attrs.bit_range.start.is_literal() && field.attrs.bit_range.end.is_literal());
+        if literal {
+            fields.sort_by_key(|field| field.attrs.bit_range.start.literal());
+        }
+        let mut gaps = Vec::new();
+        let mut end = Size::Literal(0);
+        for field in fields {
+            let range = &field.attrs.bit_range;
+            let gap = end.less_than(&range.start);
+            let message = || {
+                format!(
+                    "bits {}..{} before {} are not used by any field, add a reserve field for them or use #[bondrewd(allow_gaps)]",
+                    end, range.start, field.ident
+                )
+            };
+            if !self.allow_gaps {
+                checks.require(gap.not(), field.ident.span(), message)?;
+            } else if !literal {
+                checks.require(end.at_most(&range.start), field.ident.span(), || {
+                    format!("{} starts before the field declared before it ends", field.ident)
+                })?;
+                let ident = quote::format_ident!("bondrewd_gap_bits_before_{}", field.name);
+                gaps.push((ident, end.clone()..range.start.clone()));
+            } else if let (Condition::Literal(true), Some(start)) = (gap, end.literal()) {
+                let ident = quote::format_ident!("bondrewd_gap_bits_{}", start);
+                gaps.push((ident, end.clone()..range.start.clone()));
+            }
+            end = if literal { end.max(&range.end) } else { range.end.clone() };
+        }
+        for (ident, gap) in gaps {
+            self.fields.push(Self::fake_reserve_field(ident, gap));
         }
+        Ok(())
+    }
+
+    /// overlays are views of bits other fields own, so they have to stay inside `bit_size`.
+    pub fn check_overlays(&self, bit_size: &Size, checks: &mut SizeChecks) -> syn::Result<()> {
+        for field in self.fields.iter() {
+            let range = &field.attrs.bit_range;
+            if field.attrs.reserve.is_overlay() {
+                checks.require(range.end.at_most(bit_size), field.ident.span(), || {
+                    format!(
+                        "overlay field {} (bits {}..{}) is outside of the {} bits used by the other fields",
+                        field.ident, range.start, range.end, bit_size
+                    )
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// works out the bytes each checksum covers, which can only be done once every field has its
+    /// final place.
+    pub fn place_checksums(&mut self, checks: &mut SizeChecks) -> syn::Result<()> {
+        let byte_size = self.total_bytes();
+        let mut placed = Vec::new();
+        for (index, field) in self.fields.iter().enumerate() {
+            let checksum = if let Some(ref checksum) = field.attrs.checksum {
+                checksum
+            } else {
+                continue;
+            };
+            if self.flip {
+                return Err(Error::new(
+                    field.ident.span(),
+                    "checksum fields can not be used in reversed structures",
+                ));
+            }
+            let mut byte_of = |bound: &ChecksumBound| -> syn::Result<Size> {
+                match bound {
+                    ChecksumBound::Byte(byte) => Ok(Size::Literal(*byte)),
+                    ChecksumBound::End => Ok(byte_size.clone()),
+                    ChecksumBound::Field(ref name) => {
+                        let other = self
+                            .fields
+                            .iter()
+                            .find(|other| other.ident.as_ref() == name)
+                            .ok_or_else(|| {
+                                Error::new(
+                                    name.span(),
+                                    format!("over of {} names field {} which was not found", field.ident, name),
+                                )
+                            })?;
+                        let start = &other.attrs.bit_range.start;
+                        checks.require(start.multiple_of(8), name.span(), || {
+                            format!(
+                                "{} does not start on a byte boundary so over of {} can not use it",
+                                name, field.ident
+                            )
+                        })?;
+                        Ok(start.clone() / 8)
+                    }
+                }
+            };
+            let bytes = byte_of(&checksum.start)?..byte_of(&checksum.end)?;
+            let fits = bytes.start.less_than(&bytes.end).and(bytes.end.at_most(&byte_size));
+            checks.require(fits, field.ident.span(), || {
+                format!(
+                    "checksum {} covers bytes {}..{}, which is empty or outside of the {} bytes of the structure",
+                    field.ident, bytes.start, bytes.end, byte_size
+                )
+            })?;
+            checks.require(Self::covers(&bytes, field).not(), field.ident.span(), || {
+                format!(
+                    "checksum {} covers bytes {}..{} which hold the checksum itself",
+                    field.ident, bytes.start, bytes.end
+                )
+            })?;
+            placed.push((index, bytes));
+        }
+        for (index, bytes) in placed {
+            if let Some(ref mut checksum) = self.fields[index].attrs.checksum {
+                checksum.bytes = bytes;
+            }
+        }
+        Ok(())
+    }
+
+    /// true if `bytes` hold any of the bits of `field`.
+    fn covers(bytes: &Range<Size>, field: &FieldInfo) -> Condition {
+        let range = &field.attrs.bit_range;
+        bytes
+            .start
+            .less_than(&range.end.div_ceil(8))
+            .and((range.start.clone() / 8).less_than(&bytes.end))
+    }
+
+    /// the checksum fields in the order `into_bytes` fills them, a checksum covering the bytes of
+    /// another checksum comes after it. positions from constants are assumed not to cover each
+    /// other.
+    pub fn checksum_fields(&self) -> Vec<&FieldInfo> {
+        let mut fields: Vec<&FieldInfo> = self
+            .fields
+            .iter()
+            .filter(|field| field.attrs.checksum.is_some())
+            .collect();
+        let covers = |field: &FieldInfo, other: &FieldInfo| {
+            let bytes = &field.attrs.checksum.as_ref().unwrap().bytes;
+            matches!(Self::covers(bytes, other), Condition::Literal(true))
+        };
+        let checksums = fields.clone();
+        fields.sort_by_key(|field| checksums.iter().filter(|other| covers(field, other)).count());
+        fields
+    }
+
+    pub fn parse(input: &DeriveInput) -> syn::Result<StructInfo> {
+        let info = Self::from_attrs(input)?;
+        Self::parse_fields(input, info)
+    }
+
+    /// parses the structure as if its `default_endianness` was `endianness`, fields with their own
+    /// endianness keep it.
+    pub fn parse_with_endianness(
+        input: &DeriveInput,
+        endianness: Endianness,
+    ) -> syn::Result<StructInfo> {
+        let mut info = Self::from_attrs(input)?;
+        info.default_endianess = endianness;
+        Self::parse_fields(input, info)
+    }
+
+    fn parse_fields(input: &DeriveInput, mut info: StructInfo) -> syn::Result<StructInfo> {
+        // get the struct, error out if not a struct
+        let data = match input.data {
+            syn::Data::Struct(ref data) => data,
+            _ => {
+                return Err(Error::new(Span::call_site(), "input must be a struct"));
+            }
+        };
         // get the list of fields in syn form, error out if unit struct (because they have no data, and
         // data packing/analysis don't seem necessary)
         let fields = match data.fields {
-            syn::Fields::Named(ref named_fields) => named_fields.named.iter().map(|x| x.clone()).collect::<Vec<syn::Field>>(),
-            syn::Fields::Unnamed(ref fields) => fields.unnamed.iter().map(|x| x.clone()).collect::<Vec<syn::Field>>(),
+            syn::Fields::Named(ref named_fields) => named_fields.named.iter().cloned().collect::<Vec<syn::Field>>(),
+            syn::Fields::Unnamed(ref fields) => fields.unnamed.iter().cloned().collect::<Vec<syn::Field>>(),
             syn::Fields::Unit => return Err(Error::new(data.struct_token.span, "Packing a Unit Struct (Struct with no data) seems pointless to me, so i didn't write code for it.")),
         };
 
+        let mut fields = fields;
+        if let Some(last) = fields.last() {
+            if let Some(trailing) = TrailingFieldInfo::parse(last)? {
+                info.trailing = Some(trailing);
+                fields.pop();
+            }
+        }
+
         // figure out what the field are and what/where they should be in byte form.
-        let mut bit_size = 0;
+        let mut checks = SizeChecks::default();
         for ref field in fields {
-            let parsed_field = FieldInfo::from_syn_field(field, &info)?;
-            bit_size += parsed_field.bit_size();
+            let parsed_field = FieldInfo::from_syn_field(field, &info, &mut checks)?;
             info.fields.push(parsed_field);
         }
+        info.reserve_gaps(&mut checks)?;
+        // fields do not overlap and gaps are filled, so the sum of the field sizes is where the last
+        // field ends.
+        let bit_size = info.used_bits();
 
-        match info.enforcement {
-            StructEnforcement::NoRules => {}
-            StructEnforcement::EnforceFullBytes => {
-                if bit_size % 8 != 0 {
-                    return Err(syn::Error::new(
-                        info.name.span(),
-                        "BIT_SIZE modulus 8 is not zero",
-                    ));
-                }
-            }
-            StructEnforcement::EnforceBitAmount(expected_total_bits) => {
-                if bit_size != expected_total_bits {
-                    return Err(syn::Error::new(
-                        info.name.span(),
-                        format!(
-                            "Bit Enforcement failed because bondrewd detected {} total bits used by defined fields, but the bit enforcement attribute is defined as {} bits.",
-                            bit_size, expected_total_bits
-                        ),
-                    ));
-                }
-            }
-        }
+        info.check_enforcement(&bit_size, &mut checks)?;
+        info.check_overlays(&bit_size, &mut checks)?;
 
         // add reserve for fill bytes. this happens after bit enforcement because bit_enforcement is for checking user code.
-        if let Some(fill_bits) = info.fill_bits {
-            let first_bit = if let Some(last_range) = info.fields.iter().last() {
-                last_range.attrs.bit_range.end.clone()
-            } else {
-                0_usize
-            };
-            let fill_bytes_size = ((fill_bits - first_bit) as f64 / 8.0_f64).ceil() as usize;
-            let ident = quote::format_ident!("bondrewd_fill_bits");
-            info.fields.push(FieldInfo {
-                name: ident.clone(),
-                ident: Box::new(ident),
-                attrs: FieldAttrs {
-                    bit_range: first_bit..fill_bits,
-                    endianness: Box::new(Endianness::Big),
-                    reserve: ReserveFieldOption::FakeReserveField,
-                },
-                ty: FieldDataType::BlockArray(
-                    Box::new(SubFieldInfo {
-                        ty: FieldDataType::Number(1, NumberSignage::Unsigned, quote! {u8}),
-                    }),
-                    fill_bytes_size,
-                    quote! {[u8;#fill_bytes_size]},
-                ),
-            });
+        if let Some(fill_bits) = info.fill_bits.clone() {
+            checks.require(bit_size.at_most(&fill_bits), info.name.span(), || {
+                format!(
+                    "the fields use {} bits which does not fit in fill_bytes = {}",
+                    bit_size,
+                    fill_bits.clone() / 8
+                )
+            })?;
+            // a fill from constants can not be known to be empty, so it may have no bits.
+            if !matches!(bit_size.less_than(&fill_bits), Condition::Literal(false)) {
+                info.fields.push(Self::fill_field(bit_size, fill_bits));
+            }
         }
+        info.bits = info.used_bits();
 
         if info.lsb_zero {
+            // bit 0 is the last bit of the filled bytes.
+            let bit_size = info.total_bits();
             for ref mut field in info.fields.iter_mut() {
-                field.attrs.bit_range = (bit_size - field.attrs.bit_range.end)
-                    ..(bit_size - field.attrs.bit_range.start);
+                field.attrs.bit_range = (bit_size.clone() - field.attrs.bit_range.end.clone())
+                    ..(bit_size.clone() - field.attrs.bit_range.start.clone());
             }
             info.fields.reverse();
         }
 
+        info.place_checksums(&mut checks)?;
+
+        if let Some(ref trailing) = info.trailing {
+            check_trailing_field(&info, trailing, &mut checks)?;
+        }
+        info.size_checks = checks;
+
         Ok(info)
     }
 }
+
+/// `read_from_bits` and `write_to_bits` for structures using `reverse`. In a bit stream the bits are
+/// in the order they have before the bytes get reversed, so the fields stay next to each other
+/// when `BIT_SIZE` is not a multiple of 8.
+pub fn reversed_bits_fns_quote(struct_size: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {
+        fn read_from_bits(input: &[u8], bit_offset: usize) -> Result<Self, BitfieldSliceError> {
+            let mut bytes = [0u8; #struct_size];
+            bitfield_layout::read_stream(input, bit_offset, Self::BIT_SIZE, &mut bytes)?;
+            bytes.reverse();
+            Ok(Self::from_bytes(bytes))
+        }
+        fn write_to_bits(self, output: &mut [u8], bit_offset: usize) -> Result<(), BitfieldSliceError> {
+            let mut bytes = Self::into_bytes(self);
+            bytes.reverse();
+            bitfield_layout::write_stream(output, bit_offset, Self::BIT_SIZE, &bytes)
+        }
+    }
+}
+
+/// true if `name` is an identifier anywhere in `tokens`.
+pub fn tokens_contain_ident(tokens: proc_macro2::TokenStream, name: &str) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => ident == name,
+        proc_macro2::TokenTree::Group(group) => tokens_contain_ident(group.stream(), name),
+        _ => false,
+    })
+}
+
+/// adds `param` to `generics`, lifetimes have to come before the other parameters.
+pub fn insert_generic_param(generics: &mut syn::Generics, param: syn::GenericParam) {
+    let index = if let syn::GenericParam::Lifetime(_) = param {
+        0
+    } else {
+        generics.lifetimes().count()
+    };
+    generics.params.insert(index, param);
+}
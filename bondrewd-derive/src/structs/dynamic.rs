@@ -0,0 +1,334 @@
+use crate::attrs::{bondrewd_attr_items, AttrItem, FIELD_ATTRS};
+use crate::structs::common::{FieldDataType, NumberSignage, Size, SizeChecks, StructInfo};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+/// How the trailing field stores its bytes.
+#[derive(Clone)]
+pub enum TrailingFieldType {
+    /// `Vec<u8>`, decoding copies the bytes out of the input.
+    Owned,
+    /// `&'a [u8]`, decoding borrows the bytes from the input.
+    Borrowed(syn::Lifetime),
+}
+
+/// The last field of a structure, which has a length read from a field in the header (the fields
+/// before it).
+#[derive(Clone)]
+pub struct TrailingFieldInfo {
+    pub ident: Ident,
+    /// name of the header field holding the length.
+    pub length_from: Ident,
+    /// added to the value of the length field to get the amount of bytes.
+    pub offset: usize,
+    pub ty: TrailingFieldType,
+}
+
+impl TrailingFieldInfo {
+    /// returns `None` if the field does not have a `length_from` attribute.
+    pub fn parse(field: &syn::Field) -> syn::Result<Option<Self>> {
+        let ident = if let Some(ref name) = field.ident {
+            name.clone()
+        } else {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "all fields must be named",
+            ));
+        };
+        let mut length_from: Option<Ident> = None;
+        let mut offset: Option<AttrItem> = None;
+        // only an error if this turns out to be a length_from field.
+        let mut other_attrs = false;
+        for item in bondrewd_attr_items(&field.attrs, "bondrewd", &FIELD_ATTRS)? {
+            match item.name().as_str() {
+                "length_from" => {
+                    if let Some(val) = item.lit_str() {
+                        length_from = Some(format_ident!("{}", val.value()));
+                    } else {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "length_from must be a string literal with the name of the length field",
+                        ));
+                    }
+                }
+                "offset" => offset = Some(item),
+                _ => other_attrs = true,
+            }
+        }
+        // without length_from an offset belongs to a scaled field.
+        let length_from = match length_from {
+            Some(length_from) => length_from,
+            None => return Ok(None),
+        };
+        let offset = match offset {
+            Some(item) => Some(item.usize_value()?),
+            None => None,
+        };
+        if other_attrs {
+            return Err(syn::Error::new(
+                ident.span(),
+                "only length_from and offset can be used on a length_from field",
+            ));
+        }
+        let ty = match field.ty {
+            syn::Type::Reference(ref reference) => {
+                if is_u8_slice(&reference.elem) && reference.mutability.is_none() {
+                    reference
+                        .lifetime
+                        .clone()
+                        .map(TrailingFieldType::Borrowed)
+                } else {
+                    None
+                }
+            }
+            syn::Type::Path(ref path) => {
+                if let Some(last) = path.path.segments.last() {
+                    match last.arguments {
+                        syn::PathArguments::AngleBracketed(ref args)
+                            if last.ident == "Vec" && args.args.len() == 1 =>
+                        {
+                            if let Some(syn::GenericArgument::Type(syn::Type::Path(ref elem))) =
+                                args.args.first()
+                            {
+                                if elem.path.is_ident("u8") {
+                                    Some(TrailingFieldType::Owned)
+                                } else {
+                                    None
+                                }
+                            } else {
+                                None
+                            }
+                        }
+                        _ => None,
+                    }
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        if let Some(ty) = ty {
+            Ok(Some(Self {
+                ident,
+                length_from,
+                offset: offset.unwrap_or(0),
+                ty,
+            }))
+        } else {
+            Err(syn::Error::new(
+                ident.span(),
+                "length_from fields must be a Vec<u8> or &'a [u8]",
+            ))
+        }
+    }
+}
+
+fn is_u8_slice(ty: &syn::Type) -> bool {
+    if let syn::Type::Slice(ref slice) = ty {
+        if let syn::Type::Path(ref elem) = *slice.elem {
+            return elem.path.is_ident("u8");
+        }
+    }
+    false
+}
+
+/// checks the header can hold the length and that the trailing field starts on a byte.
+pub fn check_trailing_field(
+    info: &StructInfo,
+    trailing: &TrailingFieldInfo,
+    checks: &mut SizeChecks,
+) -> syn::Result<()> {
+    checks.require(info.bits.multiple_of(8), trailing.ident.span(), || {
+        "the fields before a length_from field must use whole bytes, try fill_bytes or enforce_full_bytes".to_string()
+    })?;
+    if info.fields.iter().any(|field| field.attrs.checksum.is_some()) {
+        return Err(syn::Error::new(
+            trailing.ident.span(),
+            "checksum fields can not be used along with a length_from field",
+        ));
+    }
+    let length_field = if let Some(field) = info
+        .fields
+        .iter()
+        .find(|field| field.ident.as_ref() == &trailing.length_from)
+    {
+        field
+    } else {
+        return Err(syn::Error::new(
+            trailing.length_from.span(),
+            format!("length_from field \"{}\" was not found", trailing.length_from),
+        ));
+    };
+    if length_field.attrs.reserve.is_reserve_field() {
+        return Err(syn::Error::new(
+            trailing.length_from.span(),
+            "length_from can not point to a reserve field",
+        ));
+    }
+    if length_field.attrs.reserve.is_overlay() {
+        return Err(syn::Error::new(
+            trailing.length_from.span(),
+            "length_from can not point to an overlay field, it is not written by encode_into",
+        ));
+    }
+    if !matches!(
+        length_field.ty,
+        FieldDataType::Number(_, NumberSignage::Unsigned, _)
+    ) {
+        return Err(syn::Error::new(
+            trailing.length_from.span(),
+            "length_from must point to an unsigned number field",
+        ));
+    }
+    Ok(())
+}
+
+/// generates the `BitfieldsDyn` impl, using the read and write functions generated for the header
+/// fields.
+pub fn create_dyn_quotes(
+    info: &StructInfo,
+    trailing: &TrailingFieldInfo,
+) -> syn::Result<TokenStream> {
+    let struct_type = info.type_quote();
+    let header_size = info.total_bytes();
+    let flip = if info.flip {
+        Some(header_size.clone() - 1)
+    } else {
+        None
+    };
+    let trailing_name = &trailing.ident;
+    let offset = trailing.offset;
+    let mut decode_quote = quote! {};
+    let mut encode_quote = quote! {};
+    let mut struct_quote = quote! {};
+    for field in info.fields.iter() {
+        if field.attrs.reserve.is_fake_field() {
+            continue;
+        }
+        let field_name = &field.ident;
+        if field.attrs.reserve.is_reserve_field() {
+            let type_quote = field.ty.type_quote();
+            decode_quote = quote! {
+                #decode_quote
+                let #field_name = 0 as #type_quote;
+            };
+        } else if field.attrs.reserve.is_overlay() {
+            decode_quote = quote! {
+                #decode_quote
+                let #field_name = core::default::Default::default();
+            };
+        } else {
+            let peek_call = if let Some(ref const_value) = field.attrs.const_value {
+                const_value.struct_value()
+            } else {
+                let peek_name = format_ident!("read_{}", field_name.as_ref());
+                quote! {Self::#peek_name(&input_byte_buffer)}
+            };
+            decode_quote = quote! {
+                #decode_quote
+                let #field_name = #peek_call;
+            };
+            let (field_setter, _) = super::into_bytes::get_field_quote(field, &flip, false)?;
+            let value = if field_name.as_ref() == &trailing.length_from {
+                // the length field always describes the trailing field that gets written.
+                let type_quote = field.ty.type_quote();
+                let bit_length = field.attrs.bit_length();
+                // any usize fits in 64 bits so there is nothing else to check.
+                let max_length = bit_length.mask_quote();
+                let range_check = match bit_length {
+                    Size::Literal(bits) if bits >= 64 => quote! {},
+                    Size::Literal(_) => quote! {if (length as u128) <= #max_length},
+                    bits => quote! {if #bits >= 64 || (length as u128) <= #max_length},
+                };
+                quote! {
+                    match trailing_length.checked_sub(#offset) {
+                        Some(length) #range_check => length as #type_quote,
+                        _ => return Err(BitfieldDynError::LengthOutOfRange(trailing_length)),
+                    }
+                }
+            } else if field.attrs.const_value.is_some() {
+                super::into_bytes::field_value_quote(field)
+            } else {
+                quote! {core::clone::Clone::clone(&self.#field_name)}
+            };
+            encode_quote = quote! {
+                #encode_quote
+                let #field_name = #value;
+                #field_setter
+            };
+        }
+        struct_quote = quote! {
+            #struct_quote
+            #field_name,
+        };
+    }
+    let length_peek_name = format_ident!("read_{}", trailing.length_from);
+    let size_assertions = info.size_assertions();
+    // the header fields are cloned when encoding.
+    let clone_bound = |_: &FieldDataType| Some(quote! {core::clone::Clone});
+    let (trait_lifetime, generics, trailing_value) = match trailing.ty {
+        TrailingFieldType::Owned => {
+            let lifetime = syn::Lifetime::new(
+                &format!("'{}", info.unused_ident("bondrewd_input")),
+                proc_macro2::Span::call_site(),
+            );
+            (
+                quote! {#lifetime},
+                info.wrapper_generics(
+                    syn::GenericParam::Lifetime(syn::LifetimeParam::new(lifetime)),
+                    clone_bound,
+                ),
+                quote! {input[#header_size..end].to_vec()},
+            )
+        }
+        TrailingFieldType::Borrowed(ref lifetime) => (
+            quote! {#lifetime},
+            info.impl_generics(clone_bound),
+            quote! {&input[#header_size..end]},
+        ),
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics BitfieldsDyn<#trait_lifetime> for #struct_type #where_clause {
+            const HEADER_SIZE: usize = #header_size;
+            fn decode(input: &#trait_lifetime [u8]) -> Result<(Self, usize), BitfieldDynError> {
+                #size_assertions
+                if input.len() < #header_size {
+                    return Err(BitfieldDynError::Slice(input.len(), #header_size));
+                }
+                let mut input_byte_buffer: [u8;#header_size] = [0u8;#header_size];
+                input_byte_buffer.copy_from_slice(&input[..#header_size]);
+                // a length field wider than usize can describe lengths no slice can hold.
+                let end = match usize::try_from(Self::#length_peek_name(&input_byte_buffer) as u128)
+                    .ok()
+                    .and_then(|length| length.checked_add(#header_size + #offset))
+                {
+                    Some(end) => end,
+                    None => return Err(BitfieldDynError::Slice(input.len(), usize::MAX)),
+                };
+                if input.len() < end {
+                    return Err(BitfieldDynError::Slice(input.len(), end));
+                }
+                #decode_quote
+                let #trailing_name = #trailing_value;
+                Ok((Self{
+                    #struct_quote
+                    #trailing_name,
+                }, end))
+            }
+            fn encode_into(&self, output: &mut [u8]) -> Result<usize, BitfieldDynError> {
+                let trailing_length = self.#trailing_name.len();
+                let end = #header_size + trailing_length;
+                if output.len() < end {
+                    return Err(BitfieldDynError::Slice(output.len(), end));
+                }
+                let mut output_byte_buffer: [u8;#header_size] = [0u8;#header_size];
+                #encode_quote
+                output[..#header_size].copy_from_slice(&output_byte_buffer);
+                output[#header_size..end].copy_from_slice(&self.#trailing_name);
+                Ok(end)
+            }
+        }
+    })
+}
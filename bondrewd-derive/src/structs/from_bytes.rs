@@ -1,6 +1,6 @@
 use crate::structs::common::{
     get_be_starting_index, get_left_and_mask, get_right_and_mask, BitMath, Endianness,
-    FieldDataType, FieldInfo, StructInfo,
+    FieldDataType, FieldInfo, FloatFormat, Size, StructInfo,
 };
 
 use proc_macro2::TokenStream;
@@ -13,11 +13,13 @@ pub struct FromBytesOptions {
     pub peek_field_fns: TokenStream,
     pub peek_slice_field_fns: Option<TokenStream>,
     pub peek_slice_field_unchecked_fns: Option<TokenStream>,
+    pub try_from_bytes_fn: Option<TokenStream>,
 }
 
 pub fn create_from_bytes_field_quotes(
     info: &StructInfo,
     peek_slice: bool,
+    try_from: bool,
 ) -> Result<FromBytesOptions, syn::Error> {
     // make a quote which is a list of the fields separated by a comma then a newline
     let mut from_bytes_struct_quote = quote! {};
@@ -28,14 +30,14 @@ pub fn create_from_bytes_field_quotes(
     let mut peek_slice_fns_option: Option<(TokenStream, TokenStream)> = if peek_slice {
         let checked_ident = format_ident!("{}Checked", &info.name);
         let check_size = info.total_bytes();
+        let (lifetime, checked_generics) = info.checked_generics();
+        let (_, checked_ty_generics, _) = checked_generics.split_for_impl();
         Some((
             quote! {
-                pub fn check_slice(buffer: &[u8]) -> Result<#checked_ident, BitfieldSliceError> {
+                pub fn check_slice<#lifetime>(buffer: &#lifetime [u8]) -> Result<#checked_ident #checked_ty_generics, BitfieldSliceError> {
                     let buf_len = buffer.len();
                     if buf_len >= #check_size {
-                        Ok(#checked_ident {
-                            buffer
-                        })
+                        Ok(#checked_ident::from_unchecked_slice(buffer))
                     }else{
                         Err(BitfieldSliceError(buf_len, #check_size))
                     }
@@ -46,21 +48,34 @@ pub fn create_from_bytes_field_quotes(
     } else { None };
     // all quote with all of the peek functions appended to it.
     let mut peek_fns_quote = quote! {};
+    // same as from_bytes_quote but every field extraction can return a BitfieldDecodeError.
+    let mut try_from_bytes_quote = quote! {};
+    let flip = if info.flip {
+        Some(info.total_bytes() - 1)
+    } else {
+        None
+    };
     for field in info.fields.iter() {
+        if try_from
+            && (field.attrs.reserve.is_reserve_field() || field.attrs.reserve.is_fake_field())
+        {
+            let reserve_check = get_reserve_check_quote(field, &flip);
+            try_from_bytes_quote = quote! {
+                #try_from_bytes_quote
+                #reserve_check
+            };
+        }
         if field.attrs.reserve.is_fake_field() {
             continue;
         }
         let field_name = &field.ident;
         let peek_name = format_ident!("read_{}", field_name.as_ref());
-        let field_extractor = get_field_quote(
-            &field,
-            if info.flip {
-                Some(info.total_bytes() - 1)
-            } else {
-                None
-            },
-        )?;
-        let peek_call = if !field.attrs.reserve.is_reserve_field() {
+        let field_extractor = get_field_quote(field, &flip, false)?;
+        let peek_call = if field.attrs.reserve.is_overlay() {
+            quote! {core::default::Default::default()}
+        } else if let Some(ref const_value) = field.attrs.const_value {
+            const_value.struct_value()
+        } else if !field.attrs.reserve.is_reserve_field() {
             quote! {Self::#peek_name(&input_byte_buffer)}
         } else {
             // TODO make this detect the type to use.
@@ -71,20 +86,45 @@ pub fn create_from_bytes_field_quotes(
             #from_bytes_quote
             let #field_name = #peek_call;
         };
+        if try_from {
+            let try_call = if field.attrs.reserve.is_reserve_field() {
+                peek_call
+            } else {
+                let try_peek_name = format_ident!("try_read_{}", field_name.as_ref());
+                let checked_extractor = get_field_quote(field, &flip, true)?;
+                let try_peek_quote = make_try_peek_fn(&checked_extractor, field, info)?;
+                peek_fns_quote = quote! {
+                    #peek_fns_quote
+                    #try_peek_quote
+                };
+                if field.attrs.reserve.is_overlay() {
+                    peek_call
+                } else if field.attrs.const_value.as_ref().is_some_and(|value| value.marker) {
+                    // a `()` field only has its bits checked.
+                    quote! {{Self::#try_peek_name(&input_byte_buffer)?;}}
+                } else {
+                    quote! {Self::#try_peek_name(&input_byte_buffer)?}
+                }
+            };
+            try_from_bytes_quote = quote! {
+                #try_from_bytes_quote
+                let #field_name = #try_call;
+            };
+        }
         from_bytes_struct_quote = quote! {
             #from_bytes_struct_quote
             #field_name,
         };
 
-        let peek_quote = make_peek_fn(&field_extractor, &field, &info)?;
+        let peek_quote = make_peek_fn(&field_extractor, field, info)?;
         peek_fns_quote = quote! {
             #peek_fns_quote
             #peek_quote
         };
 
         if let Some((ref mut the_peek_slice_fns_quote, ref mut unchecked_quote)) = peek_slice_fns_option {
-            let peek_slice_quote = make_peek_slice_fn(&field_extractor, &field, &info)?;
-            let peek_slice_unchecked_quote = make_peek_slice_unchecked_fn(&field_extractor, &field)?;
+            let peek_slice_quote = make_peek_slice_fn(&field_extractor, field, info)?;
+            let peek_slice_unchecked_quote = make_peek_slice_unchecked_fn(&field_extractor, field)?;
             let mut the_peek_slice_fns_quote_temp = quote! {
                 #the_peek_slice_fns_quote
                 #peek_slice_quote
@@ -98,6 +138,7 @@ pub fn create_from_bytes_field_quotes(
         }
     }
     let struct_size = &info.total_bytes();
+    let size_assertions = info.size_assertions();
     // construct from bytes function. use input_byte_buffer as input name because,
     // that is what the field quotes expect to extract from.
     // wrap our list of field names with commas with Self{} so we it instantiate our struct,
@@ -105,18 +146,32 @@ pub fn create_from_bytes_field_quotes(
     // name as its destination field the list of field names will be just fine.
     let from_bytes_fn = quote! {
         fn from_bytes(mut input_byte_buffer: [u8;#struct_size]) -> Self {
+            #size_assertions
             #from_bytes_quote
             Self{
                 #from_bytes_struct_quote
             }
         }
     };
+    let try_from_bytes_fn = if try_from {
+        Some(quote! {
+            fn try_from_bytes(input_byte_buffer: [u8;#struct_size]) -> Result<Self, BitfieldDecodeError> {
+                #try_from_bytes_quote
+                Ok(Self{
+                    #from_bytes_struct_quote
+                })
+            }
+        })
+    } else {
+        None
+    };
     if let Some((peek_slice_field_fns, peek_slice_field_unchecked_fns)) = peek_slice_fns_option {
         Ok(FromBytesOptions {
             from_bytes_fn,
             peek_field_fns: peek_fns_quote,
             peek_slice_field_fns: Some(peek_slice_field_fns),
             peek_slice_field_unchecked_fns: Some(peek_slice_field_unchecked_fns),
+            try_from_bytes_fn,
         })
     }else{
         Ok(FromBytesOptions {
@@ -124,6 +179,7 @@ pub fn create_from_bytes_field_quotes(
             peek_field_fns: peek_fns_quote,
             peek_slice_field_fns: None,
             peek_slice_field_unchecked_fns: None,
+            try_from_bytes_fn,
         })
     }
 }
@@ -136,9 +192,9 @@ fn make_peek_slice_fn(
     let field_name = format_ident!("read_slice_{}", field.ident.as_ref().clone());
     let type_ident = field.ty.type_quote();
     let min_length = if info.flip {
-        ((info.total_bits() - field.attrs.bit_range.start) as f64 / 8.0f64).ceil() as usize
+        (info.total_bits() - field.attrs.bit_range.start.clone()).div_ceil(8)
     } else {
-        (field.attrs.bit_range.end as f64 / 8.0f64).ceil() as usize
+        field.attrs.bit_range.end.div_ceil(8)
     };
     Ok(quote! {
         #[inline]
@@ -186,18 +242,222 @@ fn make_peek_fn(
     })
 }
 
+fn make_try_peek_fn(
+    field_quote: &TokenStream,
+    field: &FieldInfo,
+    info: &StructInfo,
+) -> syn::Result<TokenStream> {
+    let field_name = format_ident!("try_read_{}", field.ident.as_ref().clone());
+    let type_ident = field.ty.type_quote();
+    let struct_size = info.total_bytes();
+    Ok(quote! {
+        #[inline]
+        pub fn #field_name(input_byte_buffer: &[u8;#struct_size]) -> Result<#type_ident, BitfieldDecodeError> {
+            Ok({#field_quote})
+        }
+    })
+}
+
+/// reads a `const_value` field with `value_quote`, returning a BitfieldDecodeError when it does not
+/// hold its value.
+fn get_const_check_quote(field: &FieldInfo, value_quote: TokenStream) -> TokenStream {
+    let const_value = if let Some(ref const_value) = field.attrs.const_value {
+        &const_value.value
+    } else {
+        return value_quote;
+    };
+    let type_quote = field.ty.type_quote();
+    let mask = field.attrs.bit_length().mask_quote();
+    let error = get_decode_error_quote(
+        field,
+        quote! {ConstMismatch {
+            expected: (expected as u128) & #mask,
+            actual: (actual as u128) & #mask,
+        }},
+    );
+    quote! {
+        let actual: #type_quote = {#value_quote};
+        let expected: #type_quote = #const_value;
+        if actual != expected {
+            #error
+        }
+        actual
+    }
+}
+
+/// returns a BitfieldDecodeError describing `field` from the function the quote is placed in.
+pub fn get_decode_error_quote(field: &FieldInfo, kind: TokenStream) -> TokenStream {
+    let field_name = field.ident.to_string();
+    let start = &field.attrs.bit_range.start;
+    let end = &field.attrs.bit_range.end;
+    quote! {
+        return Err(BitfieldDecodeError {
+            field: BitfieldFieldName::new(#field_name),
+            bits: #start..#end,
+            kind: BitfieldDecodeErrorKind::#kind,
+        });
+    }
+}
+
+/// checks every bit of a reserve field directly in input_byte_buffer, so that the check does not
+/// depend on the type of the field.
+pub fn get_reserve_check_quote(field: &FieldInfo, flip: &Option<Size>) -> TokenStream {
+    let bit_range = match field.literal_range() {
+        Ok(range) if field.has_literal_layout(flip) => range,
+        _ => {
+            let (start, length, flip) = field.layout_args(flip);
+            let error = get_decode_error_quote(field, quote! {NonZeroReserve});
+            return quote! {
+                if !bitfield_layout::is_zero(&input_byte_buffer[..], #start, #length, #flip) {
+                    #error
+                }
+            };
+        }
+    };
+    let flip = flip.as_ref().and_then(Size::literal);
+    let mut checks = Vec::default();
+    for byte_index in (bit_range.start / 8)..bit_range.end.div_ceil(8) {
+        let byte_start = byte_index * 8;
+        let first = bit_range.start.max(byte_start) - byte_start;
+        let last = bit_range.end.min(byte_start + 8) - byte_start;
+        let mut mask = 0u8;
+        for bit in first..last {
+            mask |= 0b1000_0000 >> bit;
+        }
+        let byte_index = if let Some(flip) = flip {
+            flip - byte_index
+        } else {
+            byte_index
+        };
+        checks.push(quote! { input_byte_buffer[#byte_index] & #mask != 0 });
+    }
+    if checks.is_empty() {
+        return quote! {};
+    }
+    let error = get_decode_error_quote(field, quote! {NonZeroReserve});
+    quote! {
+        if #(#checks)||* {
+            #error
+        }
+    }
+}
+
 /// if is_inner is false the field will be put into a variable with the fields name, otherwise
-/// it will be returned.
-fn get_field_quote(
+/// it will be returned. when checked is true the quote is meant for try_from_bytes and will
+/// return a BitfieldDecodeError for invalid enums and chars.
+/// the value a field is stored as before it gets converted to the type of the field, for nested
+/// structures these are the bytes given to `from_bytes`. not for arrays.
+pub fn get_value_retrieval_quote(
     field: &FieldInfo,
-    flip: Option<usize>,
+    flip: &Option<Size>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if !field.has_literal_layout(flip) {
+        return get_layout_retrieval_quote(field, flip);
+    }
+    let flip = flip.as_ref().and_then(Size::literal);
+    match field.attrs.endianness.as_ref() {
+        Endianness::Big => apply_be_math_to_field_access_quote(field, flip),
+        Endianness::Little => apply_le_math_to_field_access_quote(field, flip),
+        Endianness::None => apply_ne_math_to_field_access_quote(field, flip),
+        Endianness::Native => {
+            let le_field = field.with_endianness(Endianness::Little);
+            let be_field = field.with_endianness(Endianness::Big);
+            let le = apply_le_math_to_field_access_quote(&le_field, flip)?;
+            let be = apply_be_math_to_field_access_quote(&be_field, flip)?;
+            Ok(quote! {
+                {
+                    #[cfg(target_endian = "little")]
+                    let native_value = #le;
+                    #[cfg(target_endian = "big")]
+                    let native_value = #be;
+                    native_value
+                }
+            })
+        }
+    }
+}
+
+/// the value of a field the compiler works out the position of, read with `bitfield_layout`. it
+/// is the same value `get_value_retrieval_quote` gives for fields with a literal layout.
+fn get_layout_retrieval_quote(
+    field: &FieldInfo,
+    flip: &Option<Size>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (start, length, flip) = field.layout_args(flip);
+    if let FieldDataType::Struct(ref size, _) = field.ty {
+        return Ok(quote! {{
+            let mut bytes = [0u8; #size];
+            bitfield_layout::read_bytes(&input_byte_buffer[..], #start, #length, #flip, &mut bytes);
+            bytes
+        }});
+    }
+    let read_fn = field.layout_fn("read");
+    let raw = quote! {bitfield_layout::#read_fn(&input_byte_buffer[..], #start, #length, #flip)};
+    match field.ty {
+        FieldDataType::Boolean => Ok(quote! {(#raw != 0)}),
+        FieldDataType::Char(_, _) => Ok(quote! {(#raw as u32)}),
+        _ => {
+            let primitive = field.ty.primitive_quote().ok_or_else(|| {
+                syn::Error::new(field.ident.span(), "unsupported type for a position from constants")
+            })?;
+            if let Some((_, NumberSignage::Signed)) = field.ty.number_signage() {
+                Ok(quote! {(bitfield_layout::sign_extend(#raw, #length) as #primitive)})
+            } else {
+                Ok(quote! {(#raw as #primitive)})
+            }
+        }
+    }
+}
+
+/// reads every element of an array with a length from a constant in a loop. a checked read checks
+/// every element before the array is made, because `core::array::from_fn` can not return errors.
+fn get_array_loop_quote(
+    field: &FieldInfo,
+    flip: &Option<Size>,
+    checked: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let index = format_ident!("{}_index", field.name);
+    let element = field.element_at(&index)?;
+    let element_quote = get_field_quote(&element, flip, false)?;
+    let length = match field.ty {
+        FieldDataType::ElementArray(_, ref length, _) | FieldDataType::BlockArray(_, ref length, _) => length,
+        _ => unreachable!("get_array_loop_quote is only used for arrays"),
+    };
+    let check = if checked {
+        let checked_quote = get_field_quote(&element, flip, true)?;
+        quote! {
+            for #index in 0..#length {
+                let _ = {#checked_quote};
+            }
+        }
+    } else {
+        quote! {}
+    };
+    Ok(quote! {{
+        #check
+        core::array::from_fn(|#index| {#element_quote})
+    }})
+}
+
+pub fn get_field_quote(
+    field: &FieldInfo,
+    flip: &Option<Size>,
+    checked: bool,
 ) -> syn::Result<proc_macro2::TokenStream> {
+    if checked && field.attrs.const_value.is_some() {
+        return Ok(get_const_check_quote(field, get_field_quote(field, flip, false)?));
+    }
     let value_retrieval = match field.ty {
+        FieldDataType::ElementArray(_, ref length, _) | FieldDataType::BlockArray(_, ref length, _)
+            if !length.is_literal() =>
+        {
+            get_array_loop_quote(field, flip, checked)?
+        }
         FieldDataType::ElementArray(_, _, _) => {
             let mut buffer = quote! {};
             let sub = field.get_element_iter()?;
             for sub_field in sub {
-                let sub_field_quote = get_field_quote(&sub_field, flip)?;
+                let sub_field_quote = get_field_quote(&sub_field, flip, checked)?;
                 buffer = quote! {
                     #buffer
                     {#sub_field_quote},
@@ -210,7 +470,7 @@ fn get_field_quote(
             let mut buffer = quote! {};
             let sub = field.get_block_iter()?;
             for sub_field in sub {
-                let sub_field_quote = get_field_quote(&sub_field, flip)?;
+                let sub_field_quote = get_field_quote(&sub_field, flip, checked)?;
                 buffer = quote! {
                     #buffer
                     {#sub_field_quote},
@@ -219,17 +479,35 @@ fn get_field_quote(
             let buffer = quote! { [#buffer] };
             buffer
         }
-        _ => match field.attrs.endianness.as_ref() {
-            Endianness::Big => apply_be_math_to_field_access_quote(field, flip)?,
-            Endianness::Little => apply_le_math_to_field_access_quote(field, flip)?,
-            Endianness::None => apply_ne_math_to_field_access_quote(field, flip)?,
-        },
+        _ => get_value_retrieval_quote(field, flip)?,
     };
 
     let output = match field.ty {
-        FieldDataType::Float(_, ref ident) => {
+        FieldDataType::Float(_, ref ident, Some(FloatFormat::Reduced(ref reduced))) => {
+            let exponent_bits = reduced.exponent_bits as u32;
+            let mantissa_bits = reduced.mantissa_bits.u32_quote();
+            quote! {<#ident as BitfieldFloat>::from_reduced_bits(#value_retrieval, #exponent_bits, #mantissa_bits)}
+        }
+        FieldDataType::Float(_, ref ident, Some(FloatFormat::Scaled(ref scaled))) => {
+            let scale = proc_macro2::Literal::f64_suffixed(scaled.scale);
+            let offset = proc_macro2::Literal::f64_suffixed(scaled.offset);
+            quote! {<#ident as BitfieldFloat>::from_scaled_raw((#value_retrieval) as i128, #scale, #offset)}
+        }
+        FieldDataType::Float(_, ref ident, None) => {
             quote! {#ident::from_bits(#value_retrieval)}
         }
+        FieldDataType::Char(_, _) if checked => {
+            let error = get_decode_error_quote(field, quote! {InvalidChar});
+            quote! {
+                if let Some(c) = char::from_u32({
+                    #value_retrieval
+                }) {
+                    c
+                }else{
+                    #error
+                }
+            }
+        }
         FieldDataType::Char(_, _) => {
             quote! {
                 if let Some(c) = char::from_u32({
@@ -241,11 +519,40 @@ fn get_field_quote(
                 }
             }
         }
+        FieldDataType::Enum(ref prim, _, ref ident) if checked => {
+            let error = get_decode_error_quote(field, quote! {InvalidEnum});
+            if field.attrs.strict {
+                quote! {
+                    let prim: #prim = #value_retrieval;
+                    if !<#ident>::is_variant_value(&prim) {
+                        #error
+                    }
+                    <#ident>::from_primitive(prim)
+                }
+            } else {
+                quote! {
+                    if let Ok(value) = <#ident>::try_from_primitive(#value_retrieval) {
+                        value
+                    } else {
+                        #error
+                    }
+                }
+            }
+        }
         FieldDataType::Enum(_, _, ref ident) => {
-            quote! {#ident::from_primitive(#value_retrieval)}
+            quote! {<#ident>::from_primitive(#value_retrieval)}
+        }
+        FieldDataType::Struct(_, ref ident) if checked => {
+            // errors of the nested structure get the name of this field and its bit position.
+            let field_name = field.ident.to_string();
+            let start = &field.attrs.bit_range.start;
+            quote! {
+                <#ident>::try_from_bytes({#value_retrieval})
+                    .map_err(|err| err.nested_in(#field_name, #start))?
+            }
         }
         FieldDataType::Struct(_, ref ident) => {
-            quote! {#ident::from_bytes({#value_retrieval})}
+            quote! {<#ident>::from_bytes({#value_retrieval})}
         }
         _ => {
             quote! {#value_retrieval}
@@ -321,9 +628,9 @@ fn apply_le_math_to_field_access_quote(
         //         quote! { (#field_access_quote.rotate_right(#right_shift_usize)) }
         //     }
         // };
-        let size = field.ty.size();
+        let size = field.struct_byte_size()?;
         let new_array_quote =
-            if let Some(a) = add_sign_fix_quote(&field, &amount_of_bits, &right_shift)? {
+            if let Some(a) = add_sign_fix_quote(field, &amount_of_bits, &right_shift, flip)? {
                 a
             } else {
                 quote! {[0u8;#size]}
@@ -338,7 +645,7 @@ fn apply_le_math_to_field_access_quote(
         let next_bit_mask = get_left_and_mask(mid_shift as usize);
         let mut i = 0;
         while i != fields_last_bits_index {
-            let start = if let None = flip {
+            let start = if flip.is_none() {
                 starting_inject_byte + i
             } else {
                 starting_inject_byte - i
@@ -370,12 +677,12 @@ fn apply_le_math_to_field_access_quote(
         }
         let used_bits = available_bits_in_first_byte + (8 * i);
         if right_shift > 0 {
-            let start = if let None = flip {
+            let start = if flip.is_none() {
                 starting_inject_byte + i
             } else {
                 starting_inject_byte - i
             };
-            let right_shift: u32 = right_shift.clone() as u32;
+            let right_shift: u32 = right_shift as u32;
             if used_bits < amount_of_bits {
                 full_quote = quote! {
                     #full_quote
@@ -383,7 +690,7 @@ fn apply_le_math_to_field_access_quote(
                     #field_buffer_name[#i] |= input_byte_buffer[#start + 1] & #last_bit_mask;
                 };
             } else {
-                let mut last_mask = first_bit_mask.clone();
+                let mut last_mask = first_bit_mask;
                 if amount_of_bits < used_bits {
                     last_mask &= !get_right_and_mask(used_bits - amount_of_bits);
                 }
@@ -397,13 +704,13 @@ fn apply_le_math_to_field_access_quote(
                 #field_buffer_name[#i] = #field_buffer_name[#i].rotate_left(#right_shift);
             };
         } else {
-            let start = if let None = flip {
+            let start = if flip.is_none() {
                 starting_inject_byte + i
             } else {
                 starting_inject_byte - i
             };
             // this should give us the last index of the field
-            let left_shift: u32 = right_shift.clone().abs() as u32;
+            let left_shift: u32 = right_shift.unsigned_abs() as u32;
             let mid_mask = first_bit_mask & last_bit_mask;
             full_quote = quote! {
                 #full_quote
@@ -426,11 +733,9 @@ fn apply_le_math_to_field_access_quote(
                 };
                 apply_field_to_buffer
             }
-            FieldDataType::Float(_, _) => {
-                let alt_type_quote = if size == 4 {
-                    quote!{u32}
-                }else if size == 8 {
-                    quote!{u64}
+            FieldDataType::Float(_, _, _) => {
+                let alt_type_quote = if let Some(bits) = field.ty.primitive_quote() {
+                    bits
                 }else{
                     return Err(syn::Error::new(field.ident.span(), "unsupported floating type"))
                 };
@@ -475,17 +780,17 @@ fn apply_le_math_to_field_access_quote(
             & get_left_and_mask(8 - zeros_on_right);
         // calculate how many left shifts need to occur to the number in order to position the bytes
         // we want to keep in the position we want.
-        if 8 - amount_of_bits < field.attrs.bit_range.start % 8 {
+        if 8 - amount_of_bits < field.literal_range()?.start % 8 {
             return Err(syn::Error::new(
                 field.ident.span(),
                 format!(
                     "calculating be left_shift failed {} , {}",
                     amount_of_bits,
-                    field.attrs.bit_range.start % 8
+                    field.literal_range()?.start % 8
                 ),
             ));
         }
-        let shift_left = (8 - amount_of_bits) - (field.attrs.bit_range.start % 8);
+        let shift_left = (8 - amount_of_bits) - (field.literal_range()?.start % 8);
         // a quote that puts the field into a byte buffer we assume exists (because this is a
         // fragment).
         // NOTE the mask used here is only needed if we can NOT guarantee the field is only using the
@@ -501,17 +806,29 @@ fn apply_le_math_to_field_access_quote(
         //          in the note above)
         // both of these could benefit from a return of the number that actually got set.
         let output_quote = match field.ty {
-            FieldDataType::Number(_, ref sign, ref ident) => {
+            // floats are read as their bits, only reduced and scaled floats fit in a byte.
+            FieldDataType::Number(_, _, _) | FieldDataType::Enum(_, _, _) | FieldDataType::Float(_, _, _) => {
+                let ident = if let Some(ident) = field.ty.primitive_quote() {
+                    ident
+                } else {
+                    return Err(syn::Error::new(field.ident.span(), "unsupported floating type"));
+                };
                 let mut field_value = quote!{((input_byte_buffer[#starting_inject_byte] & #mask) >> #shift_left)};
-                if let NumberSignage::Signed = sign {
-                    field_value = add_sign_fix_quote_single_bit(field_value, &field, &amount_of_bits, &starting_inject_byte);
+                if let Some((size, NumberSignage::Signed)) = field.ty.number_signage() {
+                    field_value = add_sign_fix_quote_single_bit(field_value, field, &amount_of_bits, &starting_inject_byte)?;
                     let mut value = quote!{
                         let mut #field_buffer_name = #field_value;
                     };
+                    // the sign fixed byte must become an i8 before growing so the sign is extended.
+                    let cast = if size == 1 {
+                        quote!{#field_buffer_name as #ident}
+                    } else {
+                        quote!{#field_buffer_name as i8 as #ident}
+                    };
                     value = quote!{
                         {
                             #value
-                            #field_buffer_name as #ident
+                            #cast
                         }
                     };
                     value
@@ -525,9 +842,7 @@ fn apply_le_math_to_field_access_quote(
                 quote!{(input_byte_buffer[#starting_inject_byte] & #mask) != 0}
             }
             FieldDataType::Char(_, _) => return Err(syn::Error::new(field.ident.span(), "Char not supported for single byte insert logic")),
-            FieldDataType::Enum(ref primitive_ident, _, _) => quote!{((input_byte_buffer[#starting_inject_byte] & #mask) >> #shift_left) as #primitive_ident},
             FieldDataType::Struct(_, _) => return Err(syn::Error::new(field.ident.span(), "Struct was given Endianness which should be described by the struct implementing Bitfield")),
-            FieldDataType::Float(_, _) => return Err(syn::Error::new(field.ident.span(), "Float not supported for single byte insert logic")),
             FieldDataType::ElementArray(_, _, _) | FieldDataType::BlockArray(_, _, _) => return Err(syn::Error::new(field.ident.span(), "an array got passed into apply_be_math_to_field_access_quote, which is bad.")),
         };
         Ok(output_quote)
@@ -558,11 +873,11 @@ fn apply_ne_math_to_field_access_quote(
                 "calculating ne right_shift failed",
             ));
         }
-        let right_shift: i8 = (8 as i8) - ((available_bits_in_first_byte % 8) as i8);
+        let right_shift: i8 = 8_i8 - ((available_bits_in_first_byte % 8) as i8);
         // here we finish the buffer setup and give it the value returned by to_bytes from the number
         let full_quote = match field.ty {
             FieldDataType::Number(_, _,_ ) |
-            FieldDataType::Float(_, _) |
+            FieldDataType::Float(_, _, _) |
             FieldDataType::Char(_, _) => return Err(syn::Error::new(field.ident.span(), "Char was not given Endianness, please report this.")),
             FieldDataType::Boolean => return Err(syn::Error::new(field.ident.span(), "matched a boolean data type in generate code for bits that span multiple bytes in the output")),
             FieldDataType::Enum(_, _, _) => return Err(syn::Error::new(field.ident.span(), "Enum was not given Endianness, please report this.")),
@@ -576,8 +891,8 @@ fn apply_ne_math_to_field_access_quote(
                     let current_bit_mask = get_right_and_mask(available_bits_in_first_byte);
                     let next_bit_mask = get_left_and_mask(8 - available_bits_in_first_byte);
                     let right_shift: u32 = right_shift as u32;
-                    for i in 0..*size {
-                        let start = if let None = flip {starting_inject_byte + i}else{starting_inject_byte - i};
+                    for i in 0..field.struct_byte_size()? {
+                        let start = if flip.is_none() {starting_inject_byte + i}else{starting_inject_byte - i};
                         let mut first = quote!{
                             #buffer_ident[#i] = input_byte_buffer[#start] & #current_bit_mask;
                         };
@@ -605,12 +920,12 @@ fn apply_ne_math_to_field_access_quote(
                     /*let current_bit_mask = get_right_and_mask(available_bits_in_first_byte);
                     let next_bit_mask = get_left_and_mask(8 - available_bits_in_first_byte);
                     let left_shift = right_shift.clone().abs() as u32;
-                    for i in 0..*size {
+                    for i in 0..field.struct_byte_size()? {
                         let start = if let None = flip {starting_inject_byte + i}else{starting_inject_byte - i};
                         let mut first = quote!{
                             #buffer_ident[#i] = input_byte_buffer[#start] & #current_bit_mask;
                         };
-                        if i + 1 <= *size {
+                        if i + 1 <= field.struct_byte_size()? {
                             first = quote!{
                                 #first
                                 #buffer_ident[#i] = input_byte_buffer[#start #operator 1] & #next_bit_mask;
@@ -625,8 +940,8 @@ fn apply_ne_math_to_field_access_quote(
                 }else{
                     // no shift can be more faster.
                     let current_bit_mask = get_right_and_mask(available_bits_in_first_byte);
-                    for i in 0..*size {
-                        let start = if let None = flip {starting_inject_byte + i}else{starting_inject_byte - i};
+                    for i in 0..field.struct_byte_size()? {
+                        let start = if flip.is_none() {starting_inject_byte + i}else{starting_inject_byte - i};
                         if i == 0{
                             quote_builder = quote!{
                                 #quote_builder
@@ -661,7 +976,7 @@ fn apply_ne_math_to_field_access_quote(
         let zeros_on_right = 8 - (zeros_on_left + amount_of_bits);
         let mask = get_right_and_mask(available_bits_in_first_byte)
             & get_left_and_mask(8 - zeros_on_right);
-        if 8 < amount_of_bits || 8 - amount_of_bits < field.attrs.bit_range.start % 8 {
+        if 8 < amount_of_bits || 8 - amount_of_bits < field.literal_range()?.start % 8 {
             return Err(syn::Error::new(
                 field.ident.span(),
                 "calculating ne shift_left failed",
@@ -679,7 +994,7 @@ fn apply_ne_math_to_field_access_quote(
                 let used_bits_in_byte = 8 - available_bits_in_first_byte;
                 quote!{([((input_byte_buffer[#starting_inject_byte] & #mask)) << #used_bits_in_byte])}
             }
-            FieldDataType::Float(_, _) => return Err(syn::Error::new(field.ident.span(), "Float not supported for single byte insert logic")),
+            FieldDataType::Float(_, _, _) => return Err(syn::Error::new(field.ident.span(), "Float not supported for single byte insert logic")),
             FieldDataType::ElementArray(_, _, _) | FieldDataType::BlockArray(_, _, _) => return Err(syn::Error::new(field.ident.span(), "an array got passed into apply_ne_math_to_field_access_quote, which is bad."))
         };
         Ok(output)
@@ -689,7 +1004,7 @@ fn apply_ne_math_to_field_access_quote(
 /// # Arguments
 /// * `field' - reference to the FieldInfo.
 /// * `field_access_quote` - a quote containing access to to byte array of the field.
-///                             ex. quote!{(self.char_field as u32)}
+///   ex. quote!{(self.char_field as u32)}
 fn apply_be_math_to_field_access_quote(
     field: &FieldInfo,
     flip: Option<usize>,
@@ -741,20 +1056,20 @@ fn apply_be_math_to_field_access_quote(
         // index of the fields byte array will be used.
         let (shift, first_bits_index) = if right_shift < 0 {
             // convert to left shift using absolute value
-            let left_shift: u32 = right_shift.clone().abs() as u32;
+            let left_shift: u32 = right_shift.unsigned_abs() as u32;
             // shift left code
             (
                 quote! { .rotate_right(#left_shift) },
                 // if the size of the field type is the same as the bit size going into the
                 // bit_buffer then we use the last byte for applying to the buffers first effected
                 // byte.
-                if field.ty.size() * 8 == amount_of_bits {
-                    field.ty.size() - 1
+                if field.struct_byte_size()? * 8 == amount_of_bits {
+                    field.struct_byte_size()? - 1
                 } else {
                     match get_be_starting_index(
                         amount_of_bits,
                         right_shift,
-                        field.struct_byte_size(),
+                        field.struct_byte_size()?,
                     ) {
                         Ok(good) => good,
                         Err(err) => return Err(syn::Error::new(field.ident.span(), err)),
@@ -768,10 +1083,10 @@ fn apply_be_math_to_field_access_quote(
                     quote! {}
                 } else {
                     // shift right code
-                    let right_shift_usize: u32 = right_shift.clone() as u32;
+                    let right_shift_usize: u32 = right_shift as u32;
                     quote! { .rotate_left(#right_shift_usize) }
                 },
-                match get_be_starting_index(amount_of_bits, right_shift, field.struct_byte_size()) {
+                match get_be_starting_index(amount_of_bits, right_shift, field.struct_byte_size()?) {
                     Ok(good) => good,
                     Err(err) => return Err(syn::Error::new(field.ident.span(), err)),
                 },
@@ -789,11 +1104,9 @@ fn apply_be_math_to_field_access_quote(
                 };
                 apply_field_to_buffer
             }
-            FieldDataType::Float(size, _) => {
-                let alt_type_quote = if size == 4 {
-                    quote!{u32}
-                }else if size == 8 {
-                    quote!{u64}
+            FieldDataType::Float(size, _, _) => {
+                let alt_type_quote = if let Some(bits) = field.ty.primitive_quote() {
+                    bits
                 }else{
                     return Err(syn::Error::new(field.ident.span(), "unsupported floating type"))
                 };
@@ -840,17 +1153,17 @@ fn apply_be_math_to_field_access_quote(
             & get_left_and_mask(8 - zeros_on_right);
         // calculate how many left shifts need to occur to the number in order to position the bytes
         // we want to keep in the position we want.
-        if 8 - amount_of_bits < field.attrs.bit_range.start % 8 {
+        if 8 - amount_of_bits < field.literal_range()?.start % 8 {
             return Err(syn::Error::new(
                 field.ident.span(),
                 format!(
                     "calculating be left_shift failed {} , {}",
                     amount_of_bits,
-                    field.attrs.bit_range.start % 8
+                    field.literal_range()?.start % 8
                 ),
             ));
         }
-        let shift_left = (8 - amount_of_bits) - (field.attrs.bit_range.start % 8);
+        let shift_left = (8 - amount_of_bits) - (field.literal_range()?.start % 8);
         // a quote that puts the field into a byte buffer we assume exists (because this is a
         // fragment).
         // NOTE the mask used here is only needed if we can NOT guarantee the field is only using the
@@ -866,17 +1179,29 @@ fn apply_be_math_to_field_access_quote(
         //          in the note above)
         // both of these could benefit from a return of the number that actually got set.
         let output_quote = match field.ty {
-            FieldDataType::Number(_, ref sign,ref ident) => {
+            // floats are read as their bits, only reduced and scaled floats fit in a byte.
+            FieldDataType::Number(_, _, _) | FieldDataType::Enum(_, _, _) | FieldDataType::Float(_, _, _) => {
+                let ident = if let Some(ident) = field.ty.primitive_quote() {
+                    ident
+                } else {
+                    return Err(syn::Error::new(field.ident.span(), "unsupported floating type"));
+                };
                 let mut field_value = quote!{((input_byte_buffer[#starting_inject_byte] & #mask) >> #shift_left)};
-                if let NumberSignage::Signed = sign {
-                    field_value = add_sign_fix_quote_single_bit(field_value, &field, &amount_of_bits, &starting_inject_byte);
+                if let Some((size, NumberSignage::Signed)) = field.ty.number_signage() {
+                    field_value = add_sign_fix_quote_single_bit(field_value, field, &amount_of_bits, &starting_inject_byte)?;
                     let mut value = quote!{
                         let mut #field_buffer_name = #field_value;
                     };
+                    // the sign fixed byte must become an i8 before growing so the sign is extended.
+                    let cast = if size == 1 {
+                        quote!{#field_buffer_name as #ident}
+                    } else {
+                        quote!{#field_buffer_name as i8 as #ident}
+                    };
                     value = quote!{
                         {
                             #value
-                            #field_buffer_name as #ident
+                            #cast
                         }
                     };
                     value
@@ -890,15 +1215,14 @@ fn apply_be_math_to_field_access_quote(
                 quote!{(input_byte_buffer[#starting_inject_byte] & #mask) != 0}
             }
             FieldDataType::Char(_, _) => return Err(syn::Error::new(field.ident.span(), "Char not supported for single byte insert logic")),
-            FieldDataType::Enum(ref primitive_ident, _, _) => quote!{((input_byte_buffer[#starting_inject_byte] & #mask) >> #shift_left) as #primitive_ident},
             FieldDataType::Struct(_, _) => return Err(syn::Error::new(field.ident.span(), "Struct was given Endianness which should be described by the struct implementing Bitfield")),
-            FieldDataType::Float(_, _) => return Err(syn::Error::new(field.ident.span(), "Float not supported for single byte insert logic")),
             FieldDataType::ElementArray(_, _, _) | FieldDataType::BlockArray(_, _, _) => return Err(syn::Error::new(field.ident.span(), "an array got passed into apply_be_math_to_field_access_quote, which is bad.")),
         };
         Ok(output_quote)
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_number_quote(
     field: &FieldInfo,
     amount_of_bits: usize,
@@ -914,7 +1238,7 @@ fn build_number_quote(
     flip: Option<usize>,
 ) -> syn::Result<TokenStream> {
     let new_array_quote =
-        if let Some(a) = add_sign_fix_quote(&field, &amount_of_bits, &right_shift)? {
+        if let Some(a) = add_sign_fix_quote(field, &amount_of_bits, &right_shift, flip)? {
             a
         } else {
             quote! {[0u8;#size]}
@@ -924,7 +1248,7 @@ fn build_number_quote(
         #field_buffer_name[#first_bits_index] |= input_byte_buffer[#starting_inject_byte] & #first_bit_mask;
     };
     // fill in the rest of the bits
-    let mut current_byte_index_in_buffer: usize = if let None = flip {
+    let mut current_byte_index_in_buffer: usize = if flip.is_none() {
         starting_inject_byte + 1
     } else {
         starting_inject_byte - 1
@@ -932,12 +1256,12 @@ fn build_number_quote(
     if right_shift > 0 {
         // right shift (this means that the last bits are in the first byte)
         if available_bits_in_first_byte + bits_in_last_byte != amount_of_bits {
-            for i in first_bits_index + 1usize..field.ty.size() {
+            for i in first_bits_index + 1usize..field.struct_byte_size()? {
                 full_quote = quote! {
                     #full_quote
                     #field_buffer_name[#i] |= input_byte_buffer[#current_byte_index_in_buffer] ;
                 };
-                current_byte_index_in_buffer = if let None = flip {
+                current_byte_index_in_buffer = if flip.is_none() {
                     current_byte_index_in_buffer + 1
                 } else {
                     current_byte_index_in_buffer - 1
@@ -952,12 +1276,12 @@ fn build_number_quote(
     } else {
         // no shift or left shift (this means the last byte contains the last bits)
         if available_bits_in_first_byte + bits_in_last_byte != amount_of_bits {
-            for i in first_bits_index + 1..field.ty.size() - 1 {
+            for i in first_bits_index + 1..field.struct_byte_size()? - 1 {
                 full_quote = quote! {
                     #full_quote
                     #field_buffer_name[#i] |= input_byte_buffer[#current_byte_index_in_buffer];
                 };
-                current_byte_index_in_buffer = if let None = flip {
+                current_byte_index_in_buffer = if flip.is_none() {
                     current_byte_index_in_buffer + 1
                 } else {
                     current_byte_index_in_buffer - 1
@@ -965,7 +1289,7 @@ fn build_number_quote(
             }
         }
         // this should give us the last index of the field
-        let final_index = field.ty.size() - 1;
+        let final_index = field.struct_byte_size()? - 1;
         //TODO make rotation optimizer.
         full_quote = quote! {
             #full_quote
@@ -1083,22 +1407,29 @@ fn add_sign_fix_quote(
     field: &FieldInfo,
     amount_of_bits: &usize,
     right_shift: &i8,
+    flip: Option<usize>,
 ) -> syn::Result<Option<TokenStream>> {
-    if let FieldDataType::Number(ref size, ref sign, _) = field.ty {
-        if *amount_of_bits != *size * 8 {
+    if let Some((size, sign)) = field.ty.number_signage() {
+        if *amount_of_bits != size * 8 {
             if let NumberSignage::Signed = sign {
                 let (bit_to_isolate, sign_index) = match field.attrs.endianness.as_ref() {
                     Endianness::Big => (
-                        field.attrs.bit_range.start % 8,
-                        field.attrs.bit_range.start / 8,
+                        field.literal_range()?.start % 8,
+                        field.literal_range()?.start / 8,
                     ),
                     Endianness::Little => {
                         let skip_bytes = (amount_of_bits / 8) * 8;
-                        let sign_bit_index = field.attrs.bit_range.start + skip_bytes;
+                        let sign_bit_index = field.literal_range()?.start + skip_bytes;
                         // TODO fix bit isolators to fix signed numbers.
                         (sign_bit_index % 8, sign_bit_index / 8)
                     }
-                    Endianness::None => return Ok(None),
+                    Endianness::None | Endianness::Native => return Ok(None),
+                };
+                // reversed structures read the bytes from the other end.
+                let sign_index = if let Some(flip) = flip {
+                    flip - sign_index
+                } else {
+                    sign_index
                 };
                 let sign_mask = isolate_bit_index_mask(&bit_to_isolate);
                 let sign_bit = quote! {
@@ -1106,7 +1437,7 @@ fn add_sign_fix_quote(
                 };
                 let mut unused_bits = (size * 8) - amount_of_bits;
                 let mut buffer: std::collections::VecDeque<u8> = Default::default();
-                for _i in 0..*size {
+                for _i in 0..size {
                     if unused_bits > 7 {
                         buffer.push_back(get_left_and_mask(8));
                         unused_bits -= 8;
@@ -1124,7 +1455,7 @@ fn add_sign_fix_quote(
                         buffer = std::collections::VecDeque::from(rotate_primitive_vec(
                             buffer.into(),
                             right_shift,
-                            &field,
+                            field,
                         )?);
                         while {
                             if let Some(c) = buffer.pop_front() {
@@ -1157,7 +1488,7 @@ fn add_sign_fix_quote(
                             }
                         } {}
                     }
-                    Endianness::None => return Ok(None),
+                    Endianness::None | Endianness::Native => return Ok(None),
                 }
                 return Ok(Some(quote! {
                     if #sign_bit == #sign_mask {[#bit_buffer]} else {[0u8;#size]}
@@ -1173,22 +1504,23 @@ fn add_sign_fix_quote_single_bit(
     field: &FieldInfo,
     amount_of_bits: &usize,
     byte_index: &usize,
-) -> TokenStream {
-    if let FieldDataType::Number(ref size, ref sign, _) = field.ty {
-        if *amount_of_bits != *size * 8 {
+) -> syn::Result<TokenStream> {
+    if let Some((size, sign)) = field.ty.number_signage() {
+        if *amount_of_bits != size * 8 {
             if let NumberSignage::Signed = sign {
-                let bit_to_isolate = field.attrs.bit_range.start % 8;
+                let bit_to_isolate = field.literal_range()?.start % 8;
                 let sign_mask = isolate_bit_index_mask(&bit_to_isolate);
-                let neg_mask = get_left_and_mask(bit_to_isolate + 1);
+                // the value is already shifted to the low bits, every bit above it is set.
+                let neg_mask = get_left_and_mask(8 - amount_of_bits);
                 let sign_bit = quote! {
                     (input_byte_buffer[#byte_index] & #sign_mask)
                 };
                 let add_me = quote! {
                     if #sign_bit == #sign_mask {#neg_mask | #field_access} else {0u8 | #field_access}
                 };
-                return add_me;
+                return Ok(add_me);
             }
         }
     }
-    field_access
+    Ok(field_access)
 }
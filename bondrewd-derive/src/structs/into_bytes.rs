@@ -1,7 +1,9 @@
 use crate::structs::common::{
     get_be_starting_index, get_left_and_mask, get_right_and_mask, BitMath, Endianness,
-    FieldDataType, FieldInfo, StructInfo,
+    FieldDataType, FieldInfo, FloatFormat, Size, StructInfo,
 };
+use crate::structs::checksum;
+use crate::structs::parse::FloatRounding;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
@@ -22,14 +24,14 @@ pub fn create_into_bytes_field_quotes(
     let mut set_slice_fns_option = if set_slice {
         let checked_ident = format_ident!("{}CheckedMut", &info.name);
         let check_size = info.total_bytes();
+        let (lifetime, checked_generics) = info.checked_generics();
+        let (_, checked_ty_generics, _) = checked_generics.split_for_impl();
         Some((
             quote! {
-                pub fn check_slice_mut(buffer: &mut [u8]) -> Result<#checked_ident, BitfieldSliceError> {
+                pub fn check_slice_mut<#lifetime>(buffer: &#lifetime mut [u8]) -> Result<#checked_ident #checked_ty_generics, BitfieldSliceError> {
                     let buf_len = buffer.len();
                     if buf_len >= #check_size {
-                        Ok(#checked_ident {
-                            buffer
-                        })
+                        Ok(#checked_ident::from_unchecked_slice(buffer))
                     }else{
                         Err(BitfieldSliceError(buf_len, #check_size))
                     }
@@ -45,31 +47,35 @@ pub fn create_into_bytes_field_quotes(
             continue;
         }
         let (field_setter, clear_quote) = get_field_quote(
-            &field,
-            if info.flip {
+            field,
+            &if info.flip {
                 Some(info.total_bytes() - 1)
             } else {
                 None
             },
             false,
         )?;
-        if !field.attrs.reserve.is_reserve_field() {
+        if !field.attrs.reserve.is_reserve_field()
+            && !field.attrs.reserve.is_overlay()
+            && field.attrs.checksum.is_none()
+        {
             let field_name = &field.ident;
+            let value = field_value_quote(field);
             into_bytes_quote = quote! {
                 #into_bytes_quote
-                let #field_name = self.#field_name;
+                let #field_name = #value;
                 #field_setter
             };
         }
-        let set_quote = make_set_fn(&field_setter, &field, &info, &clear_quote)?;
+        let set_quote = make_set_fn(&field_setter, field, info, &clear_quote)?;
         set_fns_quote = quote! {
             #set_fns_quote
             #set_quote
         };
 
         if let Some((ref mut set_slice_fns_quote, ref mut unchecked)) = set_slice_fns_option {
-            let set_slice_quote = make_set_slice_fn(&field_setter, &field, &info, &clear_quote)?;
-            let set_slice_unchecked_quote = make_set_slice_unchecked_fn(&field_setter, &field, &clear_quote)?;
+            let set_slice_quote = make_set_slice_fn(&field_setter, field, info, &clear_quote)?;
+            let set_slice_unchecked_quote = make_set_slice_unchecked_fn(&field_setter, field, &clear_quote)?;
             let mut set_slice_fns_quote_temp = quote! {
                 #set_slice_fns_quote
                 #set_slice_quote
@@ -83,6 +89,8 @@ pub fn create_into_bytes_field_quotes(
         }
     }
     let struct_size = &info.total_bytes();
+    let size_assertions = info.size_assertions();
+    let checksums_quote = checksum::write_checksums_quote(info)?;
     // construct from bytes function. use input_byte_buffer as input name because,
     // that is what the field quotes expect to extract from.
     // wrap our list of field names with commas with Self{} so we it instantiate our struct,
@@ -90,8 +98,10 @@ pub fn create_into_bytes_field_quotes(
     // name as its destination field the list of field names will be just fine.
     let into_bytes_fn = quote! {
         fn into_bytes(self) -> [u8;#struct_size] {
+            #size_assertions
             let mut output_byte_buffer: [u8;#struct_size] = [0u8;#struct_size];
             #into_bytes_quote
+            #checksums_quote
             output_byte_buffer
         }
     };
@@ -122,9 +132,9 @@ fn make_set_slice_fn(
     let fn_field_name = format_ident!("write_slice_{}", field_name);
     let type_ident = field.ty.type_quote();
     let min_length = if info.flip {
-        ((info.total_bits() - field.attrs.bit_range.start) as f64 / 8.0f64).ceil() as usize
+        (info.total_bits() - field.attrs.bit_range.start.clone()).div_ceil(8)
     } else {
-        (field.attrs.bit_range.end as f64 / 8.0f64).ceil() as usize
+        field.attrs.bit_range.end.div_ceil(8)
     };
     Ok(quote! {
         #[inline]
@@ -178,20 +188,73 @@ fn make_set_fn(
     })
 }
 
+/// the value `into_bytes` writes for `field`, `const_value` fields ignore the value in the structure.
+pub fn field_value_quote(field: &FieldInfo) -> proc_macro2::TokenStream {
+    let field_name = &field.ident;
+    if let Some(ref const_value) = field.attrs.const_value {
+        let value = &const_value.value;
+        let type_quote = field.ty.type_quote();
+        // the field is still read so it does not warn as unused.
+        quote! {{
+            let _ = &self.#field_name;
+            let value: #type_quote = #value;
+            value
+        }}
+    } else {
+        quote! {self.#field_name}
+    }
+}
+
 /// the flip value must be the total amount of bytes the result of into_bytes should have MINUS ONE,
 /// the number is used to invert indices
-fn get_field_quote(
+pub fn get_field_quote(
     field: &FieldInfo,
-    flip: Option<usize>,
+    flip: &Option<Size>,
     with_self: bool,
 ) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
     let field_name = field.name.clone();
     let quote_field_name = match field.ty {
-        FieldDataType::Float(_, _) => {
-            if with_self {
-                quote! {self.#field_name.to_bits()}
+        FieldDataType::Float(_, _, ref format) => {
+            let value = if with_self {
+                quote! {self.#field_name}
             } else {
-                quote! {#field_name.to_bits()}
+                quote! {#field_name}
+            };
+            match format {
+                Some(FloatFormat::Reduced(ref reduced)) => {
+                    let exponent_bits = reduced.exponent_bits as u32;
+                    let mantissa_bits = reduced.mantissa_bits.u32_quote();
+                    let rounding = match reduced.rounding {
+                        FloatRounding::NearestEven => quote! {FloatRounding::NearestEven},
+                        FloatRounding::TowardZero => quote! {FloatRounding::TowardZero},
+                    };
+                    quote! {BitfieldFloat::to_reduced_bits(#value, #exponent_bits, #mantissa_bits, #rounding)}
+                }
+                Some(FloatFormat::Scaled(ref scaled)) => {
+                    // the raw integer saturates to the values the bits can hold.
+                    let (min, max) = match field.attrs.bit_length() {
+                        Size::Literal(bits) => {
+                            let (min, max) = if scaled.signed {
+                                (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+                            } else {
+                                (0, (1i128 << bits) - 1)
+                            };
+                            let min = proc_macro2::Literal::i128_suffixed(min);
+                            let max = proc_macro2::Literal::i128_suffixed(max);
+                            (quote! {#min}, quote! {#max})
+                        }
+                        bits if scaled.signed => (
+                            quote! {(-(1i128 << (#bits - 1)))},
+                            quote! {((1i128 << (#bits - 1)) - 1)},
+                        ),
+                        bits => (quote! {0i128}, quote! {((1i128 << #bits) - 1)}),
+                    };
+                    let scale = proc_macro2::Literal::f64_suffixed(scaled.scale);
+                    let offset = proc_macro2::Literal::f64_suffixed(scaled.offset);
+                    let raw = field.ty.primitive_quote();
+                    quote! {(BitfieldFloat::to_scaled_raw(#value, #scale, #offset, #min, #max) as #raw)}
+                }
+                None => quote! {#value.to_bits()},
             }
         }
         FieldDataType::Char(_, _) => {
@@ -208,12 +271,27 @@ fn get_field_quote(
                 quote! {((#field_name).into_primitive())}
             }
         }
+        FieldDataType::ElementArray(_, ref length, _) | FieldDataType::BlockArray(_, ref length, _)
+            if !length.is_literal() =>
+        {
+            // the elements are written with `bitfield_layout`, which clears their bits itself.
+            let index = format_ident!("{}_index", field.name);
+            let element = field.element_at(&index)?;
+            let (element_quote, _) = get_field_quote(&element, flip, false)?;
+            let (outer_field_name, element_name) = (&field.name, &element.name);
+            let buffer = quote! {
+                for (#index, #element_name) in #outer_field_name.into_iter().enumerate() {
+                    #element_quote
+                }
+            };
+            return Ok((buffer, quote! {}));
+        }
         FieldDataType::ElementArray(_, _, _) => {
             let mut clear_buffer = quote! {};
             let mut buffer = quote! {};
             let mut de_refs: syn::punctuated::Punctuated<syn::Ident, syn::token::Comma> =
                 Default::default();
-            let outer_field_name = &field.ident;
+            let outer_field_name = &field.name;
             let sub = field.get_element_iter()?;
             for sub_field in sub {
                 let field_name = &sub_field.name;
@@ -239,7 +317,7 @@ fn get_field_quote(
             let mut clear_buffer = quote! {};
             let mut de_refs: syn::punctuated::Punctuated<syn::Ident, syn::token::Comma> =
                 Default::default();
-            let outer_field_name = &field.ident;
+            let outer_field_name = &field.name;
             let sub = field.get_block_iter()?;
             for sub_field in sub {
                 let field_name = &sub_field.name;
@@ -268,12 +346,94 @@ fn get_field_quote(
             }
         }
     };
-    match field.attrs.endianness.as_ref() {
+    if !field.has_literal_layout(flip) {
+        return Ok((get_layout_field_quote(field, quote_field_name, flip), quote! {}));
+    }
+    let flip = flip.as_ref().and_then(Size::literal);
+    // the clears made along with the field quotes miss bytes in the middle of some fields, and the
+    // bytes of a nested structure can hold more bits than the field uses. clearing the bit range
+    // keeps the bits around the field and drops everything in it.
+    let (field_quote, _) = match field.attrs.endianness.as_ref() {
         Endianness::Big => apply_be_math_to_field_access_quote(field, quote_field_name, flip),
         Endianness::Little => apply_le_math_to_field_access_quote(field, quote_field_name, flip),
         Endianness::None => apply_ne_math_to_field_access_quote(field, quote_field_name, flip),
+        Endianness::Native => {
+            let le_field = field.with_endianness(Endianness::Little);
+            let be_field = field.with_endianness(Endianness::Big);
+            let (le, _) =
+                apply_le_math_to_field_access_quote(&le_field, quote_field_name.clone(), flip)?;
+            let (be, _) = apply_be_math_to_field_access_quote(&be_field, quote_field_name, flip)?;
+            Ok((
+                quote! {
+                    #[cfg(target_endian = "little")]
+                    {
+                        #le
+                    }
+                    #[cfg(target_endian = "big")]
+                    {
+                        #be
+                    }
+                },
+                quote! {},
+            ))
+        }
+    }?;
+    Ok((field_quote, get_bit_range_clear_quote(field, flip)))
+}
+
+/// writes a field the compiler works out the position of with `bitfield_layout`, which replaces
+/// the bits of the field so nothing has to be cleared first. `value` is what the literal layout
+/// math would be given.
+fn get_layout_field_quote(
+    field: &FieldInfo,
+    value: proc_macro2::TokenStream,
+    flip: &Option<Size>,
+) -> proc_macro2::TokenStream {
+    let (start, length, flip) = field.layout_args(flip);
+    let raw = match field.ty {
+        FieldDataType::Struct(_, _) => {
+            return quote! {
+                bitfield_layout::write_bytes(&mut output_byte_buffer[..], #start, #length, #flip, &#value.into_bytes());
+            };
+        }
+        FieldDataType::Boolean => quote! {u128::from(#value)},
+        // signed values are sign extended, only their low bits are written.
+        _ => quote! {(#value as u128)},
+    };
+    let write_fn = field.layout_fn("write");
+    quote! {
+        bitfield_layout::#write_fn(&mut output_byte_buffer[..], #start, #length, #raw, #flip);
     }
 }
+
+/// clears exactly the bits in the field's bit range.
+fn get_bit_range_clear_quote(field: &FieldInfo, flip: Option<usize>) -> proc_macro2::TokenStream {
+    let range = match field.literal_range() {
+        Ok(range) => range,
+        Err(_) => return quote! {},
+    };
+    let mut clear_quote = quote! {};
+    if range.end <= range.start {
+        return clear_quote;
+    }
+    for byte in (range.start / 8)..=((range.end - 1) / 8) {
+        let first_bit = range.start.max(byte * 8) - byte * 8;
+        let end_bit = range.end.min((byte + 1) * 8) - byte * 8;
+        // bit 0 is the most significant bit of the byte.
+        let mask = get_left_and_mask(end_bit) & get_right_and_mask(8 - first_bit);
+        let not_mask = !mask;
+        let index = if let Some(flip) = flip {
+            flip - byte
+        } else {
+            byte
+        };
+        clear_quote = quote! {
+            #clear_quote
+            output_byte_buffer[#index] &= #not_mask;
+        };
+    }
+    clear_quote
+}
 // first token stream is actual setter, but second one is overwrite current bits to 0.
 fn apply_le_math_to_field_access_quote(
     field: &FieldInfo,
@@ -347,7 +507,7 @@ fn apply_le_math_to_field_access_quote(
         // here we finish the buffer setup and give it the value returned by to_bytes from the number
         let field_byte_buffer = match field.ty {
             FieldDataType::Number(_, _, _) |
-            FieldDataType::Float(_, _) |
+            FieldDataType::Float(_, _, _) |
             FieldDataType::Char(_, _) => {
                 let field_call = quote!{#field_access_quote.to_le_bytes()};
                 let apply_field_to_buffer = quote! {
@@ -376,7 +536,7 @@ fn apply_le_math_to_field_access_quote(
         let mut i = 0;
         let mut clear_quote = quote! {};
         while i != fields_last_bits_index {
-            let start = if let None = flip {
+            let start = if flip.is_none() {
                 starting_inject_byte + i
             } else {
                 starting_inject_byte - i
@@ -423,12 +583,12 @@ fn apply_le_math_to_field_access_quote(
         // bits used after applying the first_bit_mask one more time.
         let used_bits = available_bits_in_first_byte + (8 * i);
         if right_shift > 0 {
-            let start = if let None = flip {
+            let start = if flip.is_none() {
                 starting_inject_byte + i
             } else {
                 starting_inject_byte - i
             };
-            let right_shift: u32 = right_shift.clone() as u32;
+            let right_shift: u32 = right_shift as u32;
             let not_first_bit_mask = !first_bit_mask;
             let not_last_bit_mask = !last_bit_mask;
 
@@ -448,7 +608,7 @@ fn apply_le_math_to_field_access_quote(
                     output_byte_buffer[#start #operator 1] |= #field_buffer_name[#i] & #last_bit_mask;
                 };
             } else {
-                let mut last_mask = first_bit_mask.clone();
+                let mut last_mask = first_bit_mask;
                 if amount_of_bits <= used_bits {
                     last_mask &= !get_right_and_mask(used_bits - amount_of_bits);
                 }
@@ -463,14 +623,14 @@ fn apply_le_math_to_field_access_quote(
                 };
             }
         } else {
-            let start = if let None = flip {
+            let start = if flip.is_none() {
                 starting_inject_byte + i
             } else {
                 starting_inject_byte - i
             };
             // this should give us the last index of the field
-            let left_shift: u32 = right_shift.clone().abs() as u32;
-            let mut last_mask = first_bit_mask.clone();
+            let left_shift: u32 = right_shift.unsigned_abs() as u32;
+            let mut last_mask = first_bit_mask;
             if amount_of_bits <= used_bits {
                 last_mask &= !get_right_and_mask(used_bits - amount_of_bits);
             }
@@ -506,17 +666,17 @@ fn apply_le_math_to_field_access_quote(
             & get_left_and_mask(8 - zeros_on_right);
         // calculate how many left shifts need to occur to the number in order to position the bytes
         // we want to keep in the position we want.
-        if 8 - amount_of_bits < field.attrs.bit_range.start % 8 {
+        if 8 - amount_of_bits < field.literal_range()?.start % 8 {
             return Err(syn::Error::new(
                 field.ident.span(),
                 format!(
                     "calculating be left_shift failed {} , {}",
                     amount_of_bits,
-                    field.attrs.bit_range.start % 8
+                    field.literal_range()?.start % 8
                 ),
             ));
         }
-        let shift_left = (8 - amount_of_bits) - (field.attrs.bit_range.start % 8);
+        let shift_left = (8 - amount_of_bits) - (field.literal_range()?.start % 8);
         // a quote that puts the field into a byte buffer we assume exists (because this is a
         // fragment).
         // NOTE the mask used here is only needed if we can NOT guarantee the field is only using the
@@ -532,16 +692,15 @@ fn apply_le_math_to_field_access_quote(
         //          in the note above)
         // both of these could benefit from a return of the number that actually got set.
         let field_as_u8_quote = match field.ty {
-            FieldDataType::Number(_, _, _) => {
+            // reduced floats are already turned into their bits by the field access.
+            FieldDataType::Number(_, _, _) | FieldDataType::Enum(_, _, _) | FieldDataType::Float(_, _, _) => {
                 quote!{(#field_access_quote as u8)}
             }
             FieldDataType::Boolean => {
                 quote!{(#field_access_quote as u8)}
             }
             FieldDataType::Char(_, _) => return Err(syn::Error::new(field.ident.span(), "Char not supported for single byte insert logic")),
-            FieldDataType::Enum(_, _, _) => field_access_quote,
             FieldDataType::Struct(_, _) => return Err(syn::Error::new(field.ident.span(), "Struct was given Endianness which should be described by the struct implementing Bitfield")),
-            FieldDataType::Float(_, _) => return Err(syn::Error::new(field.ident.span(), "Float not supported for single byte insert logic")),
             FieldDataType::ElementArray(_, _, _) | FieldDataType::BlockArray(_, _, _) => return Err(syn::Error::new(field.ident.span(), "an array got passed into apply_be_math_to_field_access_quote, which is bad.")),
         };
         let not_mask = !mask;
@@ -580,22 +739,22 @@ fn apply_ne_math_to_field_access_quote(
                 "calculating ne right_shift failed",
             ));
         }
-        let right_shift: i8 = (8 as i8) - ((available_bits_in_first_byte % 8) as i8);
+        let right_shift: i8 = 8_i8 - ((available_bits_in_first_byte % 8) as i8);
         // make a name for the buffer that we will store the number in byte form
         let field_buffer_name = format_ident!("{}_bytes", field.ident.as_ref());
         // here we finish the buffer setup and give it the value returned by to_bytes from the number
         let (field_byte_buffer, size) = match field.ty {
             FieldDataType::Number(_, _,_ ) |
-            FieldDataType::Float(_, _) |
+            FieldDataType::Float(_, _, _) |
             FieldDataType::Char(_, _) => return Err(syn::Error::new(field.ident.span(), "Char was not given Endianness, please report this.")),
             FieldDataType::Boolean => return Err(syn::Error::new(field.ident.span(), "matched a boolean data type in generate code for bits that span multiple bytes in the output")),
             FieldDataType::Enum(_, _, _) => return Err(syn::Error::new(field.ident.span(), "Enum was not given Endianness, please report this.")),
-            FieldDataType::Struct(ref size, _) => {
+            FieldDataType::Struct(_, _) => {
                 let field_call = quote!{#field_access_quote.into_bytes()};
                 let apply_field_to_buffer = quote! {
                     let mut #field_buffer_name = #field_call
                 };
-                (apply_field_to_buffer, size.clone())
+                (apply_field_to_buffer, field.struct_byte_size()?)
             }
             FieldDataType::ElementArray(_, _, _) | FieldDataType::BlockArray(_, _, _) => return Err(syn::Error::new(field.ident.span(), "an array got passed into apply_ne_math_to_field_access_quote, which is bad."))
         };
@@ -612,7 +771,7 @@ fn apply_ne_math_to_field_access_quote(
             let next_bit_mask = get_left_and_mask(8 - available_bits_in_first_byte);
             let right_shift: u32 = right_shift as u32;
             for i in 0usize..size {
-                let start = if let None = flip {
+                let start = if flip.is_none() {
                     starting_inject_byte + i
                 } else {
                     starting_inject_byte - i
@@ -669,7 +828,7 @@ fn apply_ne_math_to_field_access_quote(
             let current_bit_mask = get_right_and_mask(available_bits_in_first_byte);
 
             for i in 0usize..size {
-                let start = if let None = flip {
+                let start = if flip.is_none() {
                     starting_inject_byte + i
                 } else {
                     starting_inject_byte - i
@@ -713,13 +872,13 @@ fn apply_ne_math_to_field_access_quote(
             & get_left_and_mask(8 - zeros_on_right);
         // calculate how many left shifts need to occur to the number in order to position the bytes
         // we want to keep in the position we want.
-        if 8 < amount_of_bits || 8 - amount_of_bits < field.attrs.bit_range.start % 8 {
+        if 8 < amount_of_bits || 8 - amount_of_bits < field.literal_range()?.start % 8 {
             return Err(syn::Error::new(
                 field.ident.span(),
                 "calculating ne shift_left failed",
             ));
         }
-        let shift_left = (8 - amount_of_bits) - (field.attrs.bit_range.start % 8);
+        let shift_left = (8 - amount_of_bits) - (field.literal_range()?.start % 8);
 
         let not_mask = !mask;
         let clear_quote = quote! {
@@ -750,7 +909,7 @@ fn apply_ne_math_to_field_access_quote(
                 let used_bits_in_byte = 8 - available_bits_in_first_byte;
                 quote!{output_byte_buffer[#starting_inject_byte] |= (#field_access_quote.into_bytes()[0]) >> #used_bits_in_byte;}
             }
-            FieldDataType::Float(_, _) => return Err(syn::Error::new(field.ident.span(), "Float not supported for single byte insert logic")),
+            FieldDataType::Float(_, _, _) => return Err(syn::Error::new(field.ident.span(), "Float not supported for single byte insert logic")),
             FieldDataType::ElementArray(_, _, _) | FieldDataType::BlockArray(_, _, _) => return Err(syn::Error::new(field.ident.span(), "an array got passed into apply_ne_math_to_field_access_quote, which is bad."))
         };
         Ok((finished_quote, clear_quote))
@@ -760,7 +919,7 @@ fn apply_ne_math_to_field_access_quote(
 /// # Arguments
 /// * `field' - reference to the FieldInfo.
 /// * `field_access_quote` - a quote containing access to to byte array of the field.
-///                             ex. quote!{(self.char_field as u32)}
+///   ex. quote!{(self.char_field as u32)}
 fn apply_be_math_to_field_access_quote(
     field: &FieldInfo,
     field_access_quote: proc_macro2::TokenStream,
@@ -808,20 +967,20 @@ fn apply_be_math_to_field_access_quote(
         // index of the fields byte array will be used.
         let (shift, first_bits_index) = if right_shift < 0 {
             // convert to left shift using absolute value
-            let left_shift: u32 = right_shift.clone().abs() as u32;
+            let left_shift: u32 = right_shift.unsigned_abs() as u32;
             // shift left code
             (
                 quote! { (#field_access_quote.rotate_left(#left_shift)) },
                 // if the size of the field type is the same as the bit size going into the
                 // bit_buffer then we use the last byte for applying to the buffers first effected
                 // byte.
-                if field.ty.size() * 8 == amount_of_bits {
-                    field.ty.size() - 1
+                if field.struct_byte_size()? * 8 == amount_of_bits {
+                    field.struct_byte_size()? - 1
                 } else {
                     match get_be_starting_index(
                         amount_of_bits,
                         right_shift,
-                        field.struct_byte_size(),
+                        field.struct_byte_size()?,
                     ) {
                         Ok(good) => good,
                         Err(err) => return Err(syn::Error::new(field.ident.span(), err)),
@@ -835,10 +994,10 @@ fn apply_be_math_to_field_access_quote(
                     quote! { #field_access_quote }
                 } else {
                     // shift right code
-                    let right_shift_usize: u32 = right_shift.clone() as u32;
+                    let right_shift_usize: u32 = right_shift as u32;
                     quote! { (#field_access_quote.rotate_right(#right_shift_usize)) }
                 },
-                match get_be_starting_index(amount_of_bits, right_shift, field.struct_byte_size()) {
+                match get_be_starting_index(amount_of_bits, right_shift, field.struct_byte_size()?) {
                     Ok(good) => good,
                     Err(err) => return Err(syn::Error::new(field.ident.span(), err)),
                 },
@@ -849,7 +1008,7 @@ fn apply_be_math_to_field_access_quote(
         // here we finish the buffer setup and give it the value returned by to_bytes from the number
         let field_byte_buffer = match field.ty {
             FieldDataType::Number(_, _, _) |
-            FieldDataType::Float(_, _) |
+            FieldDataType::Float(_, _, _) |
             FieldDataType::Char(_, _) => {
                 let field_call = quote!{#shift.to_be_bytes()};
                 let apply_field_to_buffer = quote! {
@@ -877,7 +1036,7 @@ fn apply_be_math_to_field_access_quote(
             output_byte_buffer[#starting_inject_byte] |= #field_buffer_name[#first_bits_index] & #first_bit_mask;
         };
         // fill in the rest of the bits
-        let mut current_byte_index_in_buffer: usize = if let None = flip {
+        let mut current_byte_index_in_buffer: usize = if flip.is_none() {
             starting_inject_byte + 1
         } else {
             starting_inject_byte - 1
@@ -886,7 +1045,7 @@ fn apply_be_math_to_field_access_quote(
         if right_shift > 0 {
             // right shift (this means that the last bits are in the first byte)
             if available_bits_in_first_byte + bits_in_last_byte != amount_of_bits {
-                for i in first_bits_index + 1usize..field.ty.size() {
+                for i in first_bits_index + 1usize..field.struct_byte_size()? {
                     clear_quote = quote! {
                         #clear_quote
                         output_byte_buffer[#current_byte_index_in_buffer] = 0u8;
@@ -895,7 +1054,7 @@ fn apply_be_math_to_field_access_quote(
                         #full_quote
                         output_byte_buffer[#current_byte_index_in_buffer] |= #field_buffer_name[#i];
                     };
-                    current_byte_index_in_buffer = if let None = flip {
+                    current_byte_index_in_buffer = if flip.is_none() {
                         current_byte_index_in_buffer + 1
                     } else {
                         current_byte_index_in_buffer - 1
@@ -913,12 +1072,12 @@ fn apply_be_math_to_field_access_quote(
         } else {
             // no shift
             if available_bits_in_first_byte + bits_in_last_byte != amount_of_bits {
-                for i in first_bits_index + 1..field.ty.size() - 1 {
+                for i in first_bits_index + 1..field.struct_byte_size()? - 1 {
                     full_quote = quote! {
                         #full_quote
                         output_byte_buffer[#current_byte_index_in_buffer] |= #field_buffer_name[#i];
                     };
-                    current_byte_index_in_buffer = if let None = flip {
+                    current_byte_index_in_buffer = if flip.is_none() {
                         current_byte_index_in_buffer + 1
                     } else {
                         current_byte_index_in_buffer - 1
@@ -926,7 +1085,7 @@ fn apply_be_math_to_field_access_quote(
                 }
             }
             // this should give us the last index of the field
-            let final_index = field.ty.size() - 1;
+            let final_index = field.struct_byte_size()? - 1;
             clear_quote = quote! {
                 #clear_quote
                 output_byte_buffer[#current_byte_index_in_buffer] &= #not_last_bit_mask;
@@ -958,17 +1117,17 @@ fn apply_be_math_to_field_access_quote(
             & get_left_and_mask(8 - zeros_on_right);
         // calculate how many left shifts need to occur to the number in order to position the bytes
         // we want to keep in the position we want.
-        if 8 - amount_of_bits < field.attrs.bit_range.start % 8 {
+        if 8 - amount_of_bits < field.literal_range()?.start % 8 {
             return Err(syn::Error::new(
                 field.ident.span(),
                 format!(
                     "calculating be left_shift failed {} , {}",
                     amount_of_bits,
-                    field.attrs.bit_range.start % 8
+                    field.literal_range()?.start % 8
                 ),
             ));
         }
-        let shift_left = (8 - amount_of_bits) - (field.attrs.bit_range.start % 8);
+        let shift_left = (8 - amount_of_bits) - (field.literal_range()?.start % 8);
         // a quote that puts the field into a byte buffer we assume exists (because this is a
         // fragment).
         // NOTE the mask used here is only needed if we can NOT guarantee the field is only using the
@@ -984,16 +1143,15 @@ fn apply_be_math_to_field_access_quote(
         //          in the note above)
         // both of these could benefit from a return of the number that actually got set.
         let field_as_u8_quote = match field.ty {
-            FieldDataType::Number(_, _, _) => {
+            // reduced floats are already turned into their bits by the field access.
+            FieldDataType::Number(_, _, _) | FieldDataType::Enum(_, _, _) | FieldDataType::Float(_, _, _) => {
                 quote!{(#field_access_quote as u8)}
             }
             FieldDataType::Boolean => {
                 quote!{(#field_access_quote as u8)}
             }
             FieldDataType::Char(_, _) => return Err(syn::Error::new(field.ident.span(), "Char not supported for single byte insert logic")),
-            FieldDataType::Enum(_, _, _) => field_access_quote,
             FieldDataType::Struct(_, _) => return Err(syn::Error::new(field.ident.span(), "Struct was given Endianness which should be described by the struct implementing Bitfield")),
-            FieldDataType::Float(_, _) => return Err(syn::Error::new(field.ident.span(), "Float not supported for single byte insert logic")),
             FieldDataType::ElementArray(_, _, _) | FieldDataType::BlockArray(_, _, _) => return Err(syn::Error::new(field.ident.span(), "an array got passed into apply_be_math_to_field_access_quote, which is bad.")),
         };
         let not_mask = !mask;
@@ -1,5 +1,11 @@
+pub mod byte_order;
+pub mod checksum;
 pub mod common;
+pub mod dynamic;
 pub mod from_bytes;
 pub mod into_bytes;
 pub mod parse;
+pub mod paths;
 pub mod struct_fns;
+pub mod variants;
+pub mod view;
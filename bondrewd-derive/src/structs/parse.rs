@@ -1,26 +1,30 @@
 use proc_macro2::Span;
-use quote::format_ident;
+use quote::{format_ident, quote, ToTokens};
 use std::ops::Range;
 use syn::parse::Error;
-use syn::{Ident, Lit, Meta, NestedMeta};
+use syn::spanned::Spanned;
+use syn::{Expr, Ident};
 
-use crate::structs::common::{Endianness, FieldAttrs, FieldInfo, ReserveFieldOption};
+use crate::attrs::{bondrewd_attr_items, eval_usize, AttrItem, FIELD_ATTRS};
+use crate::structs::common::{
+    Endianness, FieldAttrs, FieldInfo, ReserveFieldOption, Size, SizeChecks,
+};
 
 pub struct TryFromAttrBuilderError {
     pub endianness: Box<Endianness>,
-    pub reserve: bool,
+    pub reserve: ReserveFieldOption,
+    pub strict: bool,
 }
 
 impl TryFromAttrBuilderError {
-    pub fn fix(self, bit_range: Range<usize>) -> FieldAttrs {
+    pub fn fix(self, bit_range: Range<Size>) -> FieldAttrs {
         FieldAttrs {
             endianness: self.endianness,
             bit_range,
-            reserve: if self.reserve {
-                ReserveFieldOption::ReserveField
-            } else {
-                ReserveFieldOption::NotReserve
-            },
+            reserve: self.reserve,
+            strict: self.strict,
+            const_value: None,
+            checksum: None,
         }
     }
 }
@@ -34,30 +38,94 @@ impl std::fmt::Display for TryFromAttrBuilderError {
     }
 }
 
+/// rounding for float fields with fewer mantissa bits than their type, matches
+/// `bondrewd::FloatRounding`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatRounding {
+    NearestEven,
+    TowardZero,
+}
+
+/// one end of the bytes a checksum covers.
+#[derive(Clone, Debug)]
+pub enum ChecksumBound {
+    Byte(usize),
+    /// the byte the field starts in.
+    Field(Ident),
+    /// the end of the structure.
+    End,
+}
+
+impl ChecksumBound {
+    /// `missing` is used when the range leaves out this end, ex. `..4`.
+    fn parse(expr: Option<&Expr>, missing: ChecksumBound) -> syn::Result<ChecksumBound> {
+        match expr {
+            None => Ok(missing),
+            Some(Expr::Path(ref path)) => {
+                if let Some(ident) = path.path.get_ident() {
+                    Ok(ChecksumBound::Field(ident.clone()))
+                } else {
+                    Err(Error::new(
+                        path.span(),
+                        "over can only use byte indexes or the names of fields",
+                    ))
+                }
+            }
+            Some(expr) => Ok(ChecksumBound::Byte(eval_usize(expr, "over")?)),
+        }
+    }
+}
+
+/// a fixed point format like `Q3.12`, the sign bit is not counted in `integer_bits`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QFormat {
+    pub signed: bool,
+    pub integer_bits: usize,
+    pub fraction_bits: usize,
+}
+
+impl QFormat {
+    /// parses `Qm.n` for signed and `UQm.n` for unsigned fixed point.
+    fn parse(format: &str) -> Option<Self> {
+        let (signed, rest) = if let Some(rest) = format.strip_prefix("UQ") {
+            (false, rest)
+        } else {
+            (true, format.strip_prefix('Q')?)
+        };
+        let (integer_bits, fraction_bits) = rest.split_once('.')?;
+        Some(Self {
+            signed,
+            integer_bits: integer_bits.parse().ok()?,
+            fraction_bits: fraction_bits.parse().ok()?,
+        })
+    }
+
+    pub fn bit_length(&self) -> usize {
+        self.integer_bits + self.fraction_bits + if self.signed { 1 } else { 0 }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FieldAttrBuilderType {
     None,
-    Struct(usize),
+    Struct(Size),
     Enum(usize, Ident),
     // amount of bits for each element.
-    ElementArray(usize, Box<Option<FieldAttrBuilderType>>),
+    ElementArray(Size, Box<Option<FieldAttrBuilderType>>),
     BlockArray(Box<Option<FieldAttrBuilderType>>),
 }
 
 #[derive(Clone, Debug)]
+#[derive(Default)]
 pub enum FieldBuilderRange {
     // a range of bits to use.
-    Range(std::ops::Range<usize>),
+    Range(std::ops::Range<Size>),
     // used to pass on the last starting location to another part to figure out.
-    LastEnd(usize),
+    LastEnd(Size),
+    #[default]
     None,
 }
 
-impl Default for FieldBuilderRange {
-    fn default() -> Self {
-        Self::None
-    }
-}
 
 #[derive(Clone, Debug)]
 pub struct FieldAttrBuilder {
@@ -67,6 +135,28 @@ pub struct FieldAttrBuilder {
     pub bit_range: FieldBuilderRange,
     pub ty: FieldAttrBuilderType,
     pub reserve: bool,
+    pub strict: bool,
+    /// exponent bits of a float field stored with fewer bits than its type.
+    pub exponent_bits: Option<usize>,
+    /// rounding of a float field stored with fewer bits than its type.
+    pub rounding: Option<FloatRounding>,
+    /// float fields stored as an integer, `value = raw * scale + offset`.
+    pub scale: Option<f64>,
+    pub offset: Option<f64>,
+    /// float fields stored as fixed point.
+    pub q: Option<QFormat>,
+    /// the bit range was given with `bits`.
+    pub explicit_bits: bool,
+    /// the field is another view of bits owned by other fields.
+    pub overlay: bool,
+    /// the field is another view of the bits of the named field.
+    pub alias_of: Option<Ident>,
+    /// the value the field is always written with.
+    pub const_value: Option<proc_macro2::TokenStream>,
+    /// the `BitfieldChecksum` type filling the field.
+    pub checksum: Option<proc_macro2::TokenStream>,
+    /// the bytes the checksum covers, `start..end`.
+    pub over: Option<(ChecksumBound, ChecksumBound)>,
 }
 
 impl FieldAttrBuilder {
@@ -77,6 +167,18 @@ impl FieldAttrBuilder {
             bit_range: FieldBuilderRange::None,
             ty: FieldAttrBuilderType::None,
             reserve: false,
+            strict: false,
+            exponent_bits: None,
+            rounding: None,
+            scale: None,
+            offset: None,
+            q: None,
+            explicit_bits: false,
+            overlay: false,
+            alias_of: None,
+            const_value: None,
+            checksum: None,
+            over: None,
         }
     }
 
@@ -84,10 +186,21 @@ impl FieldAttrBuilder {
         self.name.span()
     }
 
+    fn reserve_option(&self) -> ReserveFieldOption {
+        if self.overlay || self.alias_of.is_some() {
+            ReserveFieldOption::Overlay
+        } else if self.reserve {
+            ReserveFieldOption::ReserveField
+        } else {
+            ReserveFieldOption::NotReserve
+        }
+    }
+
     pub fn parse(
         field: &syn::Field,
         last_field: Option<&FieldInfo>,
         name: Box<Ident>,
+        checks: &mut SizeChecks,
     ) -> syn::Result<FieldAttrBuilder> {
         let mut builder = FieldAttrBuilder::new(name);
         // TODO make this more compact. use match or something.
@@ -97,473 +210,377 @@ impl FieldAttrBuilder {
         // end ( this only works if a all bit fields are in order, ex. if a bit_range attribute defines a
         // complete range which occupies the same space as this field and that field is not the "last_field"
         // you will get a conflicting fields error returned to the user... hopefully )
-        for attr in field.attrs.iter() {
-            let meta = attr.parse_meta()?;
-            Self::parse_meta(meta, &last_field, &mut builder)?;
+        for item in bondrewd_attr_items(&field.attrs, "bondrewd", &FIELD_ATTRS)? {
+            Self::parse_item(item, &last_field, &mut builder, checks)?;
         }
         if let FieldBuilderRange::None = builder.bit_range {
-            builder.bit_range = FieldBuilderRange::LastEnd(if let Some(last_value) = last_field {
-                last_value.attrs.bit_range.end
-            } else {
-                0
-            })
+            builder.bit_range = FieldBuilderRange::LastEnd(Self::last_end(&last_field));
         }
 
         Ok(builder)
     }
 
-    fn parse_meta(
-        meta: Meta,
+    /// where the field before ends, the start of a field without `bits`.
+    fn last_end(last_field: &Option<&FieldInfo>) -> Size {
+        if let Some(last_value) = last_field {
+            last_value.attrs.bit_range.end.clone()
+        } else {
+            Size::Literal(0)
+        }
+    }
+
+    fn parse_item(
+        item: AttrItem,
         last_field: &Option<&FieldInfo>,
         builder: &mut Self,
+        checks: &mut SizeChecks,
     ) -> syn::Result<()> {
-        match meta {
-            Meta::NameValue(value) => {
-                if let Some(ident) = value.path.get_ident() {
-                    let ident_as_str = ident.to_string();
-                    match ident_as_str.as_str() {
-                        "endianness" => {
-                            if let Lit::Str(val) = value.lit {
-                                builder.endianness = Box::new(match val.value().as_str() {
-                                    "le" | "lsb" | "little" | "lil" => Endianness::Little,
-                                    "be" | "msb" | "big" => Endianness::Big,
-                                    "ne" | "native" => Endianness::None,
-                                    _ => {
-                                        return Err(syn::Error::new(
-                                            builder.span(),
-                                            "{} is not a valid endianness use le or be",
-                                        ));
-                                    }
-                                });
-                            }
+        let ident_as_str = item.name();
+        match ident_as_str.as_str() {
+            "endianness" => {
+                if let Some(val) = item.lit_str() {
+                    *builder.endianness = Endianness::from_lit(val)?;
+                } else {
+                    return Err(Error::new(
+                        item.span(),
+                        "endianness must use a literal str, ex. endianness = \"be\"",
+                    ));
+                }
+            }
+            "bit_length" | "byte_length" => {
+                let mut bit_length = item.size_value()?;
+                if ident_as_str == "byte_length" {
+                    bit_length = bit_length * 8;
+                }
+                match builder.bit_range {
+                    FieldBuilderRange::None => {
+                        let start = Self::last_end(last_field);
+                        builder.bit_range = FieldBuilderRange::Range(start.clone()..start + bit_length);
+                    }
+                    // bits can come before the length, they only have to agree.
+                    FieldBuilderRange::Range(ref range) => {
+                        let length = range.end.clone() - range.start.clone();
+                        checks.require(length.equals(&bit_length), item.span(), || {
+                            format!(
+                                "{} does not match the {} bits of the range already given to {}",
+                                ident_as_str, length, builder.name
+                            )
+                        })?;
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            builder.span(),
+                            "please don't double define bit_length",
+                        ));
+                    }
+                }
+            }
+            "enum_primitive" => {
+                if let Some(val) = item.lit_str() {
+                    let mut ty = Some(match val.value().as_str() {
+                        "u8" => FieldAttrBuilderType::Enum(1, format_ident!("u8")),
+                        "u16" => FieldAttrBuilderType::Enum(2, format_ident!("u16")),
+                        "u32" => FieldAttrBuilderType::Enum(4, format_ident!("u32")),
+                        "u64" => FieldAttrBuilderType::Enum(8, format_ident!("u64")),
+                        "u128" => FieldAttrBuilderType::Enum(16, format_ident!("u128")),
+                        "i8" => FieldAttrBuilderType::Enum(1, format_ident!("i8")),
+                        "i16" => FieldAttrBuilderType::Enum(2, format_ident!("i16")),
+                        "i32" => FieldAttrBuilderType::Enum(4, format_ident!("i32")),
+                        "i64" => FieldAttrBuilderType::Enum(8, format_ident!("i64")),
+                        "i128" => FieldAttrBuilderType::Enum(16, format_ident!("i128")),
+                        _ => {
+                            return Err(syn::Error::new(
+                                builder.span(),
+                                "primitives for enums must be an integer",
+                            ))
                         }
-                        "bit_length" => {
-                            if let FieldBuilderRange::None = builder.bit_range {
-                                if let Lit::Int(val) = value.lit {
-                                    match val.base10_parse::<usize>() {
-                                        Ok(bit_length) => {
-                                            let mut start = 0;
-                                            if let Some(last_value) = last_field {
-                                                start = last_value.attrs.bit_range.end;
-                                            }
-                                            builder.bit_range = FieldBuilderRange::Range(
-                                                start..start + (bit_length),
-                                            );
-                                        }
-                                        Err(err) => {
-                                            return Err(Error::new(
-                                                builder.span(),
-                                                format!("bit_length must be a number that can be parsed as a usize [{}]", err),
-                                            ));
-                                        }
-                                    }
-                                } else {
-                                    return Err(Error::new(
-                                        builder.span(),
-                                        "bit_length must use a literal usize",
-                                    ));
-                                }
-                            } else {
-                                return Err(Error::new(
-                                    builder.span(),
-                                    "please don't double define bit_length",
-                                ));
-                            }
+                    });
+                    match builder.ty {
+                        FieldAttrBuilderType::BlockArray(ref mut sub_ty) => {
+                            std::mem::swap(&mut ty, sub_ty)
                         }
-                        "byte_length" => {
-                            if let FieldBuilderRange::None = builder.bit_range {
-                                if let Lit::Int(val) = value.lit {
-                                    match val.base10_parse::<usize>() {
-                                        Ok(byte_length) => {
-                                            let mut start = 0;
-                                            if let Some(last_value) = last_field {
-                                                start = last_value.attrs.bit_range.end;
-                                            }
-                                            builder.bit_range = FieldBuilderRange::Range(
-                                                start..start + (byte_length * 8),
-                                            );
-                                        }
-                                        Err(err) => {
-                                            return Err(Error::new(
-                                                builder.span(),
-                                                format!("bit length must be a number that can be parsed as a usize [{}]", err),
-                                            ));
-                                        }
-                                    }
-                                } else {
-                                    return Err(Error::new(
-                                        builder.span(),
-                                        "bit_length must use a literal usize",
-                                    ));
-                                }
-                            } else {
-                                return Err(Error::new(
-                                    builder.span(),
-                                    "please don't double define bit width",
-                                ));
-                            }
+                        FieldAttrBuilderType::ElementArray(_, ref mut sub_ty) => {
+                            std::mem::swap(&mut ty, sub_ty)
                         }
-                        "enum_primitive" => {
-                            if let Lit::Str(val) = value.lit {
-                                let mut ty = Some(match val.value().as_str() {
-                                    "u8" => FieldAttrBuilderType::Enum(1, format_ident!("u8")),
-                                    "u16" => FieldAttrBuilderType::Enum(2, format_ident!("u16")),
-                                    "u32" => FieldAttrBuilderType::Enum(4, format_ident!("u32")),
-                                    "u64" => FieldAttrBuilderType::Enum(8, format_ident!("u64")),
-                                    "u128" => FieldAttrBuilderType::Enum(16, format_ident!("u128")),
-                                    _ => {
-                                        return Err(syn::Error::new(
-                                            builder.span(),
-                                            "primitives for enums must be an unsigned integer",
-                                        ))
-                                    }
-                                });
-                                match builder.ty {
-                                    FieldAttrBuilderType::BlockArray(ref mut sub_ty) => {
-                                        std::mem::swap(&mut ty, sub_ty)
-                                    }
-                                    FieldAttrBuilderType::ElementArray(_, ref mut sub_ty) => {
-                                        std::mem::swap(&mut ty, sub_ty)
-                                    }
-                                    _ => {
-                                        builder.ty = ty.unwrap();
-                                    }
-                                }
-                            } else {
-                                return Err(Error::new(
-                                    builder.span(),
-                                    format!("defining a struct_size requires a Int Literal"),
-                                ));
-                            }
+                        _ => {
+                            builder.ty = ty.unwrap();
                         }
-                        "struct_size" => {
-                            if let Lit::Int(val) = value.lit {
-                                let mut ty = Some(match val.base10_parse::<usize>() {
-                                    Ok(byte_length) => FieldAttrBuilderType::Struct(byte_length),
-                                    Err(err) => {
-                                        return Err(Error::new(
-                                            builder.span(),
-                                            format!("struct_size must provided a number that can be parsed as a usize [{}]", err),
-                                        ));
-                                    }
-                                });
-                                match builder.ty {
-                                    FieldAttrBuilderType::BlockArray(ref mut sub_ty) => {
-                                        std::mem::swap(&mut ty, sub_ty.as_mut())
-                                    }
-                                    FieldAttrBuilderType::ElementArray(_, ref mut sub_ty) => {
-                                        std::mem::swap(&mut ty, sub_ty.as_mut())
-                                    }
-                                    _ => {
-                                        builder.ty = ty.unwrap();
-                                    }
-                                }
-                            } else {
+                    }
+                } else {
+                    return Err(Error::new(
+                        item.span(),
+                        "enum_primitive must use a literal str, ex. enum_primitive = \"u8\"",
+                    ));
+                }
+            }
+            "struct_size" => {
+                let mut ty = Some(FieldAttrBuilderType::Struct(item.size_value()?));
+                match builder.ty {
+                    FieldAttrBuilderType::BlockArray(ref mut sub_ty) => {
+                        std::mem::swap(&mut ty, sub_ty.as_mut())
+                    }
+                    FieldAttrBuilderType::ElementArray(_, ref mut sub_ty) => {
+                        std::mem::swap(&mut ty, sub_ty.as_mut())
+                    }
+                    _ => {
+                        builder.ty = ty.unwrap();
+                    }
+                }
+            }
+            "bits" => {
+                let (start, end): (Size, Size) = if let Some(range) = item.range_value() {
+                    let range = range?;
+                    (range.start, range.end)
+                } else if let Some(val) = item.lit_str() {
+                    let val_string = val.value();
+                    let split = val_string.split("..").collect::<Vec<&str>>();
+                    if split.len() == 2 {
+                        match (split[0].parse::<usize>(), split[1].parse::<usize>()) {
+                            (Ok(start), Ok(end)) => (start.into(), end.into()),
+                            (Ok(_), Err(_)) => {
                                 return Err(Error::new(
                                     builder.span(),
-                                    format!("defining a struct_size requires a Int Literal"),
+                                    "failed paring ending index for range",
                                 ));
                             }
-                        }
-                        "bits" => {
-                            if let Lit::Str(val) = value.lit {
-                                let val_string = val.value();
-                                let split =
-                                    val_string.split("..").into_iter().collect::<Vec<&str>>();
-                                if split.len() == 2 {
-                                    match (split[0].parse::<usize>(), split[1].parse::<usize>()) {
-                                        (Ok(start), Ok(end)) => match builder.bit_range {
-                                            FieldBuilderRange::Range(ref range) => {
-                                                if range.end - range.start == end - start {
-                                                    builder.bit_range =
-                                                        FieldBuilderRange::Range(start..end);
-                                                } else {
-                                                    return Err(Error::new(
-                                                        builder.span(),
-                                                        "bits attribute didn't match bit range requirements",
-                                                    ));
-                                                }
-                                            }
-                                            _ => {
-                                                builder.bit_range =
-                                                    FieldBuilderRange::Range(start..end);
-                                            }
-                                        },
-                                        (Ok(_), Err(_)) => {
-                                            return Err(Error::new(
-                                                builder.span(),
-                                                "failed paring ending index for range",
-                                            ));
-                                        }
-                                        (Err(_), Ok(_)) => {
-                                            return Err(Error::new(
-                                                builder.span(),
-                                                "failed paring starting index for range",
-                                            ));
-                                        }
-                                        _ => {
-                                            return Err(Error::new(
-                                                builder.span(),
-                                                "failed paring range",
-                                            ));
-                                        }
-                                    }
-                                } else {
-                                    return Err(Error::new(
-                                        builder.span(),
-                                        "bits attribute should have data like \"0..8\"",
-                                    ));
-                                }
-                            } else {
+                            (Err(_), Ok(_)) => {
                                 return Err(Error::new(
                                     builder.span(),
-                                    "bits must use a literal str value with range inside quotes",
+                                    "failed paring starting index for range",
                                 ));
                             }
-                        }
-                        "element_bit_length" => {
-                            if let Lit::Int(val) = value.lit {
-                                match val.base10_parse::<usize>() {
-                                    Ok(bit_length) => {
-                                        builder.bit_range = match std::mem::take(&mut builder.bit_range) {
-                                            FieldBuilderRange::None => {
-                                                builder.ty = match builder.ty {
-                                                    FieldAttrBuilderType::Struct(_) |
-                                                    FieldAttrBuilderType::Enum(_, _) => {
-                                                        FieldAttrBuilderType::ElementArray(bit_length, Box::new(Some(builder.ty.clone())))
-                                                    }
-                                                    _ => FieldAttrBuilderType::ElementArray(bit_length, Box::new(None)),
-                                                };
-                                                if let Some(last_value) = last_field {
-                                                    FieldBuilderRange::LastEnd(last_value.attrs.bit_range.end)
-                                                }else{
-                                                    FieldBuilderRange::LastEnd(0)
-                                                }
-                                            }
-                                            FieldBuilderRange::Range(range) => {
-                                                builder.ty = match builder.ty {
-                                                    FieldAttrBuilderType::Struct(_) |
-                                                    FieldAttrBuilderType::Enum(_, _) => {
-                                                        FieldAttrBuilderType::ElementArray(bit_length, Box::new(Some(builder.ty.clone())))
-                                                    }
-                                                    _ => FieldAttrBuilderType::ElementArray(bit_length, Box::new(None)),
-                                                };
-                                                FieldBuilderRange::Range(range)
-                                            }
-                                            _ => return Err(Error::new(
-                                                builder.span(),
-                                                "found Field bit range no_end while element_bit_length attribute which should never happen",
-                                            )),
-                                        };
-                                    }
-                                    Err(err) => {
-                                        return Err(Error::new(
-                                            builder.span(),
-                                            format!("bit_length must be a number that can be parsed as a usize [{}]", err),
-                                        ));
-                                    }
-                                }
-                            } else {
-                                return Err(Error::new(
-                                    builder.span(),
-                                    "bit_length must use a literal usize",
-                                ));
+                            _ => {
+                                return Err(Error::new(builder.span(), "failed paring range"));
                             }
                         }
-                        "element_byte_length" => {
-                            if let Lit::Int(val) = value.lit {
-                                match val.base10_parse::<usize>() {
-                                    Ok(byte_length) => {
-                                        builder.bit_range = match std::mem::take(&mut builder.bit_range) {
-                                            FieldBuilderRange::None => {
-                                                builder.ty = match builder.ty {
-                                                    FieldAttrBuilderType::Struct(_) |
-                                                    FieldAttrBuilderType::Enum(_, _) => {
-                                                        FieldAttrBuilderType::ElementArray(byte_length * 8, Box::new(Some(builder.ty.clone())))
-                                                    }
-                                                    _ => FieldAttrBuilderType::ElementArray(byte_length * 8, Box::new(None)),
-                                                };
-                                                if let Some(last_value) = last_field {
-                                                    FieldBuilderRange::LastEnd(last_value.attrs.bit_range.end)
-                                                }else{
-                                                    FieldBuilderRange::LastEnd(0)
-                                                }
-                                            }
-                                            FieldBuilderRange::Range(range) => {
-                                                builder.ty = match builder.ty {
-                                                    FieldAttrBuilderType::Struct(_) |
-                                                    FieldAttrBuilderType::Enum(_, _) => {
-                                                        FieldAttrBuilderType::ElementArray(byte_length * 8, Box::new(Some(builder.ty.clone())))
-                                                    }
-                                                    _ => FieldAttrBuilderType::ElementArray(byte_length * 8, Box::new(None)),
-                                                };
-                                                FieldBuilderRange::Range(range)
-                                            }
-                                                _ => return Err(Error::new(
-                                                    builder.span(),
-                                                    "found Field bit range no_end while element_byte_length attribute which should never happen",
-                                                )),
-                                        };
-                                    }
-                                    Err(err) => {
-                                        return Err(Error::new(
-                                            builder.span(),
-                                            format!("bit_length must be a number that can be parsed as a usize [{}]", err),
-                                        ));
-                                    }
-                                }
-                            } else {
-                                return Err(Error::new(
-                                    builder.span(),
-                                    "bit_length must use a literal usize",
-                                ));
+                    } else {
+                        return Err(Error::new(
+                            builder.span(),
+                            "bits attribute should have data like \"0..8\"",
+                        ));
+                    }
+                } else {
+                    return Err(Error::new(
+                        item.span(),
+                        "bits must be a range, ex. bits = 0..8 or bits = \"0..8\"",
+                    ));
+                };
+                builder.explicit_bits = true;
+                checks.require(start.less_than(&end), item.span(), || {
+                    format!("bits range {}..{} is empty, the end has to be after the start", start, end)
+                })?;
+                match builder.bit_range {
+                    FieldBuilderRange::Range(ref range) => {
+                        let length = range.end.clone() - range.start.clone();
+                        let bits = end.clone() - start.clone();
+                        checks.require(length.equals(&bits), item.span(), || {
+                            format!(
+                                "bits {}..{} has {} bits but the length given to {} is {} bits",
+                                start, end, bits, builder.name, length
+                            )
+                        })?;
+                        builder.bit_range = FieldBuilderRange::Range(start..end);
+                    }
+                    _ => {
+                        builder.bit_range = FieldBuilderRange::Range(start..end);
+                    }
+                }
+            }
+            "element_bit_length" | "element_byte_length" => {
+                let mut bit_length = item.size_value()?;
+                if ident_as_str == "element_byte_length" {
+                    bit_length = bit_length * 8;
+                }
+                builder.bit_range = match std::mem::take(&mut builder.bit_range) {
+                    FieldBuilderRange::None => {
+                        builder.ty = match builder.ty {
+                            FieldAttrBuilderType::Struct(_) |
+                            FieldAttrBuilderType::Enum(_, _) => {
+                                FieldAttrBuilderType::ElementArray(bit_length, Box::new(Some(builder.ty.clone())))
                             }
-                        }
-                        "block_bit_length" => {
-                            if let Lit::Int(val) = value.lit {
-                                match val.base10_parse::<usize>() {
-                                    Ok(bit_length) => {
-                                        builder.bit_range = match std::mem::take(&mut builder.bit_range) {
-                                            FieldBuilderRange::None => {
-                                                builder.ty = match builder.ty {
-                                                    FieldAttrBuilderType::Struct(_) |
-                                                    FieldAttrBuilderType::Enum(_, _) => {
-                                                        FieldAttrBuilderType::BlockArray(Box::new(Some(builder.ty.clone())))
-                                                    }
-                                                    _ => FieldAttrBuilderType::BlockArray(Box::new(None)),
-                                                };
-                                                if let Some(last_value) = last_field {
-                                                    FieldBuilderRange::Range(last_value.attrs.bit_range.end..last_value.attrs.bit_range.end + (bit_length))
-                                                }else{
-                                                    FieldBuilderRange::Range(0..bit_length)
-                                                }
-                                            }
-                                            FieldBuilderRange::Range(range) => {
-                                                builder.ty = match builder.ty {
-                                                    FieldAttrBuilderType::Struct(_) |
-                                                    FieldAttrBuilderType::Enum(_, _) => {
-                                                        FieldAttrBuilderType::BlockArray(Box::new(Some(builder.ty.clone())))
-                                                    }
-                                                    _ => FieldAttrBuilderType::BlockArray(Box::new(None)),
-                                                };
-                                                if range.end - range.start == bit_length{
-                                                FieldBuilderRange::Range(range)
-                                                }else{
-                                                    return Err(Error::new(
-                                                        builder.span(),
-                                                        "size of bit-range provided by (bits, bit_length, or byte_length) does not match array_bit_length",
-                                                    ));
-                                                }
-                                            }
-                                            _ => return Err(Error::new(
-                                                    builder.span(),
-                                                    "found Field bit range no_end while array_bit_length attribute which should never happen",
-                                                )),
-                                        };
-                                    }
-                                    Err(err) => {
-                                        return Err(Error::new(
-                                            builder.span(),
-                                            format!("array_bit_length must be a number that can be parsed as a usize [{}]", err),
-                                        ));
-                                    }
-                                }
-                            } else {
-                                return Err(Error::new(
-                                    builder.span(),
-                                    "array_bit_length must use a literal usize",
-                                ));
+                            _ => FieldAttrBuilderType::ElementArray(bit_length, Box::new(None)),
+                        };
+                        FieldBuilderRange::LastEnd(Self::last_end(last_field))
+                    }
+                    FieldBuilderRange::Range(range) => {
+                        builder.ty = match builder.ty {
+                            FieldAttrBuilderType::Struct(_) |
+                            FieldAttrBuilderType::Enum(_, _) => {
+                                FieldAttrBuilderType::ElementArray(bit_length, Box::new(Some(builder.ty.clone())))
                             }
-                        }
-                        "block_byte_length" => {
-                            if let Lit::Int(val) = value.lit {
-                                match val.base10_parse::<usize>() {
-                                    Ok(byte_length) => {
-                                        builder.bit_range = match std::mem::take(&mut builder.bit_range) {
-                                            FieldBuilderRange::None => {
-                                                builder.ty = match builder.ty {
-                                                    FieldAttrBuilderType::Struct(_) |
-                                                    FieldAttrBuilderType::Enum(_, _) => {
-                                                        FieldAttrBuilderType::BlockArray(Box::new(Some(builder.ty.clone())))
-                                                    }
-                                                    _ => FieldAttrBuilderType::BlockArray(Box::new(None)),
-                                                };
-                                                if let Some(last_value) = last_field {
-                                                    FieldBuilderRange::Range(last_value.attrs.bit_range.end..last_value.attrs.bit_range.end + (byte_length * 8))
-                                                }else{
-                                                    FieldBuilderRange::Range(0..byte_length*8)
-                                                }
-                                            }
-                                            FieldBuilderRange::Range(range) => {
-                                                builder.ty = match builder.ty {
-                                                    FieldAttrBuilderType::Struct(_) |
-                                                    FieldAttrBuilderType::Enum(_, _) => {
-                                                        FieldAttrBuilderType::BlockArray(Box::new(Some(builder.ty.clone())))
-                                                    }
-                                                    _ => FieldAttrBuilderType::BlockArray(Box::new(None)),
-                                                };
-                                                if range.end - range.start == byte_length * 8{
-                                                FieldBuilderRange::Range(range)
-                                                }else{
-                                                    return Err(Error::new(
-                                                        builder.span(),
-                                                        "size of bit-range provided by (bits, bit_length, or byte_length) does not match array_byte_length",
-                                                    ));
-                                                }
-                                            }
-                                            _ => return Err(Error::new(
-                                                builder.span(),
-                                                "found Field bit range no_end while array_byte_length attribute which should never happen",
-                                            )),
-                                        };
-                                    }
-                                    Err(err) => {
-                                        return Err(Error::new(
-                                            builder.span(),
-                                            format!("array_byte_length must be a number that can be parsed as a usize [{}]", err),
-                                        ));
-                                    }
-                                }
-                            } else {
-                                return Err(Error::new(
-                                    builder.span(),
-                                    "array_byte_length must use a literal usize",
-                                ));
+                            _ => FieldAttrBuilderType::ElementArray(bit_length, Box::new(None)),
+                        };
+                        FieldBuilderRange::Range(range)
+                    }
+                    _ => return Err(Error::new(
+                        builder.span(),
+                        format!("found Field bit range no_end while {} attribute which should never happen", ident_as_str),
+                    )),
+                };
+            }
+            "block_bit_length" | "block_byte_length" => {
+                let mut bit_length = item.size_value()?;
+                if ident_as_str == "block_byte_length" {
+                    bit_length = bit_length * 8;
+                }
+                builder.bit_range = match std::mem::take(&mut builder.bit_range) {
+                    FieldBuilderRange::None => {
+                        builder.ty = match builder.ty {
+                            FieldAttrBuilderType::Struct(_) |
+                            FieldAttrBuilderType::Enum(_, _) => {
+                                FieldAttrBuilderType::BlockArray(Box::new(Some(builder.ty.clone())))
                             }
-                        }
-                        _ => {
-                            if ident_as_str.as_str() != "doc" {
-                                return Err(Error::new(
-                                    builder.span(),
-                                    format!("\"{}\" is not a valid attribute", ident_as_str),
-                                ));
+                            _ => FieldAttrBuilderType::BlockArray(Box::new(None)),
+                        };
+                        let start = Self::last_end(last_field);
+                        FieldBuilderRange::Range(start.clone()..start + bit_length)
+                    }
+                    FieldBuilderRange::Range(range) => {
+                        builder.ty = match builder.ty {
+                            FieldAttrBuilderType::Struct(_) |
+                            FieldAttrBuilderType::Enum(_, _) => {
+                                FieldAttrBuilderType::BlockArray(Box::new(Some(builder.ty.clone())))
                             }
+                            _ => FieldAttrBuilderType::BlockArray(Box::new(None)),
+                        };
+                        let length = range.end.clone() - range.start.clone();
+                        checks.require(length.equals(&bit_length), builder.span(), || {
+                            format!("size of bit-range provided by (bits, bit_length, or byte_length) does not match {}", ident_as_str)
+                        })?;
+                        FieldBuilderRange::Range(range)
+                    }
+                    _ => return Err(Error::new(
+                            builder.span(),
+                            format!("found Field bit range no_end while {} attribute which should never happen", ident_as_str),
+                        )),
+                };
+            }
+            "exponent_bits" => {
+                builder.exponent_bits = Some(item.usize_value()?);
+            }
+            "rounding" => {
+                if let Some(val) = item.lit_str() {
+                    builder.rounding = Some(match val.value().as_str() {
+                        "nearest_even" => FloatRounding::NearestEven,
+                        "toward_zero" => FloatRounding::TowardZero,
+                        _ => {
+                            return Err(Error::new(
+                                builder.span(),
+                                "rounding must be \"nearest_even\" or \"toward_zero\"",
+                            ));
                         }
+                    });
+                } else {
+                    return Err(Error::new(
+                        builder.span(),
+                        "rounding must use a literal str value",
+                    ));
+                }
+            }
+            "scale" | "offset" => {
+                let number = item.f64_value()?;
+                if ident_as_str == "scale" {
+                    if number == 0.0 {
+                        return Err(Error::new(
+                            builder.span(),
+                            "scale can not be 0",
+                        ));
                     }
+                    builder.scale = Some(number);
+                } else {
+                    builder.offset = Some(number);
                 }
             }
-            Meta::Path(path) => {
-                if let Some(ident) = path.get_ident() {
-                    match ident.to_string().as_str() {
-                        "reserve" => {
-                            builder.reserve = true;
-                        }
-                        _ => {}
+            "q" => {
+                if let Some(val) = item.lit_str() {
+                    if let Some(q) = QFormat::parse(&val.value()) {
+                        builder.q = Some(q);
+                    } else {
+                        return Err(Error::new(
+                            builder.span(),
+                            "q must be a fixed point format like \"Q3.12\" or \"UQ8.8\"",
+                        ));
                     }
+                } else {
+                    return Err(Error::new(
+                        builder.span(),
+                        "q must use a literal str value",
+                    ));
                 }
             }
-            Meta::List(meta_list) => {
-                if meta_list.path.is_ident("bondrewd") {
-                    for nested_meta in meta_list.nested {
-                        match nested_meta {
-                            NestedMeta::Meta(meta) => {
-                                Self::parse_meta(meta, last_field, builder)?;
-                            }
-                            NestedMeta::Lit(_) => {}
+            "length_from" => {
+                return Err(Error::new(
+                    builder.span(),
+                    format!("{} can only be used on the last field", ident_as_str),
+                ));
+            }
+            "alias_of" => {
+                if let Some(val) = item.lit_str() {
+                    builder.alias_of = Some(val.parse()?);
+                } else {
+                    return Err(Error::new(
+                        item.span(),
+                        "alias_of must use a literal str, ex. alias_of = \"field\"",
+                    ));
+                }
+            }
+            "const_value" => {
+                if let Some(ref value) = item.value {
+                    builder.const_value = Some(value.to_token_stream());
+                }
+            }
+            "checksum" => {
+                builder.checksum = Some(match item.value {
+                    Some(Expr::Path(ref path)) => path.to_token_stream(),
+                    _ => match item.lit_str().map(|val| val.value()).as_deref() {
+                        Some("crc16_ccitt") => quote! {Crc16Ccitt},
+                        Some("sum8") => quote! {Sum8},
+                        Some("xor8") => quote! {Xor8},
+                        _ => {
+                            return Err(Error::new(
+                                item.span(),
+                                "checksum must be \"crc16_ccitt\", \"sum8\", \"xor8\" or a type implementing BitfieldChecksum, ex. checksum = MyChecksum",
+                            ));
                         }
+                    },
+                });
+            }
+            "over" => {
+                let range = match item.value {
+                    Some(Expr::Range(ref range)) => range.clone(),
+                    Some(Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(ref val), .. })) => {
+                        if let Ok(Expr::Range(range)) = val.parse::<Expr>() {
+                            range
+                        } else {
+                            return Err(Error::new(
+                                val.span(),
+                                "over must be a range of bytes, ex. over = \"0..checksum\"",
+                            ));
+                        }
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            item.span(),
+                            "over must be a range of bytes, ex. over = 0..4 or over = \"0..checksum\"",
+                        ));
                     }
+                };
+                if let syn::RangeLimits::Closed(_) = range.limits {
+                    return Err(Error::new(
+                        range.span(),
+                        "over has to be a half open range, ex. over = 0..4",
+                    ));
                 }
+                builder.over = Some((
+                    ChecksumBound::parse(range.start.as_deref(), ChecksumBound::Byte(0))?,
+                    ChecksumBound::parse(range.end.as_deref(), ChecksumBound::End)?,
+                ));
             }
+            "overlay" => builder.overlay = true,
+            "reserve" => builder.reserve = true,
+            "strict" => builder.strict = true,
+            // the key was checked against FIELD_ATTRS.
+            _ => {}
         }
         Ok(())
     }
@@ -572,20 +589,21 @@ impl FieldAttrBuilder {
 impl TryInto<FieldAttrs> for FieldAttrBuilder {
     type Error = TryFromAttrBuilderError;
     fn try_into(self) -> std::result::Result<FieldAttrs, Self::Error> {
+        let reserve = self.reserve_option();
         if let FieldBuilderRange::Range(bit_range) = self.bit_range {
             Ok(FieldAttrs {
                 endianness: self.endianness,
-                bit_range: bit_range,
-                reserve: if self.reserve {
-                    ReserveFieldOption::ReserveField
-                } else {
-                    ReserveFieldOption::NotReserve
-                },
+                bit_range,
+                reserve,
+                strict: self.strict,
+                const_value: None,
+                checksum: None,
             })
         } else {
             Err(TryFromAttrBuilderError {
                 endianness: self.endianness,
-                reserve: self.reserve,
+                reserve,
+                strict: self.strict,
             })
         }
     }
@@ -0,0 +1,232 @@
+use crate::structs::common::{insert_generic_param, FieldDataType, StructInfo};
+use crate::structs::variants::VariantsInfo;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::Ident;
+
+/// generates `{name}Paths`, a path type for each nested structure field and the implementation of
+/// `BitfieldsPaths` for a structure.
+pub fn create_paths_quotes(info: &StructInfo) -> syn::Result<TokenStream> {
+    let struct_name = &info.name;
+    let struct_type = info.type_quote();
+    let vis = &info.vis;
+    let flip = if info.flip {
+        Some(info.total_bytes() - 1)
+    } else {
+        None
+    };
+    let struct_size = info.total_bytes().const_arg();
+    // nested generic structures need paths of their own.
+    let generics = info.impl_generics(|ty| match ty {
+        FieldDataType::Struct(size, _) => {
+            let size = size.const_arg();
+            Some(quote! {BitfieldsPaths<#size>})
+        }
+        _ => None,
+    });
+    let path = info.unused_ident("P");
+    let mut path_generics = generics.clone();
+    insert_generic_param(&mut path_generics, syn::parse_quote! {#path});
+    let (_, path_ty_generics, _) = path_generics.split_for_impl();
+    let mut bounded_generics = path_generics.clone();
+    bounded_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! {#path: BitfieldsPath<#struct_size>});
+    let (bounded_impl_generics, _, bounded_where) = bounded_generics.split_for_impl();
+    let mut field_fns = quote! {};
+    let mut path_types = quote! {};
+    for field in info.fields.iter() {
+        if field.attrs.reserve.is_fake_field() {
+            continue;
+        }
+        let field_name = &field.ident;
+        if let FieldDataType::Struct(ref size, ref ty) = field.ty {
+            let size = size.const_arg();
+            let path_ident = format_ident!(
+                "{}{}Path",
+                struct_name,
+                to_upper_camel(&field_name.to_string())
+            );
+            let bytes_quote = super::from_bytes::get_value_retrieval_quote(field, &flip)?;
+            let (setter, clear) = super::into_bytes::get_field_quote(field, &flip, false)?;
+            field_fns = quote! {
+                #field_fns
+                pub fn #field_name(self) -> <#ty as BitfieldsPaths<#size>>::Paths<#path_ident #path_ty_generics> {
+                    <#ty as BitfieldsPaths<#size>>::paths_from(#path_ident {
+                        path: self.path,
+                        _marker: core::marker::PhantomData,
+                    })
+                }
+            };
+            let path_type = path_type_quote(&path_ident, vis, &struct_type, &path_generics, &path);
+            path_types = quote! {
+                #path_types
+                #path_type
+                impl #bounded_impl_generics BitfieldsPath<#size> for #path_ident #path_ty_generics #bounded_where {
+                    type Root = #path::Root;
+                    // the nested bytes are copied out of the bytes of the structure holding them,
+                    // which were copied the same way, one copy for each level of the path.
+                    #[inline]
+                    fn read_bytes(&self, root: &#path::Root) -> [u8;#size] {
+                        let input_byte_buffer: [u8;#struct_size] = self.path.read_bytes(root);
+                        #bytes_quote
+                    }
+                    #[inline]
+                    fn write_bytes(&self, root: &mut #path::Root, bytes: [u8;#size]) {
+                        // the field setter expects something with an into_bytes function.
+                        struct FieldBytes([u8;#size]);
+                        impl FieldBytes {
+                            fn into_bytes(self) -> [u8;#size] {
+                                self.0
+                            }
+                        }
+                        let mut output_byte_buffer: [u8;#struct_size] = self.path.read_bytes(root);
+                        let #field_name = FieldBytes(bytes);
+                        #clear
+                        #setter
+                        self.path.write_bytes(root, output_byte_buffer);
+                    }
+                }
+            };
+        } else {
+            let type_quote = field.ty.type_quote();
+            let read_name = format_ident!("read_{}", field_name.as_ref());
+            let write_name = format_ident!("write_{}", field_name.as_ref());
+            field_fns = quote! {
+                #field_fns
+                pub fn #field_name(self) -> FieldPath<#path, #type_quote, #struct_size> {
+                    FieldPath::new(self.path, <#struct_type>::#read_name, <#struct_type>::#write_name)
+                }
+            };
+        }
+    }
+    Ok(paths_quotes(
+        struct_name,
+        &struct_type,
+        vis,
+        struct_size,
+        &generics,
+        &path,
+        field_fns,
+        path_types,
+    ))
+}
+
+/// enums with data can only be read and written as a whole.
+pub fn create_variants_paths_quotes(info: &VariantsInfo) -> TokenStream {
+    let enum_name = &info.name;
+    paths_quotes(
+        enum_name,
+        &quote! {#enum_name},
+        &info.variants[0].info.vis,
+        info.total_bytes().const_arg(),
+        &syn::Generics::default(),
+        &format_ident!("P"),
+        quote! {},
+        quote! {},
+    )
+}
+
+/// `generics` are the generics of the structure's impls, the path types add `path` as their first
+/// type parameter.
+#[allow(clippy::too_many_arguments)]
+pub fn paths_quotes(
+    struct_name: &Ident,
+    struct_type: &TokenStream,
+    vis: &syn::Visibility,
+    struct_size: impl ToTokens,
+    generics: &syn::Generics,
+    path: &Ident,
+    field_fns: TokenStream,
+    path_types: TokenStream,
+) -> TokenStream {
+    let paths_ident = format_ident!("{}Paths", struct_name);
+    let mut path_generics = generics.clone();
+    insert_generic_param(&mut path_generics, syn::parse_quote! {#path});
+    let (_, path_ty_generics, _) = path_generics.split_for_impl();
+    let mut bounded_generics = path_generics.clone();
+    bounded_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! {#path: BitfieldsPath<#struct_size>});
+    let (bounded_impl_generics, _, bounded_where) = bounded_generics.split_for_impl();
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let paths_type = path_type_quote(&paths_ident, vis, struct_type, &path_generics, path);
+    quote! {
+        #paths_type
+        impl #bounded_impl_generics #paths_ident #path_ty_generics #bounded_where {
+            #[inline]
+            pub fn read(&self, root: &#path::Root) -> #struct_type {
+                <#struct_type>::from_bytes(self.path.read_bytes(root))
+            }
+            #[inline]
+            pub fn write(&self, root: &mut #path::Root, value: #struct_type) {
+                self.path.write_bytes(root, value.into_bytes());
+            }
+            #field_fns
+        }
+        #path_types
+        impl #impl_generics BitfieldsPaths<#struct_size> for #struct_type #where_clause {
+            type Paths<#path: BitfieldsPath<#struct_size>> = #paths_ident #path_ty_generics;
+            fn paths_from<#path: BitfieldsPath<#struct_size>>(path: #path) -> Self::Paths<#path> {
+                #paths_ident {
+                    path,
+                    _marker: core::marker::PhantomData,
+                }
+            }
+        }
+    }
+}
+
+/// a path type only holds the path, Clone and Copy are implemented by hand so the generic
+/// parameters of the structure do not need to be Copy.
+fn path_type_quote(
+    ident: &Ident,
+    vis: &syn::Visibility,
+    struct_type: &TokenStream,
+    generics: &syn::Generics,
+    path: &Ident,
+) -> TokenStream {
+    let (decl_generics, ty_generics, decl_where) = generics.split_for_impl();
+    let mut clone_generics = generics.clone();
+    clone_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! {#path: Clone});
+    let (clone_impl_generics, _, clone_where) = clone_generics.split_for_impl();
+    let mut copy_generics = generics.clone();
+    copy_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! {#path: Copy});
+    let (copy_impl_generics, _, copy_where) = copy_generics.split_for_impl();
+    quote! {
+        #vis struct #ident #decl_generics #decl_where {
+            path: #path,
+            _marker: core::marker::PhantomData<fn() -> #struct_type>,
+        }
+        impl #clone_impl_generics Clone for #ident #ty_generics #clone_where {
+            fn clone(&self) -> Self {
+                Self {
+                    path: self.path.clone(),
+                    _marker: core::marker::PhantomData,
+                }
+            }
+        }
+        impl #copy_impl_generics Copy for #ident #ty_generics #copy_where {}
+    }
+}
+
+fn to_upper_camel(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -1,4 +1,4 @@
-use crate::structs::common::{FieldDataType, FieldInfo, NumberSignage, StructInfo};
+use crate::structs::common::{Condition, FieldDataType, FieldInfo, NumberSignage, Size, StructInfo};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
@@ -6,7 +6,10 @@ pub fn create_into_bytes_field_quotes(info: &StructInfo) -> Result<TokenStream,
     // all of the fields set functions that disallow numbers that are too large to fit into bit length.
     let mut set_fns_quote = quote! {};
     for field in info.fields.iter() {
-        let q = make_set_field_quote(&field)?;
+        if field.attrs.const_value.is_some() || field.attrs.checksum.is_some() {
+            continue;
+        }
+        let q = make_set_field_quote(field)?;
         set_fns_quote = quote! {
             #set_fns_quote
             #q
@@ -23,8 +26,39 @@ fn make_set_field_quote(field: &FieldInfo) -> Result<TokenStream, syn::Error> {
                 self.#field_name = value.clone();
                 value
             };
-            let bit_length = field.bit_size();
-            if bit_length != size * 8 {
+            let bits = field.bit_size();
+            if let Size::Const(_) = bits {
+                // the compiler works out the limits, a length using the whole type has none.
+                let type_bits = size * 8;
+                let limits = match sign {
+                    NumberSignage::Signed => quote! {
+                        if #bits < #type_bits {
+                            ((-(1i128 << (#bits - 1))) as #type_ident, ((1i128 << (#bits - 1)) - 1) as #type_ident)
+                        } else {
+                            (#type_ident::MIN, #type_ident::MAX)
+                        }
+                    },
+                    NumberSignage::Unsigned => quote! {
+                        if #bits < #type_bits {
+                            (0, ((1u128 << #bits) - 1) as #type_ident)
+                        } else {
+                            (#type_ident::MIN, #type_ident::MAX)
+                        }
+                    },
+                };
+                full_quote = quote! {
+                    let (min, max): (#type_ident, #type_ident) = #limits;
+                    if value > max {
+                        self.#field_name = max;
+                        max
+                    } else if value < min {
+                        self.#field_name = min;
+                        min
+                    } else {
+                        #full_quote
+                    }
+                };
+            } else if let Some(bit_length) = bits.literal().filter(|bits| *bits != size * 8) {
                 match sign {
                     NumberSignage::Signed => {
                         let max: i128 = ((2_u128.pow(bit_length as u32) / 2_u128) - 1) as i128;
@@ -64,13 +98,12 @@ fn make_set_field_quote(field: &FieldInfo) -> Result<TokenStream, syn::Error> {
                 }
             }
         }
-        FieldDataType::Float(ref size, ref type_ident) => {
+        FieldDataType::Float(ref size, ref type_ident, _) => {
             let mut full_quote = quote! {
                 self.#field_name = value;
                 value
             };
-            let bit_length = field.bit_size();
-            if bit_length != *size * 8_usize {
+            if !matches!(field.bit_size().equals(&Size::Literal(*size * 8)), Condition::Literal(true)) {
                 let max: f64 = if *size == 4_usize {
                     f32::MAX as f64
                 } else if *size == 8_usize {
@@ -133,8 +166,18 @@ fn make_set_field_quote(field: &FieldInfo) -> Result<TokenStream, syn::Error> {
                 self.#field_name = value.clone();
                 value
             };
-            let bit_length = field.bit_size();
-            if bit_length != size * 8 {
+            let bits = field.bit_size();
+            if let Size::Const(_) = bits {
+                full_quote = quote! {
+                    let max: u32 = if #bits < 32 { (1u32 << #bits) - 1 } else { u32::MAX };
+                    if value as u32 > max {
+                        self.#field_name = max;
+                        max
+                    }else {
+                        #full_quote
+                    }
+                };
+            } else if let Some(bit_length) = bits.literal().filter(|bits| *bits != size * 8) {
                 let max: u32 = 2_u32.pow(bit_length as u32) - 1;
                 full_quote = quote! {
                     if value as u32 > #max {
@@ -0,0 +1,496 @@
+use crate::attrs::{bondrewd_attr_items, VARIANTS_ATTRS, VARIANT_ATTRS};
+use crate::enums::parse::EnumInfo;
+use crate::structs::common::{
+    Condition, Endianness, FieldAttrs, FieldDataType, FieldInfo, NumberSignage,
+    ReserveFieldOption, Size, SizeChecks, StructEnforcement, StructInfo,
+};
+use crate::structs::{from_bytes, into_bytes};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::Error;
+use syn::{DeriveInput, Ident};
+
+pub struct VariantInfo {
+    pub name: Ident,
+    pub id: u128,
+    /// the layout of the variant as if it was a struct. the first field is always the variant id
+    /// and the last is a fake reserve field padding the variant to the size of the largest one.
+    pub info: StructInfo,
+}
+
+impl VariantInfo {
+    /// the fields of the variant without the variant id.
+    pub fn fields(&self) -> &[FieldInfo] {
+        &self.info.fields[1..]
+    }
+}
+
+/// Enums with a variant id in the first `id_bit_length` bits, followed by the fields of the
+/// variant.
+pub struct VariantsInfo {
+    pub name: Ident,
+    pub id_field: FieldInfo,
+    pub variants: Vec<VariantInfo>,
+    /// index of the variant used for ids that do not match a variant.
+    pub catch: usize,
+    /// false when the catch is the last variant because no variant was marked invalid.
+    pub explicit_catch: bool,
+    pub flip: bool,
+    pub serde_bytes: bool,
+    /// the checks on sizes from constants of every variant.
+    pub size_checks: SizeChecks,
+}
+
+impl VariantsInfo {
+    pub fn total_bits(&self) -> Size {
+        self.variants[0].info.total_bits()
+    }
+
+    pub fn total_bytes(&self) -> Size {
+        self.variants[0].info.total_bytes()
+    }
+
+    /// the size constants of the enum, every variant has the same size so the first one has them.
+    pub fn size_consts_quote(&self) -> TokenStream {
+        let consts = self.variants[0].info.size_consts_quote();
+        let checks = self.size_checks.quote();
+        quote! {
+            #consts
+            #checks
+        }
+    }
+
+    /// the other enum attributes are parsed by `StructInfo::from_attrs`.
+    fn parse_id_bit_length(input: &DeriveInput) -> syn::Result<Option<usize>> {
+        let mut id_bit_length = None;
+        for item in bondrewd_attr_items(&input.attrs, "bondrewd", &VARIANTS_ATTRS)? {
+            if item.key == "id_bit_length" {
+                id_bit_length = Some(item.usize_value()?);
+            }
+        }
+        Ok(id_bit_length)
+    }
+
+    /// returns true if the variant has a `#[bondrewd(invalid)]` attribute.
+    fn parse_variant_attrs(var: &syn::Variant) -> syn::Result<bool> {
+        // invalid is the only variant attribute.
+        Ok(!bondrewd_attr_items(&var.attrs, "bondrewd", &VARIANT_ATTRS)?.is_empty())
+    }
+
+    pub fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let data = match input.data {
+            syn::Data::Enum(ref data) => data,
+            _ => {
+                return Err(Error::new(Span::call_site(), "input must be an enum"));
+            }
+        };
+        if data.variants.is_empty() {
+            return Err(Error::new(
+                input.ident.span(),
+                "enums deriving Bitfields need at least one variant",
+            ));
+        }
+        if !input.generics.params.is_empty() {
+            return Err(Error::new(
+                input.ident.span(),
+                "enums deriving Bitfields can not be generic",
+            ));
+        }
+        let id_bit_length = match Self::parse_id_bit_length(input)? {
+            Some(length) if (1..=128).contains(&length) => length,
+            Some(_) => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "id_bit_length must be between 1 and 128",
+                ))
+            }
+            None => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "enums deriving Bitfields need an id_bit_length attribute, ex. #[bondrewd(id_bit_length = 2)]",
+                ))
+            }
+        };
+        let enum_attrs = StructInfo::from_attrs(input)?;
+        if enum_attrs.runtime_endianness {
+            return Err(Error::new(
+                input.ident.span(),
+                "runtime_endianness is only supported on structures",
+            ));
+        }
+
+        // the id is read like an unsigned number field at the start of every variant.
+        let id_bytes = match id_bit_length {
+            1..=8 => 1,
+            9..=16 => 2,
+            17..=32 => 4,
+            33..=64 => 8,
+            _ => 16,
+        };
+        let id_ty = format_ident!("u{}", id_bytes * 8);
+        let id_endianness = if enum_attrs.default_endianess.has_endianness() {
+            enum_attrs.default_endianess.clone()
+        } else if id_bytes == 1 {
+            Endianness::Big
+        } else {
+            return Err(Error::new(
+                input.ident.span(),
+                "id_bit_length over 8 bits needs a default_endianness",
+            ));
+        };
+        let id_ident = format_ident!("bondrewd_variant_id");
+        let mut id_field = FieldInfo {
+            name: id_ident.clone(),
+            ident: Box::new(id_ident),
+            ty: FieldDataType::Number(id_bytes, NumberSignage::Unsigned, quote! {#id_ty}),
+            attrs: FieldAttrs {
+                endianness: Box::new(id_endianness),
+                bit_range: Size::Literal(0)..Size::Literal(id_bit_length),
+                reserve: ReserveFieldOption::NotReserve,
+                strict: false,
+                const_value: None,
+                checksum: None,
+            },
+        };
+
+        // assign ids like BitfieldEnum does, literal discriminants keep their value and the rest
+        // take the lowest values that are not taken, in order.
+        let mut literal_ids: Vec<Option<u128>> = Vec::default();
+        let mut taken: std::collections::BTreeSet<u128> = Default::default();
+        for var in data.variants.iter() {
+            if let Some((_, ref discriminant)) = var.discriminant {
+                let value = EnumInfo::parse_lit_discriminant_expr(discriminant)?;
+                if value < 0 {
+                    return Err(Error::new(
+                        var.ident.span(),
+                        "variant ids can not be negative",
+                    ));
+                }
+                if !taken.insert(value as u128) {
+                    return Err(Error::new(var.ident.span(), "Literal Values conflict"));
+                }
+                literal_ids.push(Some(value as u128));
+            } else {
+                literal_ids.push(None);
+            }
+        }
+        let max_id = if id_bit_length == 128 {
+            u128::MAX
+        } else {
+            (1u128 << id_bit_length) - 1
+        };
+        let mut next_id = 0u128;
+        let mut checks = SizeChecks::default();
+        let mut catch: Option<usize> = None;
+        let mut variants: Vec<VariantInfo> = Vec::default();
+        for (i, (var, literal_id)) in data.variants.iter().zip(literal_ids).enumerate() {
+            let id = if let Some(id) = literal_id {
+                id
+            } else {
+                while taken.contains(&next_id) {
+                    next_id += 1;
+                }
+                taken.insert(next_id);
+                next_id
+            };
+            if id > max_id {
+                return Err(Error::new(
+                    var.ident.span(),
+                    format!("variant id {} does not fit in {} bits", id, id_bit_length),
+                ));
+            }
+            if Self::parse_variant_attrs(var)? {
+                if let Some(other) = catch {
+                    return Err(Error::new(
+                        var.ident.span(),
+                        format!(
+                            "conflicting Invalid Variant named [{}]",
+                            data.variants[other].ident
+                        ),
+                    ));
+                }
+                catch = Some(i);
+            }
+            // every variant is laid out as a struct starting with the id so fields can be
+            // positioned by the same rules.
+            let mut info = StructInfo::from_attrs(input)?;
+            info.enforcement = StructEnforcement::NoRules;
+            info.fill_bits = None;
+            info.fields.push(id_field.clone());
+            match var.fields {
+                syn::Fields::Named(ref named) => {
+                    for field in named.named.iter() {
+                        let parsed_field = FieldInfo::from_syn_field(field, &info, &mut checks)?;
+                        if parsed_field.attrs.reserve.is_overlay() {
+                            return Err(Error::new(
+                                parsed_field.ident.span(),
+                                "overlay fields can only be used in structures",
+                            ));
+                        }
+                        if parsed_field.attrs.const_value.is_some() {
+                            return Err(Error::new(
+                                parsed_field.ident.span(),
+                                "const_value fields can only be used in structures",
+                            ));
+                        }
+                        if parsed_field.attrs.checksum.is_some() {
+                            return Err(Error::new(
+                                parsed_field.ident.span(),
+                                "checksum fields can only be used in structures",
+                            ));
+                        }
+                        info.fields.push(parsed_field);
+                    }
+                }
+                syn::Fields::Unit => {}
+                syn::Fields::Unnamed(_) => {
+                    return Err(Error::new(
+                        var.ident.span(),
+                        "variants must have named fields or no fields",
+                    ))
+                }
+            }
+            info.reserve_gaps(&mut checks)?;
+            variants.push(VariantInfo {
+                name: var.ident.clone(),
+                id,
+                info,
+            });
+        }
+        let (catch, explicit_catch) = if let Some(catch) = catch {
+            (catch, true)
+        } else {
+            (variants.len() - 1, false)
+        };
+
+        // the enum is as large as its largest variant, enforcement is checked against that.
+        let bit_size = variants
+            .iter()
+            .map(|var| var.info.used_bits())
+            .reduce(|largest, bits| largest.max(&bits))
+            .unwrap_or(Size::Literal(id_bit_length));
+        enum_attrs.check_enforcement(&bit_size, &mut checks)?;
+        let bits = if let Some(ref fill_bits) = enum_attrs.fill_bits {
+            bit_size.max(fill_bits)
+        } else {
+            bit_size
+        };
+        for var in variants.iter_mut() {
+            let end = var.info.used_bits();
+            // a variant with a size from constants may be the largest, so its fill can be empty.
+            if !matches!(end.less_than(&bits), Condition::Literal(false)) {
+                var.info.fields.push(StructInfo::fill_field(end, bits.clone()));
+            }
+            var.info.bits = bits.clone();
+        }
+        // the size of the enum, or the constant holding it.
+        let total_bits = variants[0].info.total_bits();
+        if enum_attrs.lsb_zero {
+            for var in variants.iter_mut() {
+                for field in var.info.fields.iter_mut() {
+                    field.attrs.bit_range = (total_bits.clone() - field.attrs.bit_range.end.clone())
+                        ..(total_bits.clone() - field.attrs.bit_range.start.clone());
+                }
+            }
+            id_field.attrs.bit_range = (total_bits.clone() - id_field.attrs.bit_range.end.clone())
+                ..(total_bits - id_field.attrs.bit_range.start.clone());
+        }
+
+        Ok(Self {
+            name: input.ident.clone(),
+            id_field,
+            variants,
+            catch,
+            explicit_catch,
+            flip: enum_attrs.flip,
+            serde_bytes: enum_attrs.serde_bytes,
+            size_checks: checks,
+        })
+    }
+}
+
+pub struct VariantsQuotes {
+    pub into_bytes_fn: TokenStream,
+    pub from_bytes_fn: TokenStream,
+    pub read_variant_id_fn: TokenStream,
+    pub try_from_bytes_fn: Option<TokenStream>,
+}
+
+pub fn create_variants_quotes(info: &VariantsInfo, try_from: bool) -> syn::Result<VariantsQuotes> {
+    let struct_size = info.total_bytes();
+    let flip = if info.flip {
+        Some(struct_size.clone() - 1)
+    } else {
+        None
+    };
+    let id_ty = info.id_field.ty.type_quote();
+    let (id_setter, _) = into_bytes::get_field_quote(&info.id_field, &flip, false)?;
+    let id_getter = from_bytes::get_field_quote(&info.id_field, &flip, false)?;
+
+    let mut into_arms = quote! {};
+    let mut from_arms = quote! {};
+    let mut try_arms = quote! {};
+    let mut from_catch_arm = quote! {};
+    let mut try_catch_arm = quote! {};
+    for (i, var) in info.variants.iter().enumerate() {
+        let name = &var.name;
+        let id = Literal::u128_unsuffixed(var.id);
+        let mut bindings = quote! {};
+        let mut setters = quote! {};
+        let mut lets = quote! {};
+        let mut try_lets = quote! {};
+        let mut field_names = quote! {};
+        for field in var.fields() {
+            if try_from
+                && (field.attrs.reserve.is_reserve_field() || field.attrs.reserve.is_fake_field())
+            {
+                let reserve_check = from_bytes::get_reserve_check_quote(field, &flip);
+                try_lets = quote! {
+                    #try_lets
+                    #reserve_check
+                };
+            }
+            if field.attrs.reserve.is_fake_field() {
+                continue;
+            }
+            let field_name = &field.ident;
+            field_names = quote! {
+                #field_names
+                #field_name,
+            };
+            if field.attrs.reserve.is_reserve_field() {
+                let type_quote = field.ty.type_quote();
+                lets = quote! {
+                    #lets
+                    let #field_name = 0 as #type_quote;
+                };
+                try_lets = quote! {
+                    #try_lets
+                    let #field_name = 0 as #type_quote;
+                };
+                continue;
+            }
+            let (field_setter, _) = into_bytes::get_field_quote(field, &flip, false)?;
+            bindings = quote! {
+                #bindings
+                #field_name,
+            };
+            setters = quote! {
+                #setters
+                #field_setter
+            };
+            let field_getter = from_bytes::get_field_quote(field, &flip, false)?;
+            lets = quote! {
+                #lets
+                let #field_name = {#field_getter};
+            };
+            if try_from {
+                let checked_getter = from_bytes::get_field_quote(field, &flip, true)?;
+                try_lets = quote! {
+                    #try_lets
+                    let #field_name = {#checked_getter};
+                };
+            }
+        }
+        let construct = quote! {Self::#name { #field_names }};
+        into_arms = quote! {
+            #into_arms
+            Self::#name { #bindings .. } => {
+                let bondrewd_variant_id: #id_ty = #id;
+                #id_setter
+                #setters
+            }
+        };
+        let from_body = quote! {
+            #lets
+            #construct
+        };
+        let try_body = quote! {
+            #try_lets
+            Ok(#construct)
+        };
+        if i == info.catch {
+            from_catch_arm = quote! {
+                _ => {
+                    #from_body
+                }
+            };
+            try_catch_arm = if info.explicit_catch {
+                quote! {
+                    _ => {
+                        #try_body
+                    }
+                }
+            } else {
+                let mut id_field = info.id_field.clone();
+                *id_field.ident = format_ident!("variant_id");
+                let error = from_bytes::get_decode_error_quote(&id_field, quote! {InvalidEnum});
+                quote! {
+                    #id => {
+                        #try_body
+                    }
+                    _ => {
+                        #error
+                    }
+                }
+            };
+        } else {
+            from_arms = quote! {
+                #from_arms
+                #id => {
+                    #from_body
+                }
+            };
+            try_arms = quote! {
+                #try_arms
+                #id => {
+                    #try_body
+                }
+            };
+        }
+    }
+    let into_bytes_fn = quote! {
+        fn into_bytes(self) -> [u8;#struct_size] {
+            let mut output_byte_buffer: [u8;#struct_size] = [0u8;#struct_size];
+            match self {
+                #into_arms
+            }
+            output_byte_buffer
+        }
+    };
+    let from_bytes_fn = quote! {
+        fn from_bytes(input_byte_buffer: [u8;#struct_size]) -> Self {
+            let bondrewd_variant_id: #id_ty = {#id_getter};
+            match bondrewd_variant_id {
+                #from_arms
+                #from_catch_arm
+            }
+        }
+    };
+    let read_variant_id_fn = quote! {
+        #[inline]
+        pub fn read_variant_id(input_byte_buffer: &[u8;#struct_size]) -> #id_ty {
+            #id_getter
+        }
+    };
+    let try_from_bytes_fn = if try_from {
+        Some(quote! {
+            #[allow(unreachable_patterns)]
+            fn try_from_bytes(input_byte_buffer: [u8;#struct_size]) -> Result<Self, BitfieldDecodeError> {
+                let bondrewd_variant_id: #id_ty = {#id_getter};
+                match bondrewd_variant_id {
+                    #try_arms
+                    #try_catch_arm
+                }
+            }
+        })
+    } else {
+        None
+    };
+    Ok(VariantsQuotes {
+        into_bytes_fn,
+        from_bytes_fn,
+        read_variant_id_fn,
+        try_from_bytes_fn,
+    })
+}
@@ -0,0 +1,227 @@
+use crate::structs::common::{insert_generic_param, FieldDataType, FieldInfo, StructInfo};
+use crate::structs::variants::VariantsInfo;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::Ident;
+
+/// generates `{name}View` and `{name}ViewMut` with the implementation of `BitfieldsView` for
+/// a structure.
+pub fn create_view_quotes(info: &StructInfo) -> syn::Result<TokenStream> {
+    let struct_type = info.type_quote();
+    let mut read_fns = quote! {};
+    let mut write_fns = quote! {};
+    for field in info.fields.iter() {
+        if field.attrs.reserve.is_fake_field() {
+            continue;
+        }
+        let read_name = format_ident!("read_{}", field.ident.as_ref());
+        let write_name = format_ident!("write_{}", field.ident.as_ref());
+        let field_name = &field.ident;
+        if let Some((start, size, ty)) = nested_view_range(field, info) {
+            let end = start + size;
+            let read_mut_name = format_ident!("read_{}_mut", field.ident.as_ref());
+            read_fns = quote! {
+                #read_fns
+                #[inline]
+                pub fn #read_name(&self) -> <#ty as BitfieldsView<#size>>::View<&[u8;#size]> {
+                    let bytes: &[u8;#size] = self.as_bytes()[#start..#end].try_into().unwrap();
+                    <#ty as BitfieldsView<#size>>::view(bytes)
+                }
+            };
+            write_fns = quote! {
+                #write_fns
+                #[inline]
+                pub fn #read_mut_name(&mut self) -> <#ty as BitfieldsView<#size>>::ViewMut<&mut [u8;#size]> {
+                    let bytes: &mut [u8;#size] = (&mut self.as_bytes_mut()[#start..#end]).try_into().unwrap();
+                    <#ty as BitfieldsView<#size>>::view_mut(bytes)
+                }
+            };
+        } else {
+            let type_quote = field.ty.type_quote();
+            read_fns = quote! {
+                #read_fns
+                #[inline]
+                pub fn #read_name(&self) -> #type_quote {
+                    <#struct_type>::#read_name(self.as_bytes())
+                }
+            };
+        }
+        let type_quote = field.ty.type_quote();
+        write_fns = quote! {
+            #write_fns
+            #[inline]
+            pub fn #write_name(&mut self, #field_name: #type_quote) {
+                <#struct_type>::#write_name(self.as_bytes_mut(), #field_name)
+            }
+        };
+    }
+    // nested generic structures need views of their own.
+    let generics = info.impl_generics(|ty| match ty {
+        FieldDataType::Struct(size, _) => Some(quote! {BitfieldsView<#size>}),
+        _ => None,
+    });
+    Ok(view_quotes(
+        &info.name,
+        &struct_type,
+        &info.vis,
+        info.total_bytes(),
+        &generics,
+        &info.unused_ident("B"),
+        read_fns,
+        write_fns,
+    ))
+}
+
+/// enums with data only get the variant id, fields can only be reached through `to_owned`.
+pub fn create_variants_view_quotes(info: &VariantsInfo) -> TokenStream {
+    let enum_name = &info.name;
+    let id_ty = info.id_field.ty.type_quote();
+    let read_fns = quote! {
+        #[inline]
+        pub fn read_variant_id(&self) -> #id_ty {
+            #enum_name::read_variant_id(self.as_bytes())
+        }
+    };
+    view_quotes(
+        enum_name,
+        &quote! {#enum_name},
+        &info.variants[0].info.vis,
+        info.total_bytes(),
+        &syn::Generics::default(),
+        // the view's buffer parameter can not shadow the enum.
+        &if enum_name == "B" {
+            format_ident!("B_")
+        } else {
+            format_ident!("B")
+        },
+        read_fns,
+        quote! {},
+    )
+}
+
+/// the views share everything but the field functions between structures and enums. `generics`
+/// are the generics of the structure's impls, the views add `buffer` as their first type parameter.
+#[allow(clippy::too_many_arguments)]
+pub fn view_quotes(
+    struct_name: &Ident,
+    struct_type: &TokenStream,
+    vis: &syn::Visibility,
+    struct_size: impl ToTokens,
+    generics: &syn::Generics,
+    buffer: &Ident,
+    read_fns: TokenStream,
+    write_fns: TokenStream,
+) -> TokenStream {
+    let view_ident = format_ident!("{}View", struct_name);
+    let view_mut_ident = format_ident!("{}ViewMut", struct_name);
+    let mut view_generics = generics.clone();
+    insert_generic_param(&mut view_generics, syn::parse_quote! {#buffer});
+    let (view_decl_generics, view_ty_generics, view_decl_where) = view_generics.split_for_impl();
+    let mut ref_generics = view_generics.clone();
+    ref_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! {#buffer: AsRef<[u8]>});
+    let (ref_impl_generics, _, ref_where) = ref_generics.split_for_impl();
+    let mut mut_generics = view_generics.clone();
+    mut_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! {#buffer: AsRef<[u8]> + AsMut<[u8]>});
+    let (mut_impl_generics, _, mut_where) = mut_generics.split_for_impl();
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    // the length of the buffer is checked when a view is made, so the conversions to arrays
+    // can not fail.
+    quote! {
+        #vis struct #view_ident #view_decl_generics #view_decl_where {
+            buffer: #buffer,
+            _marker: core::marker::PhantomData<fn() -> #struct_type>,
+        }
+        impl #ref_impl_generics #view_ident #view_ty_generics #ref_where {
+            #[inline]
+            pub fn as_bytes(&self) -> &[u8;#struct_size] {
+                self.buffer.as_ref()[..#struct_size].try_into().unwrap()
+            }
+            pub fn into_inner(self) -> #buffer {
+                self.buffer
+            }
+            pub fn to_owned(&self) -> #struct_type {
+                <#struct_type>::from_bytes(*self.as_bytes())
+            }
+            #read_fns
+        }
+        #vis struct #view_mut_ident #view_decl_generics #view_decl_where {
+            buffer: #buffer,
+            _marker: core::marker::PhantomData<fn() -> #struct_type>,
+        }
+        impl #mut_impl_generics #view_mut_ident #view_ty_generics #mut_where {
+            #[inline]
+            pub fn as_bytes(&self) -> &[u8;#struct_size] {
+                self.buffer.as_ref()[..#struct_size].try_into().unwrap()
+            }
+            #[inline]
+            pub fn as_bytes_mut(&mut self) -> &mut [u8;#struct_size] {
+                (&mut self.buffer.as_mut()[..#struct_size]).try_into().unwrap()
+            }
+            pub fn into_inner(self) -> #buffer {
+                self.buffer
+            }
+            pub fn to_owned(&self) -> #struct_type {
+                <#struct_type>::from_bytes(*self.as_bytes())
+            }
+            /// overwrites every field with the fields of `value`.
+            pub fn set(&mut self, value: #struct_type) {
+                *self.as_bytes_mut() = value.into_bytes();
+            }
+            #read_fns
+            #write_fns
+        }
+        impl #impl_generics BitfieldsView<#struct_size> for #struct_type #where_clause {
+            type View<#buffer: AsRef<[u8]>> = #view_ident #view_ty_generics;
+            type ViewMut<#buffer: AsRef<[u8]> + AsMut<[u8]>> = #view_mut_ident #view_ty_generics;
+            fn view(input_byte_buffer: &[u8;#struct_size]) -> Self::View<&[u8;#struct_size]> {
+                #view_ident {
+                    buffer: input_byte_buffer,
+                    _marker: core::marker::PhantomData,
+                }
+            }
+            fn view_mut(output_byte_buffer: &mut [u8;#struct_size]) -> Self::ViewMut<&mut [u8;#struct_size]> {
+                #view_mut_ident {
+                    buffer: output_byte_buffer,
+                    _marker: core::marker::PhantomData,
+                }
+            }
+            fn view_slice<#buffer: AsRef<[u8]>>(buffer: #buffer) -> Result<Self::View<#buffer>, BitfieldSliceError> {
+                let buf_len = buffer.as_ref().len();
+                if buf_len >= #struct_size {
+                    Ok(#view_ident { buffer, _marker: core::marker::PhantomData })
+                } else {
+                    Err(BitfieldSliceError(buf_len, #struct_size))
+                }
+            }
+            fn view_slice_mut<#buffer: AsRef<[u8]> + AsMut<[u8]>>(buffer: #buffer) -> Result<Self::ViewMut<#buffer>, BitfieldSliceError> {
+                let buf_len = buffer.as_ref().len();
+                if buf_len >= #struct_size {
+                    Ok(#view_mut_ident { buffer, _marker: core::marker::PhantomData })
+                } else {
+                    Err(BitfieldSliceError(buf_len, #struct_size))
+                }
+            }
+        }
+    }
+}
+
+/// nested structures get a view of their own bytes when those bytes are stored as they are, which
+/// means the field uses every bit of the structure, starts on a byte and nothing is reversed.
+/// returns the first byte, the byte size and the type.
+fn nested_view_range(field: &FieldInfo, info: &StructInfo) -> Option<(usize, usize, TokenStream)> {
+    if let FieldDataType::Struct(ref size, ref ty) = field.ty {
+        // positions from constants aren't known here, so those fields don't get a view.
+        let size = size.literal()?;
+        let range = field.literal_range().ok()?;
+        if !info.flip && range.start.is_multiple_of(8) && range.end - range.start == size * 8 {
+            return Some((range.start / 8, size, ty.clone()));
+        }
+    }
+    None
+}
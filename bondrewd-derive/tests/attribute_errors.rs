@@ -0,0 +1,7 @@
+// the messages of attribute errors are part of what the derive offers, so they are checked here
+// instead of only checking that the code fails to compile.
+#[test]
+fn attribute_errors() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -26,7 +26,7 @@ fn be_into_bytes_simple() -> anyhow::Result<()> {
     assert_eq!(bytes[0], 0b010_00000);
     assert_eq!(bytes[1], 0b00000000);
     assert_eq!(bytes[2], 0b01100011);
-    assert_eq!(bytes[3], 0b001001_00);
+    assert_eq!(bytes[3], 0b0010_0100);
     assert_eq!(bytes[4], 0b10000110);
     assert_eq!(bytes[5], 0b0001_0100);
     // this last 4 bits here don't exist in the struct
@@ -59,7 +59,7 @@ struct SimpleWithFlip {
 fn be_into_bytes_simple_with_reverse() -> anyhow::Result<()> {
     let simple = SimpleWithFlip {
         one: false,
-        two: u16::MAX & 0b0000001111111111,
+        two: 0b0000001111111111,
         three: 0,
     };
     assert_eq!(SimpleWithFlip::BYTE_SIZE, 2);
@@ -95,7 +95,7 @@ struct SimpleWithReadFromBack {
 fn be_into_bytes_simple_with_read_from_back() -> anyhow::Result<()> {
     let simple = SimpleWithReadFromBack {
         one: false,
-        two: u16::MAX & 0b0000001111111111,
+        two: 0b0000001111111111,
         three: 0,
     };
     assert_eq!(SimpleWithReadFromBack::BYTE_SIZE, 2);
@@ -172,3 +172,75 @@ fn be_into_bytes_simple_with_reserve_field() -> anyhow::Result<()> {
     assert_eq!(simple, new_simple);
     Ok(())
 }
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct SmallSigned {
+    #[bondrewd(bit_length = 2)]
+    one: u8,
+    #[bondrewd(bit_length = 4)]
+    two: i8,
+    #[bondrewd(bit_length = 2)]
+    three: i8,
+}
+
+#[test]
+fn be_small_signed_in_middle_of_byte() {
+    let simple = SmallSigned {
+        one: 1,
+        two: -3,
+        three: -1,
+    };
+    let bytes = simple.clone().into_bytes();
+    assert_eq!(bytes, [0b0111_0111]);
+    assert_eq!(SmallSigned::read_two(&bytes), -3);
+    assert_eq!(SmallSigned::from_bytes(bytes), simple);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Aligned {
+    #[bondrewd(bit_length = 4)]
+    one: u8,
+    #[bondrewd(bit_length = 12)]
+    two: u16,
+    three: u32,
+}
+
+#[test]
+fn be_write_replaces_old_value() {
+    let mut bytes = Aligned {
+        one: 1,
+        two: 2,
+        three: 0x0102_0304,
+    }
+    .into_bytes();
+    Aligned::write_three(&mut bytes, 7);
+    assert_eq!(Aligned::read_three(&bytes), 7);
+    Aligned::write_two(&mut bytes, 0xFFF);
+    Aligned::write_two(&mut bytes, 0x123);
+    assert_eq!(Aligned::read_two(&bytes), 0x123);
+    assert_eq!(Aligned::read_one(&bytes), 1);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", reverse)]
+struct ReverseSigned {
+    one: u16,
+    #[bondrewd(bit_length = 4)]
+    two: u8,
+    #[bondrewd(bit_length = 12)]
+    three: i16,
+}
+
+#[test]
+fn be_reverse_signed() {
+    let simple = ReverseSigned {
+        one: 0x1234,
+        two: 5,
+        three: -3,
+    };
+    let bytes = simple.clone().into_bytes();
+    assert_eq!(ReverseSigned::read_three(&bytes), -3);
+    assert_eq!(ReverseSigned::from_bytes(bytes), simple);
+}
@@ -0,0 +1,153 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", enforce_bytes = 2)]
+struct OutOfOrder {
+    #[bondrewd(bits = 12..16)]
+    last: u8,
+    #[bondrewd(bits = 0..4)]
+    first: u8,
+    #[bondrewd(bits = 4..12)]
+    middle: u8,
+}
+
+// fields without bits start where the field declared before them ends.
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", enforce_bytes = 3)]
+struct Mixed {
+    #[bondrewd(bits = 8..12)]
+    high: u8,
+    #[bondrewd(bit_length = 4)]
+    low: u8,
+    #[bondrewd(bits = 0..8)]
+    id: u8,
+    #[bondrewd(bits = 16..23, bit_length = 7)]
+    tail: u8,
+    flag: bool,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", allow_gaps)]
+struct Gaps {
+    #[bondrewd(bits = 2..8)]
+    one: u8,
+    #[bondrewd(bits = 12..16)]
+    two: u8,
+    #[bondrewd(element_bit_length = 4, bits = 20..28)]
+    nibbles: [u8; 2],
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", allow_gaps, fill_bytes = 3)]
+struct FilledGaps {
+    #[bondrewd(bits = 4..8)]
+    one: u8,
+    #[bondrewd(bits = 12..16)]
+    two: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", read_from = "lsb0", fill_bytes = 2)]
+struct Lsb0Ranges {
+    #[bondrewd(bits = 0..3)]
+    low: u8,
+    #[bondrewd(bits = 3..8)]
+    high: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2, allow_gaps)]
+enum Command {
+    Stop,
+    Go {
+        #[bondrewd(bits = 4..8)]
+        speed: u8,
+    },
+}
+
+#[test]
+fn out_of_order_ranges() {
+    assert_eq!(OutOfOrder::BIT_SIZE, 16);
+    let value = OutOfOrder {
+        last: 0xD,
+        first: 0xA,
+        middle: 0xBC,
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(bytes, [0xAB, 0xCD]);
+    assert_eq!(OutOfOrder::read_middle(&bytes), 0xBC);
+    assert_eq!(OutOfOrder::from_bytes(bytes), value);
+}
+
+#[test]
+fn explicit_and_inferred_ranges() {
+    assert_eq!(Mixed::BIT_SIZE, 24);
+    let value = Mixed {
+        high: 0x1,
+        low: 0x2,
+        id: 0xFE,
+        tail: 0x55,
+        flag: true,
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(bytes, [0xFE, 0x12, 0b1010_1011]);
+    assert_eq!(Mixed::from_bytes(bytes), value);
+}
+
+#[test]
+fn gaps_are_reserved() -> anyhow::Result<()> {
+    assert_eq!(Gaps::BIT_SIZE, 28);
+    assert_eq!(Gaps::BYTE_SIZE, 4);
+    let value = Gaps {
+        one: 0x3F,
+        two: 0xF,
+        nibbles: [0xA, 0x5],
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(bytes, [0x3F, 0x0F, 0x0A, 0x50]);
+    assert_eq!(Gaps::from_bytes([0xFF; 4]).two, 0xF);
+    assert_eq!(Gaps::try_from_bytes(bytes)?, value);
+
+    let mut bytes = bytes;
+    bytes[1] |= 0b0100_0000;
+    let err = Gaps::try_from_bytes(bytes).unwrap_err();
+    assert_eq!(err.field, "bondrewd_gap_bits_8");
+    assert_eq!(err.bits, 8..12);
+    assert_eq!(err.kind, BitfieldDecodeErrorKind::NonZeroReserve);
+    // the bits before the first field are a gap too.
+    let err = Gaps::try_from_bytes([0x80, 0, 0, 0]).unwrap_err();
+    assert_eq!(err.bits, 0..2);
+    Ok(())
+}
+
+#[test]
+fn gaps_and_fill_bytes() {
+    assert_eq!(FilledGaps::BYTE_SIZE, 3);
+    let value = FilledGaps { one: 0x1, two: 0x2 };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(bytes, [0x01, 0x02, 0x00]);
+    assert_eq!(FilledGaps::from_bytes([0xF1, 0xF2, 0xFF]), value);
+    let err = FilledGaps::try_from_bytes([0x01, 0x02, 0x01]).unwrap_err();
+    assert_eq!(err.field, "bondrewd_fill_bits");
+}
+
+#[test]
+fn lsb0_ranges() {
+    assert_eq!(Lsb0Ranges::BYTE_SIZE, 2);
+    let value = Lsb0Ranges {
+        low: 0b101,
+        high: 0b1_0011,
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(bytes, [0x00, 0b1001_1101]);
+    assert_eq!(Lsb0Ranges::from_bytes(bytes), value);
+}
+
+#[test]
+fn variant_gaps() {
+    assert_eq!(Command::BIT_SIZE, 8);
+    let command = Command::Go { speed: 0xA };
+    let bytes = command.clone().into_bytes();
+    assert_eq!(bytes, [0b0100_1010]);
+    assert_eq!(Command::from_bytes(bytes), command);
+}
@@ -0,0 +1,158 @@
+use bondrewd::*;
+
+const VALUE_BITS: usize = 9;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Sample {
+    #[bondrewd(bit_length = 3)]
+    channel: u8,
+    #[bondrewd(bit_length = 9)]
+    value: u16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le", reverse)]
+struct Reversed {
+    #[bondrewd(bit_length = 3)]
+    channel: u8,
+    #[bondrewd(bit_length = 9)]
+    value: u16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct ConstSample {
+    #[bondrewd(bit_length = 3)]
+    channel: u8,
+    #[bondrewd(bit_length = VALUE_BITS)]
+    value: u16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2)]
+enum Record {
+    Empty,
+    Flag {
+        set: bool,
+    },
+    Sample {
+        #[bondrewd(bit_length = 12)]
+        sample: Sample,
+    },
+}
+
+#[test]
+fn records_back_to_back() -> anyhow::Result<()> {
+    let samples = [
+        Sample {
+            channel: 2,
+            value: 1,
+        },
+        Sample {
+            channel: 7,
+            value: 3,
+        },
+        Sample {
+            channel: 5,
+            value: 0x1FF,
+        },
+    ];
+    let mut bytes = [0u8; 5];
+    let mut writer = BitWriter::new(&mut bytes);
+    for sample in samples.iter() {
+        writer.write(sample.clone())?;
+    }
+    assert_eq!(writer.bit_index(), 36);
+    assert_eq!(writer.bytes_used(), 5);
+    assert_eq!(bytes, [0x40, 0x1E, 0x03, 0xBF, 0xF0]);
+
+    let mut reader = BitReader::new(&bytes);
+    for sample in samples.iter() {
+        let read: Sample = reader.read()?;
+        assert_eq!(&read, sample);
+    }
+    assert_eq!(reader.remaining_bits(), 4);
+    assert!(reader.read::<Sample, 2>().is_err());
+    assert_eq!(reader.bit_index(), 36);
+    Ok(())
+}
+
+#[test]
+fn read_and_write_at_offsets() -> anyhow::Result<()> {
+    let sample = Sample {
+        channel: 7,
+        value: 0x1FF,
+    };
+    // bits around the structure are left alone.
+    let mut bytes = [0b1000_0000, 0, 0b0001_1111];
+    sample.clone().write_to_bits(&mut bytes, 1)?;
+    assert_eq!(bytes, [0xFF, 0b1111_1000, 0b0001_1111]);
+    assert_eq!(Sample::read_from_bits(&bytes, 1)?, sample);
+    // byte aligned offsets.
+    let mut bytes = [0xAA; 3];
+    sample.clone().write_to_bits(&mut bytes, 8)?;
+    assert_eq!(bytes, [0xAA, 0xFF, 0xFA]);
+    assert_eq!(Sample::read_from_bits(&bytes, 8)?, sample);
+
+    let err = Sample::read_from_bits(&bytes, 13).unwrap_err();
+    assert_eq!((err.0, err.1), (3, 4));
+    assert!(sample.write_to_bits(&mut bytes, 13).is_err());
+    assert_eq!(bytes, [0xAA, 0xFF, 0xFA]);
+    Ok(())
+}
+
+#[test]
+fn reversed_const_and_enum_records() -> anyhow::Result<()> {
+    let reversed = Reversed {
+        channel: 5,
+        value: 0x123,
+    };
+    let mut bytes = [0u8; 4];
+    let mut writer = BitWriter::with_bit_index(&mut bytes, 4);
+    writer.write(reversed.clone())?;
+    writer.write(ConstSample {
+        channel: 1,
+        value: 0x0F0,
+    })?;
+    let mut reader = BitReader::with_bit_index(&bytes, 4);
+    assert_eq!(reader.read::<Reversed, 2>()?, reversed);
+    let sample: ConstSample = reader.read()?;
+    assert_eq!(sample.value, 0x0F0);
+
+    // in a stream the fields of a reversed structure are as they are before the bytes get
+    // reversed, so they are next to each other.
+    let mut reversed_bytes = reversed.clone().into_bytes();
+    reversed_bytes.reverse();
+    let mut expected = [0u8; 2];
+    bitfield_layout::write_bytes(&mut expected, 0, 12, None, &reversed_bytes);
+    assert_eq!(Reversed::read_from_bits(&expected, 0)?, reversed);
+
+    let records = [
+        Record::Flag { set: true },
+        Record::Empty,
+        Record::Sample {
+            sample: Sample {
+                channel: 3,
+                value: 0x100,
+            },
+        },
+    ];
+    // each record uses 14 bits.
+    let mut bytes = [0u8; 7];
+    let mut writer = BitWriter::new(&mut bytes);
+    for record in records.iter() {
+        writer.write(record.clone())?;
+        writer.align_to_byte();
+    }
+    let mut reader = BitReader::new(&bytes);
+    for record in records.iter() {
+        let read: Record = reader.read()?;
+        reader.align_to_byte();
+        assert_eq!(&read, record);
+    }
+    assert_eq!(reader.bit_index(), 48);
+    reader.skip(8)?;
+    assert!(reader.skip(9).is_err());
+    Ok(())
+}
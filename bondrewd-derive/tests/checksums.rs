@@ -0,0 +1,151 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", enforce_bytes = 6)]
+struct Packet {
+    #[bondrewd(bit_length = 4)]
+    version: u8,
+    #[bondrewd(bit_length = 12)]
+    id: u16,
+    length: u8,
+    #[bondrewd(checksum = "sum8", over = 0..3)]
+    sum: u8,
+    // without over the checksum covers the bytes before it.
+    #[bondrewd(checksum = "crc16_ccitt")]
+    crc: u16,
+}
+
+// the two's complement of the sum of the bytes, so all bytes add up to 0.
+struct TwosComplement;
+
+impl BitfieldChecksum for TwosComplement {
+    type Output = u8;
+    fn checksum(bytes: &[u8]) -> u8 {
+        Sum8::checksum(bytes).wrapping_neg()
+    }
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Nested {
+    // covers inner, so it is filled after inner.
+    #[bondrewd(checksum = "xor8", over = "data..")]
+    outer: u8,
+    data: [u8; 3],
+    #[bondrewd(checksum = TwosComplement, over = "data..inner")]
+    inner: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", runtime_endianness)]
+struct Short {
+    value: u16,
+    #[bondrewd(checksum = "crc16_ccitt", bit_length = 12)]
+    crc: u16,
+    #[bondrewd(bit_length = 4)]
+    flags: u8,
+}
+
+#[test]
+fn builtin_checksums() {
+    assert_eq!(Crc16Ccitt::checksum(b"123456789"), 0x29B1);
+    assert_eq!(Sum8::checksum(&[0xFF, 0x02, 0x03]), 0x04);
+    assert_eq!(Xor8::checksum(&[0xF0, 0x0F, 0x01]), 0xFE);
+    assert_eq!(TwosComplement::checksum(&[0x01, 0x02]), 0xFD);
+}
+
+#[test]
+fn into_bytes_fills_checksums() -> anyhow::Result<()> {
+    let packet = Packet {
+        version: 0x1,
+        id: 0x234,
+        length: 0x56,
+        sum: 0,
+        crc: 0,
+    };
+    let bytes = packet.into_bytes();
+    assert_eq!(bytes[..3], [0x12, 0x34, 0x56]);
+    assert_eq!(bytes[3], 0x12 + 0x34 + 0x56);
+    let crc = Crc16Ccitt::checksum(&bytes[..4]);
+    assert_eq!(bytes[4..], crc.to_be_bytes());
+    Packet::verify_checksums(&bytes)?;
+
+    let decoded = Packet::from_bytes(bytes);
+    assert_eq!(decoded.sum, 0x9C);
+    assert_eq!(decoded.crc, crc);
+    Ok(())
+}
+
+#[test]
+fn verify_checksums_reports_the_field() {
+    let mut bytes = Packet {
+        version: 0x1,
+        id: 0x234,
+        length: 0x56,
+        sum: 0,
+        crc: 0,
+    }
+    .into_bytes();
+    bytes[2] = 0x57;
+    assert_eq!(
+        Packet::verify_checksums(&bytes),
+        Err(BitfieldChecksumError {
+            field: "sum",
+            expected: 0x9D,
+            actual: 0x9C,
+        })
+    );
+    bytes[3] = 0x9D;
+    let err = Packet::verify_checksums(&bytes).unwrap_err();
+    assert_eq!(err.field, "crc");
+    assert_eq!(err.expected, Crc16Ccitt::checksum(&bytes[..4]) as u128);
+    assert_eq!(
+        Packet::verify_checksums(&[0, 0, 1, 1, 0, 0])
+            .unwrap_err()
+            .to_string(),
+        format!(
+            "checksum crc is 0x0 but the bytes it covers have a checksum of {:#x}.",
+            Crc16Ccitt::checksum(&[0, 0, 1, 1])
+        )
+    );
+}
+
+#[test]
+fn checksums_covering_checksums() -> anyhow::Result<()> {
+    let nested = Nested {
+        outer: 0,
+        data: [1, 2, 4],
+        inner: 0,
+    };
+    let bytes = nested.into_bytes();
+    assert_eq!(bytes[1..4], [1, 2, 4]);
+    assert_eq!(bytes[4], 0xF9);
+    assert_eq!(bytes[0], 1 ^ 2 ^ 4 ^ 0xF9);
+    Nested::verify_checksums(&bytes)?;
+    Ok(())
+}
+
+#[test]
+fn checksums_with_runtime_endianness() -> anyhow::Result<()> {
+    let short = Short {
+        value: 0x1234,
+        crc: 0,
+        flags: 0xF,
+    };
+    let le = short.clone().into_bytes_le();
+    assert_eq!(le[..2], [0x34, 0x12]);
+    // only the low 12 bits of the crc fit in the field.
+    let crc = Crc16Ccitt::checksum(&le[..2]) & 0xFFF;
+    assert_eq!(Short::read_crc_le(&le), crc);
+    Short::verify_checksums_le(&le)?;
+
+    let be = short.into_bytes();
+    assert_eq!(
+        Short::read_crc(&be),
+        Crc16Ccitt::checksum(&[0x12, 0x34]) & 0xFFF
+    );
+    Short::verify_checksums(&be)?;
+    Short::verify_checksums_be(&be)?;
+    assert!(Short::verify_checksums_le(&be).is_err());
+    Ok(())
+}
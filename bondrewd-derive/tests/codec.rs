@@ -0,0 +1,145 @@
+use bondrewd::codec::{BitfieldsCodec, TryBitfieldsCodec};
+use bondrewd::*;
+use futures::{SinkExt, StreamExt};
+use tokio::io::AsyncWriteExt;
+use tokio_util::codec::{FramedRead, FramedWrite};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Telemetry {
+    #[bondrewd(bit_length = 4)]
+    channel: u8,
+    #[bondrewd(bit_length = 12)]
+    value: u16,
+    counter: u8,
+}
+
+fn telemetry(count: u8) -> Vec<Telemetry> {
+    (0..count)
+        .map(|counter| Telemetry {
+            channel: counter % 16,
+            value: 0x100 + counter as u16,
+            counter,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn frames_over_a_duplex_stream() -> anyhow::Result<()> {
+    // a buffer smaller than a frame, so frames arrive split up.
+    let (client, server) = tokio::io::duplex(2);
+    let frames = telemetry(5);
+    let sent = frames.clone();
+    let writer = tokio::spawn(async move {
+        let mut sink = FramedWrite::new(client, BitfieldsCodec::<Telemetry, 3>::new());
+        for frame in sent {
+            sink.send(frame).await?;
+        }
+        anyhow::Ok(())
+    });
+    let mut stream = FramedRead::new(server, BitfieldsCodec::<Telemetry, 3>::default());
+    let mut received = Vec::new();
+    while let Some(frame) = stream.next().await {
+        received.push(frame?);
+    }
+    writer.await??;
+    assert_eq!(received, frames);
+    Ok(())
+}
+
+#[tokio::test]
+async fn frames_after_sync_markers() -> anyhow::Result<()> {
+    let (mut client, server) = tokio::io::duplex(64);
+    let frames = telemetry(3);
+    let writer = tokio::spawn(async move {
+        // noise and a marker missing its last byte come before the first frame.
+        client.write_all(&[0x00, 0x1A, 0xCF, 0x1A]).await?;
+        client.write_all(&[0x1A, 0xCF, 0xFC]).await?;
+        let mut sink = FramedWrite::new(
+            client,
+            BitfieldsCodec::<Telemetry, 3>::with_sync_marker([0x1A, 0xCF, 0xFC, 0x1D]),
+        );
+        for frame in telemetry(3) {
+            sink.send(frame).await?;
+        }
+        // noise at the end of the stream is dropped.
+        sink.get_mut().write_all(&[0x1A, 0xCF]).await?;
+        anyhow::Ok(())
+    });
+    let codec = BitfieldsCodec::<Telemetry, 3>::with_sync_marker(vec![0x1A, 0xCF, 0xFC, 0x1D]);
+    assert_eq!(codec.sync_marker(), [0x1A, 0xCF, 0xFC, 0x1D]);
+    let mut stream = FramedRead::new(server, codec);
+    let mut received = Vec::new();
+    while let Some(frame) = stream.next().await {
+        received.push(frame?);
+    }
+    writer.await??;
+    assert_eq!(received, frames);
+    Ok(())
+}
+
+#[tokio::test]
+async fn encoded_frames_start_with_the_marker() -> anyhow::Result<()> {
+    let mut sink = FramedWrite::new(
+        Vec::new(),
+        BitfieldsCodec::<Telemetry, 3>::with_sync_marker([0xEB, 0x90]),
+    );
+    sink.send(Telemetry {
+        channel: 0xA,
+        value: 0xBCD,
+        counter: 0xEF,
+    })
+    .await?;
+    assert_eq!(sink.get_ref(), &[0xEB, 0x90, 0xAB, 0xCD, 0xEF]);
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Checked {
+    value: u8,
+    #[bondrewd(reserve)]
+    reserved: u8,
+}
+
+#[tokio::test]
+async fn invalid_frames_are_invalid_data() -> anyhow::Result<()> {
+    let bytes: &[u8] = &[0xEB, 0x90, 0x12, 0x00, 0xEB, 0x90, 0x34, 0xFF];
+    let codec = TryBitfieldsCodec::<Checked, 2>::with_sync_marker([0xEB, 0x90]);
+    assert_eq!(codec.sync_marker(), [0xEB, 0x90]);
+    let mut stream = FramedRead::new(bytes, codec);
+    assert_eq!(
+        stream.next().await.transpose()?,
+        Some(Checked {
+            value: 0x12,
+            reserved: 0
+        })
+    );
+    let err = stream.next().await.unwrap().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    let decode_err = err
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<BitfieldDecodeError>())
+        .unwrap();
+    assert_eq!(decode_err.field, "reserved");
+    assert_eq!(decode_err.kind, BitfieldDecodeErrorKind::NonZeroReserve);
+
+    // BitfieldsCodec does not check the frame.
+    let mut stream = FramedRead::new(
+        &bytes[4..],
+        BitfieldsCodec::<Checked, 2>::with_sync_marker([0xEB, 0x90]),
+    );
+    assert_eq!(
+        stream.next().await.transpose()?.map(|frame| frame.value),
+        Some(0x34)
+    );
+
+    let mut sink = FramedWrite::new(Vec::new(), TryBitfieldsCodec::<Checked, 2>::default());
+    sink.send(Checked {
+        value: 0x56,
+        reserved: 0,
+    })
+    .await?;
+    assert_eq!(sink.get_ref(), &[0x56, 0x00]);
+    Ok(())
+}
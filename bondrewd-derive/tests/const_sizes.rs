@@ -0,0 +1,475 @@
+use bondrewd::*;
+
+const ID_BITS: usize = 11;
+const SMALL_BITS: usize = 5;
+const KIND_BITS: usize = 3;
+const SAMPLE_BITS: usize = 12;
+const SAMPLES: usize = 3;
+const INNER_BYTES: usize = 2;
+const INNER_BITS: usize = 12;
+const WIDE_BITS: usize = 40;
+const RESERVED_BITS: usize = 4;
+
+mod sizes {
+    pub const HEADER_BYTES: usize = 4;
+    pub const PAYLOAD_BYTES: usize = 5;
+
+    pub const fn bits_for(max: usize) -> usize {
+        (usize::BITS - max.leading_zeros()) as usize
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug, BitfieldEnum)]
+#[bondrewd_enum(u8)]
+enum Kind {
+    Zero,
+    One,
+    Two,
+    Other(u8),
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Inner {
+    #[bondrewd(bit_length = 4)]
+    channel: u8,
+    value: u8,
+}
+
+// the same structure with sizes from constants and with literal sizes, both have to pack the same
+// bytes.
+macro_rules! layout_pair {
+    ($const_name:ident, $literal_name:ident, $($attrs:tt)*) => {
+        layout_pair!(@struct $const_name, [$($attrs)*], ID_BITS, SMALL_BITS, KIND_BITS, SAMPLE_BITS, SAMPLES, INNER_BYTES, INNER_BITS, WIDE_BITS, RESERVED_BITS);
+        layout_pair!(@struct $literal_name, [$($attrs)*], 11, 5, 3, 12, 3, 2, 12, 40, 4);
+    };
+    (@struct $name:ident, [$($attrs:tt)*], $id:tt, $small:tt, $kind:tt, $sample:tt, $samples:tt, $inner_bytes:tt, $inner:tt, $wide:tt, $reserved:tt) => {
+        #[derive(Bitfields, Clone, PartialEq, Debug)]
+        #[bondrewd($($attrs)*)]
+        struct $name {
+            #[bondrewd(bit_length = $id)]
+            id: u16,
+            #[bondrewd(bit_length = $small)]
+            small: i8,
+            #[bondrewd(enum_primitive = "u8", bit_length = $kind)]
+            kind: Kind,
+            flag: bool,
+            #[bondrewd(element_bit_length = $sample)]
+            samples: [i16; $samples],
+            #[bondrewd(struct_size = $inner_bytes, bit_length = $inner)]
+            inner: Inner,
+            #[bondrewd(reserve, bit_length = $reserved)]
+            reserved: u8,
+            #[bondrewd(bit_length = $wide)]
+            wide: u64,
+            letter: char,
+            ratio: f32,
+        }
+
+        impl $name {
+            fn sample() -> Self {
+                Self {
+                    id: 0x5A5,
+                    small: -9,
+                    kind: Kind::Two,
+                    flag: true,
+                    samples: [-1, 0x7FF, -0x800],
+                    inner: Inner {
+                        channel: 0xA,
+                        value: 0x5C,
+                    },
+                    reserved: 0,
+                    wide: 0xAB_CDEF_0123,
+                    letter: 'ß',
+                    ratio: -1.5,
+                }
+            }
+        }
+    };
+}
+
+layout_pair!(ConstBe, LiteralBe, default_endianness = "be");
+layout_pair!(ConstLe, LiteralLe, default_endianness = "le");
+layout_pair!(ConstNe, LiteralNe, default_endianness = "ne");
+layout_pair!(
+    ConstReverse,
+    LiteralReverse,
+    default_endianness = "le",
+    reverse
+);
+layout_pair!(
+    ConstLsb0,
+    LiteralLsb0,
+    default_endianness = "be",
+    read_from = "lsb0"
+);
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", enforce_bytes = sizes::HEADER_BYTES)]
+struct Header {
+    #[bondrewd(bit_length = sizes::bits_for(1000))]
+    id: u16,
+    #[bondrewd(bit_length = 16 - sizes::bits_for(1000))]
+    flags: u8,
+    #[bondrewd(byte_length = sizes::HEADER_BYTES / 2)]
+    length: u16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", fill_bytes = sizes::HEADER_BYTES + sizes::PAYLOAD_BYTES + 1)]
+struct Frame {
+    #[bondrewd(struct_size = sizes::HEADER_BYTES)]
+    header: Header,
+    payload: [u8; sizes::PAYLOAD_BYTES],
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Outer {
+    #[bondrewd(bit_length = 4)]
+    tag: u8,
+    #[bondrewd(struct_size = 4)]
+    header: Header,
+    #[bondrewd(bit_length = 4)]
+    tail: u8,
+}
+
+#[test]
+fn const_sizes_match_literal_sizes() {
+    assert_eq!(ConstBe::BIT_SIZE, LiteralBe::BIT_SIZE);
+    assert_eq!(ConstBe::BYTE_SIZE, LiteralBe::BYTE_SIZE);
+    assert_eq!(
+        ConstBe::sample().into_bytes(),
+        LiteralBe::sample().into_bytes()
+    );
+    assert_eq!(
+        ConstLe::sample().into_bytes(),
+        LiteralLe::sample().into_bytes()
+    );
+    assert_eq!(
+        ConstNe::sample().into_bytes(),
+        LiteralNe::sample().into_bytes()
+    );
+    assert_eq!(
+        ConstReverse::sample().into_bytes(),
+        LiteralReverse::sample().into_bytes()
+    );
+    assert_eq!(
+        ConstLsb0::sample().into_bytes(),
+        LiteralLsb0::sample().into_bytes()
+    );
+    assert_eq!(
+        ConstBe::from_bytes(ConstBe::sample().into_bytes()),
+        ConstBe::sample()
+    );
+    assert_eq!(
+        ConstReverse::from_bytes(ConstReverse::sample().into_bytes()),
+        ConstReverse::sample()
+    );
+    assert_eq!(
+        ConstLsb0::from_bytes(ConstLsb0::sample().into_bytes()),
+        ConstLsb0::sample()
+    );
+}
+
+#[test]
+fn const_sizes_read_and_write() {
+    let mut bytes = ConstLe::sample().into_bytes();
+    assert_eq!(ConstLe::read_small(&bytes), -9);
+    assert_eq!(ConstLe::read_samples(&bytes), [-1, 0x7FF, -0x800]);
+    assert_eq!(ConstLe::read_kind(&bytes), Kind::Two);
+    assert_eq!(ConstLe::read_letter(&bytes), 'ß');
+    ConstLe::write_wide(&mut bytes, 0x12_3456_789A);
+    ConstLe::write_kind(&mut bytes, Kind::Other(6));
+    ConstLe::write_inner(
+        &mut bytes,
+        Inner {
+            channel: 0x3,
+            value: 0x00,
+        },
+    );
+    let mut literal = LiteralLe::sample().into_bytes();
+    LiteralLe::write_wide(&mut literal, 0x12_3456_789A);
+    LiteralLe::write_kind(&mut literal, Kind::Other(6));
+    LiteralLe::write_inner(
+        &mut literal,
+        Inner {
+            channel: 0x3,
+            value: 0x00,
+        },
+    );
+    assert_eq!(bytes, literal);
+    assert_eq!(ConstLe::read_id(&bytes), 0x5A5);
+    assert_eq!(ConstLe::read_ratio(&bytes), -1.5);
+}
+
+#[test]
+fn const_sizes_slices_views_and_paths() -> anyhow::Result<()> {
+    let bytes = ConstReverse::sample().into_bytes();
+    assert_eq!(ConstReverse::read_slice_wide(&bytes)?, 0xAB_CDEF_0123);
+    assert!(ConstReverse::read_slice_wide(&bytes[..2]).is_err());
+    let checked = ConstReverse::check_slice(&bytes)?;
+    assert_eq!(checked.read_samples(), [-1, 0x7FF, -0x800]);
+    let mut buffer = [0u8; 32];
+    let mut checked = ConstBe::check_slice_mut(&mut buffer)?;
+    checked.write_id(0x7FF);
+    assert_eq!(ConstBe::read_slice_id(&buffer)?, 0x7FF);
+    assert!(ConstBe::check_slice(&buffer[..3]).is_err());
+
+    let mut bytes = ConstBe::sample().into_bytes();
+    let mut view = ConstBe::view_mut(&mut bytes);
+    view.write_small(7);
+    assert_eq!(view.read_small(), 7);
+    assert_eq!(ConstBe::paths().wide().read(&bytes), 0xAB_CDEF_0123);
+
+    assert_eq!(ConstBe::try_from_bytes(bytes)?.small, 7);
+    let hex = ConstBe::sample().into_hex_upper();
+    assert_eq!(ConstBe::from_hex(hex).unwrap(), ConstBe::sample());
+    Ok(())
+}
+
+#[test]
+fn const_paths_and_expressions() {
+    assert_eq!(Header::BYTE_SIZE, sizes::HEADER_BYTES);
+    let header = Header {
+        id: 999,
+        flags: 0x3F,
+        length: 0x1234,
+    };
+    let bytes = header.clone().into_bytes();
+    assert_eq!(bytes, [0b1111_1001, 0b1111_1111, 0x12, 0x34]);
+    assert_eq!(Header::from_bytes(bytes), header);
+
+    assert_eq!(Frame::BYTE_SIZE, 10);
+    assert_eq!(Frame::BIT_SIZE, 80);
+    let frame = Frame {
+        header: header.clone(),
+        payload: [1, 2, 3, 4, 5],
+    };
+    let frame_bytes = frame.clone().into_bytes();
+    assert_eq!(frame_bytes[..4], bytes);
+    assert_eq!(frame_bytes[4..9], [1, 2, 3, 4, 5]);
+    assert_eq!(Frame::from_bytes(frame_bytes), frame);
+
+    // structures with sizes from constants can be nested in other structures.
+    let outer = Outer {
+        tag: 0xA,
+        header: header.clone(),
+        tail: 0x5,
+    };
+    let outer_bytes = outer.clone().into_bytes();
+    assert_eq!(Outer::from_bytes(outer_bytes), outer);
+    assert_eq!(Outer::paths().header().length().read(&outer_bytes), 0x1234);
+}
+
+#[test]
+fn const_sizes_try_from_bytes() {
+    let mut bytes = ConstBe::sample().into_bytes();
+    let reserved_start = 11 + 5 + 3 + 1 + 36 + 12;
+    bytes[reserved_start / 8] |= 0b1000_0000 >> (reserved_start % 8);
+    let err = ConstBe::try_from_bytes(bytes).unwrap_err();
+    assert_eq!(err.field, "reserved");
+    assert_eq!(err.bits, reserved_start..reserved_start + 4);
+    assert_eq!(err.kind, BitfieldDecodeErrorKind::NonZeroReserve);
+    assert_eq!(ConstBe::from_bytes(bytes).reserved, 0);
+
+    let mut frame = Frame {
+        header: Header {
+            id: 1,
+            flags: 0,
+            length: 0,
+        },
+        payload: [0; sizes::PAYLOAD_BYTES],
+    }
+    .into_bytes();
+    frame[sizes::HEADER_BYTES + sizes::PAYLOAD_BYTES] = 1;
+    let err = Frame::try_from_bytes(frame).unwrap_err();
+    assert_eq!(err.kind, BitfieldDecodeErrorKind::NonZeroReserve);
+}
+
+const MAGIC_BITS: usize = 4;
+const ID_START: usize = MAGIC_BITS + 3;
+const HALF_BITS: usize = 16;
+const BLOCK_BITS: usize = 18;
+const ROWS: usize = 2;
+
+// every other attribute the structure above doesn't use, with sizes from constants and literals.
+macro_rules! features_pair {
+    ($const_name:ident, $literal_name:ident) => {
+        features_pair!(@struct $const_name, MAGIC_BITS, ID_START, ID_BITS, HALF_BITS, SAMPLE_BITS, BLOCK_BITS, ROWS);
+        features_pair!(@struct $literal_name, 4, 7, 11, 16, 12, 18, 2);
+    };
+    (@struct $name:ident, $magic:tt, $id_start:tt, $id:tt, $half:tt, $sample:tt, $block:tt, $rows:tt) => {
+        #[derive(Bitfields, Clone, PartialEq, Debug)]
+        #[bondrewd(default_endianness = "be", allow_gaps)]
+        struct $name {
+            #[bondrewd(const_value = 0x9, bit_length = $magic)]
+            magic: u8,
+            #[bondrewd(bits = $id_start..$id_start + $id)]
+            id: u16,
+            #[bondrewd(alias_of = "id", bit_length = $id)]
+            signed_id: i16,
+            #[bondrewd(overlay, bits = $id_start..$id_start + 3)]
+            id_top: u8,
+            #[bondrewd(exponent_bits = 5, bit_length = $half)]
+            half: f32,
+            #[bondrewd(bit_length = $sample, scale = 0.5, offset = -40)]
+            temperature: f32,
+            #[bondrewd(block_bit_length = $block)]
+            block: [u8; 3],
+            #[bondrewd(element_bit_length = 6)]
+            grid: [[u8; 2]; $rows],
+            #[bondrewd(checksum = "sum8")]
+            sum: u8,
+        }
+
+        impl $name {
+            fn sample() -> Self {
+                Self {
+                    magic: 0x9,
+                    id: 0x5A5,
+                    signed_id: 0,
+                    id_top: 0,
+                    half: -2.5,
+                    temperature: 21.5,
+                    block: [0x3, 0xAB, 0xCD],
+                    grid: [[0x1, 0x2], [0x7, 0x4]],
+                    sum: 0,
+                }
+            }
+        }
+    };
+}
+
+features_pair!(ConstFeatures, LiteralFeatures);
+
+impl LiteralFeatures {
+    fn into_const(self) -> ConstFeatures {
+        ConstFeatures {
+            magic: self.magic,
+            id: self.id,
+            signed_id: self.signed_id,
+            id_top: self.id_top,
+            half: self.half,
+            temperature: self.temperature,
+            block: self.block,
+            grid: self.grid,
+            sum: self.sum,
+        }
+    }
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le", runtime_endianness)]
+struct ConstRuntime {
+    #[bondrewd(bit_length = ID_BITS)]
+    id: u16,
+    #[bondrewd(bit_length = SMALL_BITS)]
+    small: i8,
+    #[bondrewd(element_bit_length = SAMPLE_BITS)]
+    samples: [i16; SAMPLES],
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct ConstTagged<T> {
+    #[bondrewd(bit_length = KIND_BITS)]
+    tag: u8,
+    #[bondrewd(struct_size = INNER_BYTES, bit_length = INNER_BITS)]
+    inner: T,
+    #[bondrewd(bit_length = 8 - KIND_BITS)]
+    tail: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", fill_bytes = sizes::HEADER_BYTES)]
+struct ConstRecord {
+    #[bondrewd(bit_length = MAGIC_BITS)]
+    kind: u8,
+    #[bondrewd(bit_length = 8 - MAGIC_BITS)]
+    length: u8,
+    #[bondrewd(length_from = "length")]
+    payload: Vec<u8>,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2, fill_bytes = INNER_BYTES)]
+enum ConstCommand {
+    Stop,
+    Go {
+        #[bondrewd(bit_length = ID_BITS)]
+        speed: u16,
+    },
+    Turn {
+        #[bondrewd(bit_length = KIND_BITS)]
+        direction: u8,
+    },
+}
+
+#[test]
+fn const_sizes_with_every_attribute() -> anyhow::Result<()> {
+    assert_eq!(ConstFeatures::BIT_SIZE, LiteralFeatures::BIT_SIZE);
+    let bytes = ConstFeatures::sample().into_bytes();
+    assert_eq!(bytes, LiteralFeatures::sample().into_bytes());
+    ConstFeatures::verify_checksums(&bytes)?;
+    let decoded = ConstFeatures::try_from_bytes(bytes)?;
+    assert_eq!(decoded, LiteralFeatures::from_bytes(bytes).into_const());
+    assert_eq!(ConstFeatures::read_signed_id(&bytes), 0x5A5 - 0x800);
+    assert_eq!(ConstFeatures::read_id_top(&bytes), (0x5A5u16 >> 8) as u8);
+    assert_eq!(decoded.half, -2.5);
+    assert_eq!(decoded.temperature, 21.5);
+    assert_eq!(decoded.block, [0x3, 0xAB, 0xCD]);
+    assert_eq!(decoded.grid, [[0x1, 0x2], [0x7, 0x4]]);
+    let mut bad = bytes;
+    bad[0] ^= 0b0110_0000;
+    assert!(ConstFeatures::try_from_bytes(bad).is_err());
+    Ok(())
+}
+
+#[test]
+fn const_sizes_with_runtime_endianness_generics_and_variants() -> anyhow::Result<()> {
+    let runtime = ConstRuntime {
+        id: 0x3A5,
+        small: -3,
+        samples: [0x123, -1, 0x7FF],
+    };
+    let le = runtime.clone().into_bytes_le();
+    let be = runtime.clone().into_bytes_be();
+    assert_ne!(le, be);
+    assert_eq!(ConstRuntime::from_bytes_le(le), runtime);
+    assert_eq!(ConstRuntime::from_bytes_be(be), runtime);
+    assert_eq!(ConstRuntime::read_small_le(&le), -3);
+
+    let tagged = ConstTagged {
+        tag: 0x5,
+        inner: Inner {
+            channel: 0xA,
+            value: 0x5C,
+        },
+        tail: 0x1F,
+    };
+    assert_eq!(ConstTagged::<Inner>::BIT_SIZE, 20);
+    let bytes = tagged.clone().into_bytes();
+    assert_eq!(ConstTagged::<Inner>::from_bytes(bytes), tagged);
+    assert_eq!(ConstTagged::<Inner>::read_inner(&bytes).value, 0x5C);
+
+    let record = ConstRecord {
+        kind: 0x2,
+        length: 3,
+        payload: vec![7, 8, 9],
+    };
+    let mut out = [0u8; 8];
+    let used = record.encode_into(&mut out)?;
+    assert_eq!(used, sizes::HEADER_BYTES + 3);
+    assert_eq!(out[..used], [0x23, 0, 0, 0, 7, 8, 9]);
+    assert_eq!(ConstRecord::decode(&out[..used])?, (record, used));
+
+    assert_eq!(ConstCommand::BYTE_SIZE, INNER_BYTES);
+    let go = ConstCommand::Go { speed: 0x5A5 };
+    let bytes = go.clone().into_bytes();
+    assert_eq!(bytes, [0b0110_1101, 0b0010_1000]);
+    assert_eq!(ConstCommand::from_bytes(bytes), go);
+    let turn = ConstCommand::Turn { direction: 0x5 };
+    assert_eq!(ConstCommand::from_bytes(turn.clone().into_bytes()), turn);
+    Ok(())
+}
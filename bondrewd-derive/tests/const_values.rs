@@ -0,0 +1,109 @@
+use bondrewd::*;
+
+const VERSION: u8 = 0b01;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", enforce_bytes = 7)]
+struct Frame {
+    #[bondrewd(const_value = 0x1ACF_FC1D)]
+    sync: u32,
+    #[bondrewd(const_value = VERSION, bit_length = 2)]
+    version: (),
+    #[bondrewd(const_value = true)]
+    primary: bool,
+    #[bondrewd(bit_length = 13)]
+    id: u16,
+    #[bondrewd(const_value = -2, bit_length = 8)]
+    offset: i16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le", runtime_endianness)]
+struct Marker {
+    #[bondrewd(const_value = 0xBEEF)]
+    magic: u16,
+    value: u8,
+}
+
+#[test]
+fn const_values_are_always_written() {
+    let frame = Frame {
+        sync: 0,
+        version: (),
+        primary: false,
+        id: 0x1234,
+        offset: 0,
+    };
+    let bytes = frame.into_bytes();
+    assert_eq!(bytes, [0x1A, 0xCF, 0xFC, 0x1D, 0b0111_0010, 0x34, 0xFE]);
+    assert_eq!(Frame::read_version(&bytes), VERSION);
+    assert_eq!(Frame::read_offset(&bytes), -2);
+}
+
+#[test]
+fn from_bytes_holds_const_values() {
+    let frame = Frame::from_bytes([0, 0, 0, 0, 0b0001_0010, 0x34, 0]);
+    assert_eq!(
+        frame,
+        Frame {
+            sync: 0x1ACF_FC1D,
+            version: (),
+            primary: true,
+            id: 0x1234,
+            offset: -2,
+        }
+    );
+}
+
+#[test]
+fn try_from_bytes_checks_const_values() -> anyhow::Result<()> {
+    let bytes = [0x1A, 0xCF, 0xFC, 0x1D, 0b0111_0010, 0x34, 0xFE];
+    assert_eq!(Frame::try_from_bytes(bytes)?.id, 0x1234);
+
+    let mut wrong = bytes;
+    wrong[3] = 0x1E;
+    assert_eq!(
+        Frame::try_from_bytes(wrong),
+        Err(BitfieldDecodeError {
+            field: "sync".into(),
+            bits: 0..32,
+            kind: BitfieldDecodeErrorKind::ConstMismatch {
+                expected: 0x1ACF_FC1D,
+                actual: 0x1ACF_FC1E,
+            },
+        })
+    );
+    let mut wrong = bytes;
+    wrong[4] = 0b1111_0010;
+    assert_eq!(
+        Frame::try_from_bytes(wrong).unwrap_err().kind,
+        BitfieldDecodeErrorKind::ConstMismatch {
+            expected: 0b01,
+            actual: 0b11,
+        }
+    );
+    assert_eq!(
+        Frame::try_read_offset(&[0, 0, 0, 0, 0, 0, 0x7F])
+            .unwrap_err()
+            .kind,
+        BitfieldDecodeErrorKind::ConstMismatch {
+            expected: 0xFE,
+            actual: 0x7F,
+        }
+    );
+    let mut wrong = bytes;
+    wrong[4] = 0b0101_0010;
+    assert_eq!(
+        Frame::try_from_bytes(wrong).unwrap_err().to_string(),
+        "field primary @ bits 34..35 is 0x0 but has to be 0x1."
+    );
+    Ok(())
+}
+
+#[test]
+fn const_values_with_runtime_endianness() {
+    let marker = Marker { magic: 0, value: 7 };
+    assert_eq!(marker.clone().into_bytes_le(), [0xEF, 0xBE, 7]);
+    assert_eq!(marker.into_bytes_be(), [0xBE, 0xEF, 7]);
+    assert_eq!(Marker::from_bytes_be([0, 0, 7]).magic, 0xBEEF);
+}
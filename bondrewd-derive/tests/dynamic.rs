@@ -0,0 +1,168 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be", enforce_bytes = 6)]
+struct SpacePacket {
+    #[bondrewd(bit_length = 3)]
+    version: u8,
+    packet_type: bool,
+    secondary_header: bool,
+    #[bondrewd(bit_length = 11)]
+    apid: u16,
+    #[bondrewd(bit_length = 2)]
+    sequence_flags: u8,
+    #[bondrewd(bit_length = 14)]
+    sequence_count: u16,
+    packet_data_length: u16,
+    #[bondrewd(length_from = "packet_data_length", offset = 1)]
+    data: Vec<u8>,
+}
+
+#[test]
+fn dyn_encode_decode_owned() {
+    assert_eq!(SpacePacket::HEADER_SIZE, 6);
+    let packet = SpacePacket {
+        version: 0,
+        packet_type: true,
+        secondary_header: false,
+        apid: 0x7FF,
+        sequence_flags: 3,
+        sequence_count: 2,
+        packet_data_length: 2,
+        data: vec![0xDE, 0xAD, 0xBE],
+    };
+    let mut bytes = [0u8; 12];
+    assert_eq!(packet.encode_into(&mut bytes), Ok(9));
+    assert_eq!(
+        bytes,
+        [
+            0b0001_0111,
+            0xFF,
+            0b1100_0000,
+            2,
+            0,
+            2,
+            0xDE,
+            0xAD,
+            0xBE,
+            0,
+            0,
+            0
+        ]
+    );
+    // anything after the packet is left for the next one.
+    assert_eq!(SpacePacket::decode(&bytes), Ok((packet, 9)));
+}
+
+#[test]
+fn dyn_length_written_from_data() {
+    // the stored length is ignored, encoding always describes the data that gets written.
+    let packet = SpacePacket {
+        version: 0,
+        packet_type: false,
+        secondary_header: false,
+        apid: 1,
+        sequence_flags: 0,
+        sequence_count: 0,
+        packet_data_length: 100,
+        data: vec![1],
+    };
+    let mut bytes = [0u8; 7];
+    assert_eq!(packet.encode_into(&mut bytes), Ok(7));
+    assert_eq!(
+        SpacePacket::read_packet_data_length(&[
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]
+        ]),
+        0
+    );
+    let empty = SpacePacket {
+        data: Vec::new(),
+        ..packet
+    };
+    assert_eq!(
+        empty.encode_into(&mut bytes),
+        Err(BitfieldDynError::LengthOutOfRange(0))
+    );
+}
+
+#[test]
+fn dyn_buffer_too_short() {
+    let bytes = [0u8, 1, 0, 0, 0, 3, 1, 2];
+    assert_eq!(
+        SpacePacket::decode(&bytes[..4]),
+        Err(BitfieldDynError::Slice(4, 6))
+    );
+    assert_eq!(
+        SpacePacket::decode(&bytes),
+        Err(BitfieldDynError::Slice(8, 10))
+    );
+    let (packet, _) = SpacePacket::decode(&[0u8, 1, 0, 0, 0, 1, 1, 2]).unwrap();
+    assert_eq!(packet.data, vec![1, 2]);
+    let mut out = [0u8; 7];
+    assert_eq!(
+        packet.encode_into(&mut out),
+        Err(BitfieldDynError::Slice(7, 8))
+    );
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "le", fill_bytes = 2)]
+struct Record<'a> {
+    #[bondrewd(bit_length = 4)]
+    kind: u8,
+    #[bondrewd(bit_length = 4)]
+    length: u8,
+    #[bondrewd(length_from = "length")]
+    payload: &'a [u8],
+}
+
+#[test]
+fn dyn_borrowed_payload() {
+    let bytes = [0b0001_0011, 0, 7, 8, 9, 0b0000_0001, 0, 1];
+    let (first, used) = Record::decode(&bytes).unwrap();
+    assert_eq!(used, 5);
+    assert_eq!(
+        first,
+        Record {
+            kind: 1,
+            length: 3,
+            payload: &[7, 8, 9],
+        }
+    );
+    let (second, used) = Record::decode(&bytes[5..]).unwrap();
+    assert_eq!(used, 3);
+    assert_eq!(second.payload, &[1]);
+    let mut out = [0u8; 8];
+    let first_len = first.encode_into(&mut out).unwrap();
+    let second_len = second.encode_into(&mut out[first_len..]).unwrap();
+    assert_eq!(first_len + second_len, 8);
+    assert_eq!(out, bytes);
+    // 16 bytes can not be described by 4 bits.
+    let long = Record {
+        kind: 0,
+        length: 0,
+        payload: &[0; 16],
+    };
+    assert_eq!(
+        long.encode_into(&mut [0u8; 32]),
+        Err(BitfieldDynError::LengthOutOfRange(16))
+    );
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be")]
+struct LongFrame {
+    id: u32,
+    length: u64,
+    #[bondrewd(length_from = "length", offset = 1)]
+    data: Vec<u8>,
+}
+
+#[test]
+fn dyn_maximal_length_field() {
+    let bytes = [0xff; 12];
+    assert_eq!(
+        LongFrame::decode(&bytes),
+        Err(BitfieldDynError::Slice(12, usize::MAX))
+    );
+}
@@ -14,6 +14,13 @@ enum NoInvalidEnum {
     Three,
 }
 
+#[test]
+fn enum_last_variant_catch_all() {
+    assert_eq!(NoInvalidEnum::from_primitive(2u8), NoInvalidEnum::Two);
+    assert_eq!(NoInvalidEnum::from_primitive(3u8), NoInvalidEnum::Three);
+    assert_eq!(NoInvalidEnum::from_primitive(4u8), NoInvalidEnum::Three);
+}
+
 #[derive(BitfieldEnum, PartialEq, Debug)]
 enum InferPrimitiveTypeWithInvalidEnum {
     Zero,
@@ -105,3 +112,152 @@ fn enum_centered_catch_primitive() {
     assert!(CenteredInvalidPrimitive::from_primitive(154u8).into_primitive() == 154);
     assert!(CenteredInvalidPrimitive::from_primitive(255u8).into_primitive() == 255);
 }
+
+#[derive(BitfieldEnum, PartialEq, Debug)]
+#[bondrewd_enum(u16)]
+enum CommandCode {
+    Noop,
+    Reset = 0x0A0,
+    Dump = 0xFFE,
+    Invalid,
+}
+
+#[test]
+fn enum_u16_primitive() {
+    assert_eq!(CommandCode::from_primitive(0u16), CommandCode::Noop);
+    assert_eq!(CommandCode::from_primitive(0x0A0u16), CommandCode::Reset);
+    assert_eq!(CommandCode::Dump.into_primitive(), 0xFFE);
+    assert_eq!(CommandCode::from_primitive(0x0FFFu16), CommandCode::Invalid);
+    assert_eq!(CommandCode::Invalid.into_primitive(), 1);
+}
+
+#[derive(BitfieldEnum, PartialEq, Debug)]
+#[repr(i8)]
+enum SignedAxis {
+    Negative = -1,
+    Zero = 0,
+    Positive = 1,
+    Unknown(i8),
+}
+
+#[test]
+fn enum_infer_signed_primitive() {
+    assert_eq!(SignedAxis::from_primitive(-1i8), SignedAxis::Negative);
+    assert_eq!(SignedAxis::Positive.into_primitive(), 1i8);
+    assert_eq!(
+        SignedAxis::from_primitive(-100i8),
+        SignedAxis::Unknown(-100)
+    );
+}
+
+#[derive(BitfieldEnum, PartialEq, Debug)]
+#[bondrewd_enum(i32)]
+enum SignedWide {
+    Low = -70000,
+    High = 70000,
+    Other,
+}
+
+#[test]
+fn enum_i32_primitive() {
+    assert_eq!(SignedWide::from_primitive(-70000), SignedWide::Low);
+    assert_eq!(SignedWide::High.into_primitive(), 70000i32);
+    assert_eq!(SignedWide::from_primitive(5), SignedWide::Other);
+}
+
+// 257 variants do not fit in a u8, so u16 should be inferred.
+#[derive(BitfieldEnum, PartialEq, Debug)]
+#[rustfmt::skip]
+enum ManyVariants {
+    V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11,
+    V12, V13, V14, V15, V16, V17, V18, V19, V20, V21, V22, V23,
+    V24, V25, V26, V27, V28, V29, V30, V31, V32, V33, V34, V35,
+    V36, V37, V38, V39, V40, V41, V42, V43, V44, V45, V46, V47,
+    V48, V49, V50, V51, V52, V53, V54, V55, V56, V57, V58, V59,
+    V60, V61, V62, V63, V64, V65, V66, V67, V68, V69, V70, V71,
+    V72, V73, V74, V75, V76, V77, V78, V79, V80, V81, V82, V83,
+    V84, V85, V86, V87, V88, V89, V90, V91, V92, V93, V94, V95,
+    V96, V97, V98, V99, V100, V101, V102, V103, V104, V105, V106, V107,
+    V108, V109, V110, V111, V112, V113, V114, V115, V116, V117, V118, V119,
+    V120, V121, V122, V123, V124, V125, V126, V127, V128, V129, V130, V131,
+    V132, V133, V134, V135, V136, V137, V138, V139, V140, V141, V142, V143,
+    V144, V145, V146, V147, V148, V149, V150, V151, V152, V153, V154, V155,
+    V156, V157, V158, V159, V160, V161, V162, V163, V164, V165, V166, V167,
+    V168, V169, V170, V171, V172, V173, V174, V175, V176, V177, V178, V179,
+    V180, V181, V182, V183, V184, V185, V186, V187, V188, V189, V190, V191,
+    V192, V193, V194, V195, V196, V197, V198, V199, V200, V201, V202, V203,
+    V204, V205, V206, V207, V208, V209, V210, V211, V212, V213, V214, V215,
+    V216, V217, V218, V219, V220, V221, V222, V223, V224, V225, V226, V227,
+    V228, V229, V230, V231, V232, V233, V234, V235, V236, V237, V238, V239,
+    V240, V241, V242, V243, V244, V245, V246, V247, V248, V249, V250, V251,
+    V252, V253, V254, V255, V256,
+}
+
+#[test]
+fn enum_infer_u16_from_variant_count() {
+    let value: u16 = ManyVariants::V256.into_primitive();
+    assert_eq!(value, 256);
+    assert_eq!(ManyVariants::from_primitive(255u16), ManyVariants::V255);
+    assert_eq!(ManyVariants::from_primitive(1000u16), ManyVariants::V256);
+}
+
+#[derive(BitfieldEnum, PartialEq, Debug)]
+#[bondrewd_enum(u8, strict)]
+enum StrictEnum {
+    Zero,
+    One,
+    Five = 5,
+}
+
+#[test]
+fn enum_strict_has_no_catch_all() {
+    use bondrewd::{BitfieldEnumError, TryBitfieldEnum};
+    assert_eq!(StrictEnum::try_from_primitive(0), Ok(StrictEnum::Zero));
+    assert_eq!(StrictEnum::try_from_primitive(1), Ok(StrictEnum::One));
+    assert_eq!(StrictEnum::try_from_primitive(5), Ok(StrictEnum::Five));
+    assert_eq!(StrictEnum::try_from_primitive(2), Err(BitfieldEnumError(2)));
+    assert_eq!(StrictEnum::Five.into_primitive(), 5);
+    const { assert!(!StrictEnum::HAS_CATCH_VARIANT) };
+}
+
+#[test]
+fn enum_strict_from_primitive_gives_last_variant() {
+    assert_eq!(StrictEnum::from_primitive(1), StrictEnum::One);
+    assert_eq!(StrictEnum::from_primitive(3), StrictEnum::Five);
+    assert_eq!(StrictEnum::from_primitive(255), StrictEnum::Five);
+}
+
+#[derive(BitfieldEnum, PartialEq, Debug)]
+#[bondrewd_enum(strict)]
+enum StrictWithCatch {
+    Zero,
+    Other(u8),
+    One,
+}
+
+#[test]
+fn enum_try_from_primitive_catch_variants() {
+    use bondrewd::{BitfieldEnumError, TryBitfieldEnum};
+    // strict only removes the implicit catch all, marked catch variants still catch.
+    assert_eq!(
+        StrictWithCatch::try_from_primitive(2),
+        Ok(StrictWithCatch::One)
+    );
+    assert_eq!(
+        StrictWithCatch::try_from_primitive(9),
+        Ok(StrictWithCatch::Other(9))
+    );
+    // without strict the last variant catches for from_primitive but not try_from_primitive.
+    assert_eq!(
+        NoInvalidEnum::try_from_primitive(3),
+        Ok(NoInvalidEnum::Three)
+    );
+    assert_eq!(
+        NoInvalidEnum::try_from_primitive(4),
+        Err(BitfieldEnumError(4))
+    );
+    assert_eq!(
+        CenteredInvalid::try_from_primitive(200),
+        Ok(CenteredInvalid::Invalid)
+    );
+}
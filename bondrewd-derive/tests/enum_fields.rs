@@ -53,3 +53,57 @@ fn to_bytes_simple_with_enum_spanning() -> anyhow::Result<()> {
     assert_eq!(simple, new_simple);
     Ok(())
 }
+
+#[derive(Eq, PartialEq, Clone, Debug, BitfieldEnum)]
+#[bondrewd_enum(u16)]
+#[repr(u16)]
+enum Apid {
+    Idle = 0x7FF,
+    Housekeeping = 0x064,
+    Science = 0x1F4,
+    Other(u16),
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, BitfieldEnum)]
+#[bondrewd_enum(i8)]
+#[repr(i8)]
+enum Trend {
+    Falling = -1,
+    Steady = 0,
+    Rising = 1,
+    Unknown(i8),
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct WideEnums {
+    #[bondrewd(bit_length = 3)]
+    version: u8,
+    #[bondrewd(enum_primitive = "u16", bit_length = 11)]
+    apid: Apid,
+    #[bondrewd(enum_primitive = "i8", bit_length = 2)]
+    trend: Trend,
+}
+
+#[test]
+fn wide_and_signed_enum_fields() -> anyhow::Result<()> {
+    let wide = WideEnums {
+        version: 0,
+        apid: Apid::Science,
+        trend: Trend::Falling,
+    };
+    assert_eq!(WideEnums::BYTE_SIZE, 2);
+    let bytes = wide.clone().into_bytes();
+    assert_eq!(bytes, [0b0000_0111, 0b1101_0011]);
+    assert_eq!(WideEnums::read_apid(&bytes), Apid::Science);
+    assert_eq!(WideEnums::read_trend(&bytes), Trend::Falling);
+    assert_eq!(WideEnums::from_bytes(bytes), wide);
+
+    let other = WideEnums {
+        version: 7,
+        apid: Apid::Other(0x123),
+        trend: Trend::Rising,
+    };
+    assert_eq!(WideEnums::from_bytes(other.clone().into_bytes()), other);
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2)]
+enum Frame {
+    Telemetry {
+        #[bondrewd(bit_length = 6)]
+        channel: u8,
+        value: u16,
+    },
+    Command {
+        opcode: u8,
+    },
+    Ping,
+    Unknown,
+}
+
+#[test]
+fn variants_into_bytes() {
+    assert_eq!(Frame::BYTE_SIZE, 3);
+    assert_eq!(Frame::BIT_SIZE, 24);
+    let telemetry = Frame::Telemetry {
+        channel: 5,
+        value: 0x1234,
+    };
+    assert_eq!(telemetry.into_bytes(), [0b0000_0101, 0x12, 0x34]);
+    let command = Frame::Command { opcode: 0xAB };
+    assert_eq!(command.into_bytes(), [0b0110_1010, 0b1100_0000, 0]);
+    assert_eq!(Frame::Ping.into_bytes(), [0b1000_0000, 0, 0]);
+    assert_eq!(Frame::Unknown.into_bytes(), [0b1100_0000, 0, 0]);
+}
+
+#[test]
+fn variants_from_bytes() {
+    let frames = [
+        Frame::Telemetry {
+            channel: 63,
+            value: 0xBEEF,
+        },
+        Frame::Command { opcode: 0x0F },
+        Frame::Ping,
+        Frame::Unknown,
+    ];
+    for frame in frames {
+        let bytes = frame.clone().into_bytes();
+        assert_eq!(Frame::from_bytes(bytes), frame);
+    }
+    assert_eq!(Frame::read_variant_id(&[0b0110_1010, 0, 0]), 1);
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "le", id_bit_length = 4, enforce_bytes = 2)]
+#[repr(u8)]
+enum Tagged {
+    Short {
+        #[bondrewd(bit_length = 4)]
+        a: u8,
+    } = 5,
+    Long {
+        #[bondrewd(bit_length = 12)]
+        b: u16,
+    },
+    #[bondrewd(invalid)]
+    Other,
+    Last,
+}
+
+#[test]
+fn variants_literal_ids_and_invalid() {
+    assert_eq!(
+        Tagged::read_variant_id(&Tagged::Short { a: 3 }.into_bytes()),
+        5
+    );
+    assert_eq!(
+        Tagged::read_variant_id(&Tagged::Long { b: 3 }.into_bytes()),
+        0
+    );
+    assert_eq!(Tagged::read_variant_id(&Tagged::Other.into_bytes()), 1);
+    assert_eq!(Tagged::read_variant_id(&Tagged::Last.into_bytes()), 2);
+    let long = Tagged::Long { b: 0x0ABC };
+    assert_eq!(Tagged::from_bytes(long.clone().into_bytes()), long);
+    // ids without a variant go to the variant marked invalid rather than the last one.
+    assert_eq!(Tagged::from_bytes([0b1001_0000, 0]), Tagged::Other);
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be", id_bit_length = 3, read_from = "lsb0")]
+enum LsbFrame {
+    One {
+        #[bondrewd(bit_length = 5)]
+        value: u8,
+    },
+    Two {
+        #[bondrewd(bit_length = 13)]
+        value: u16,
+    },
+}
+
+#[test]
+fn variants_lsb0() {
+    // with lsb0 the id is in the least significant bits of the last byte.
+    assert_eq!(
+        LsbFrame::One { value: 0b10101 }.into_bytes(),
+        [0, 0b1010_1000]
+    );
+    assert_eq!(LsbFrame::Two { value: 1 }.into_bytes(), [0, 0b0000_1001]);
+    let two = LsbFrame::Two { value: 0x1FFF };
+    assert_eq!(LsbFrame::from_bytes(two.clone().into_bytes()), two);
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2)]
+enum Small {
+    Zero {
+        value: u8,
+    },
+    One,
+    Two {
+        #[bondrewd(bit_length = 4, reserve)]
+        reserve: u8,
+        #[bondrewd(bit_length = 4)]
+        value: u8,
+    },
+}
+
+#[test]
+fn variants_try_from_bytes() {
+    let two = Small::Two {
+        reserve: 0,
+        value: 9,
+    };
+    assert_eq!(Small::try_from_bytes(two.clone().into_bytes()), Ok(two));
+    // 3 would land in the last variant, but it was not marked as the catch.
+    assert_eq!(
+        Small::from_bytes([0b1100_0000, 0b0000_0000]),
+        Small::Two {
+            reserve: 0,
+            value: 0
+        }
+    );
+    assert_eq!(
+        Small::try_from_bytes([0b1100_0000, 0]),
+        Err(BitfieldDecodeError {
+            field: "variant_id".into(),
+            bits: 0..2,
+            kind: BitfieldDecodeErrorKind::InvalidEnum,
+        })
+    );
+    assert_eq!(
+        Small::try_from_bytes([0b1000_0100, 0]),
+        Err(BitfieldDecodeError {
+            field: "reserve".into(),
+            bits: 2..6,
+            kind: BitfieldDecodeErrorKind::NonZeroReserve,
+        })
+    );
+    // bits past the end of a smaller variant must be zero as well.
+    assert_eq!(
+        Small::try_from_bytes([0b0100_0000, 0b0100_0000]),
+        Err(BitfieldDecodeError {
+            field: "bondrewd_fill_bits".into(),
+            bits: 2..10,
+            kind: BitfieldDecodeErrorKind::NonZeroReserve,
+        })
+    );
+}
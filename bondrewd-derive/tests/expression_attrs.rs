@@ -0,0 +1,91 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", enforce_bytes = 2 * 2)]
+struct Arithmetic {
+    #[bondrewd(bit_length = 4 * 3)]
+    one: u16,
+    #[bondrewd(byte_length = (1 + 1))]
+    two: u16,
+    #[bondrewd(bit_length = 1 << 1)]
+    three: u8,
+    #[bondrewd(bit_length = 10 - 8)]
+    four: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Ranges {
+    #[bondrewd(bits = 0..3)]
+    one: u8,
+    #[bondrewd(bits = 3..=8)]
+    two: u8,
+    #[bondrewd(bits = "9..16")]
+    three: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Debug)]
+#[bondrewd(default_endianness = "le", fill_bytes = 1 + 2)]
+struct Values {
+    #[bondrewd(element_bit_length = 2 * 2)]
+    nibbles: [u8; 2],
+    #[bondrewd(bit_length = 8 + 4, scale = 0.5, offset = -40)]
+    temperature: f32,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 1 + 1)]
+enum Command {
+    Stop,
+    Go {
+        #[bondrewd(bit_length = 3 * 2)]
+        speed: u8,
+    },
+}
+
+#[test]
+fn arithmetic_lengths() {
+    assert_eq!(Arithmetic::BIT_SIZE, 32);
+    let value = Arithmetic {
+        one: 0xABC,
+        two: 0x1234,
+        three: 0x1,
+        four: 0x3,
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(bytes, [0xAB, 0xC1, 0x23, 0x47]);
+    assert_eq!(Arithmetic::read_four(&bytes), 0x3);
+    assert_eq!(Arithmetic::from_bytes(bytes), value);
+}
+
+#[test]
+fn unquoted_bit_ranges() {
+    assert_eq!(Ranges::BIT_SIZE, 16);
+    let value = Ranges {
+        one: 0b101,
+        two: 0b11_0011,
+        three: 0b101_0101,
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(bytes, [0b1011_1001, 0b1101_0101]);
+    assert_eq!(Ranges::from_bytes(bytes), value);
+}
+
+#[test]
+fn negative_and_array_values() {
+    assert_eq!(Values::BYTE_SIZE, 3);
+    let value = Values {
+        nibbles: [0x3, 0xC],
+        temperature: 21.5,
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(Values::from_bytes(bytes), value);
+    assert_eq!(Values::read_temperature(&bytes), 21.5);
+    let command = Command::Go { speed: 0x2A };
+    assert_eq!(Command::BIT_SIZE, 8);
+    assert_eq!(command.clone().into_bytes(), [0b0110_1010]);
+    assert_eq!(
+        Command::from_bytes(command.into_bytes()),
+        Command::Go { speed: 0x2A }
+    );
+}
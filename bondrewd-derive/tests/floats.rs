@@ -0,0 +1,242 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, PartialEq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Reduced {
+    #[bondrewd(bit_length = 24)]
+    wide: f32,
+    #[bondrewd(exponent_bits = 5, bit_length = 16)]
+    half: f32,
+    #[bondrewd(exponent_bits = 5, bit_length = 16, rounding = "toward_zero")]
+    truncated: f32,
+    #[bondrewd(bit_length = 4)]
+    flags: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Debug)]
+#[bondrewd(default_endianness = "le")]
+struct HalfFloats {
+    a: half::f16,
+    b: half::bf16,
+    #[bondrewd(exponent_bits = 4, bit_length = 8)]
+    tiny: half::f16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Samples {
+    #[bondrewd(bit_length = 4)]
+    id: u8,
+    #[bondrewd(element_bit_length = 16, exponent_bits = 5)]
+    values: [f32; 3],
+    #[bondrewd(bit_length = 4)]
+    tail: u8,
+}
+
+#[test]
+fn f32_to_half_matches_ieee() {
+    // 1.0, -2.0, 65504 (largest half), 2^-24 (smallest half subnormal).
+    let cases: [(f32, u32); 5] = [
+        (1.0, 0x3C00),
+        (-2.0, 0xC000),
+        (65504.0, 0x7BFF),
+        (5.960_464_5e-8, 0x0001),
+        (0.0, 0x0000),
+    ];
+    for (value, bits) in cases {
+        assert_eq!(
+            value.to_reduced_bits(5, 10, FloatRounding::NearestEven),
+            bits
+        );
+        assert_eq!(f32::from_reduced_bits(bits, 5, 10), value);
+        assert_eq!(
+            half::f16::from_f32(value).to_bits() as u32,
+            bits,
+            "half disagrees for {value}"
+        );
+    }
+}
+
+#[test]
+fn rounding_modes() {
+    // 1 + 2^-11 is exactly between two halves, ties go to the even mantissa.
+    let tie = 1.0f32 + 2f32.powi(-11);
+    assert_eq!(
+        tie.to_reduced_bits(5, 10, FloatRounding::NearestEven),
+        0x3C00
+    );
+    let above = 1.0f32 + 2f32.powi(-11) + 2f32.powi(-12);
+    assert_eq!(
+        above.to_reduced_bits(5, 10, FloatRounding::NearestEven),
+        0x3C01
+    );
+    assert_eq!(
+        above.to_reduced_bits(5, 10, FloatRounding::TowardZero),
+        0x3C00
+    );
+    // too large for a half.
+    assert_eq!(
+        1.0e6f32.to_reduced_bits(5, 10, FloatRounding::NearestEven),
+        0x7C00
+    );
+    assert_eq!(
+        1.0e6f32.to_reduced_bits(5, 10, FloatRounding::TowardZero),
+        0x7BFF
+    );
+    // rounding up from the largest mantissa carries into the exponent.
+    let carry = 2.0f32 - 2f32.powi(-12);
+    assert_eq!(
+        carry.to_reduced_bits(5, 10, FloatRounding::NearestEven),
+        0x4000
+    );
+}
+
+#[test]
+fn special_values() {
+    assert_eq!(
+        f32::INFINITY.to_reduced_bits(5, 10, FloatRounding::TowardZero),
+        0x7C00
+    );
+    assert!(f32::from_reduced_bits(
+        f32::NAN.to_reduced_bits(5, 10, FloatRounding::NearestEven),
+        5,
+        10
+    )
+    .is_nan());
+    assert_eq!(f32::from_reduced_bits(0x7C00, 5, 10), f32::INFINITY);
+    assert_eq!(
+        (-0.0f32).to_reduced_bits(8, 15, FloatRounding::NearestEven),
+        0x80_0000
+    );
+}
+
+#[test]
+fn subnormals_with_the_full_exponent() {
+    // a reduced f32 keeping the f32 exponent holds the same subnormals, minus the low bits.
+    for value in [
+        f32::from_bits(1),
+        f32::from_bits(0x100),
+        f32::MIN_POSITIVE / 2.0,
+        -f32::MIN_POSITIVE / 3.0,
+    ] {
+        let bits = value.to_reduced_bits(8, 15, FloatRounding::TowardZero);
+        assert_eq!(
+            f32::from_reduced_bits(bits, 8, 15),
+            f32::from_bits(value.to_bits() & !0xFF),
+            "{value:e}"
+        );
+        let bits = value.to_reduced_bits(8, 7, FloatRounding::NearestEven);
+        assert_eq!(
+            bits,
+            half::bf16::from_f32(value).to_bits() as u32,
+            "bf16 disagrees for {value:e}"
+        );
+    }
+    let value = f64::from_bits(1);
+    let bits = value.to_reduced_bits(11, 36, FloatRounding::NearestEven);
+    assert_eq!(bits, 0);
+    let value = f64::MIN_POSITIVE / 2.0;
+    let bits = value.to_reduced_bits(11, 36, FloatRounding::NearestEven);
+    assert_eq!(f64::from_reduced_bits(bits, 11, 36), value);
+}
+
+#[test]
+fn bf16_layout_matches_half() {
+    for bits in (0..u32::MAX).step_by(65_521) {
+        let value = f32::from_bits(bits);
+        if value.is_nan() {
+            continue;
+        }
+        assert_eq!(
+            value.to_reduced_bits(8, 7, FloatRounding::NearestEven),
+            half::bf16::from_f32(value).to_bits() as u32,
+            "bf16 disagrees for {value:e}"
+        );
+    }
+}
+
+#[test]
+fn subnormal_fields() {
+    let value = Reduced {
+        wide: f32::MIN_POSITIVE / 2.0,
+        half: 0.0,
+        truncated: 0.0,
+        flags: 0,
+    };
+    assert_eq!(
+        Reduced::from_bytes(value.into_bytes()).wide,
+        f32::MIN_POSITIVE / 2.0
+    );
+    // the smallest subnormal is below the 24 bit float's smallest subnormal and rounds down.
+    let value = Reduced {
+        wide: f32::from_bits(1),
+        half: 0.0,
+        truncated: 0.0,
+        flags: 0,
+    };
+    assert_eq!(Reduced::from_bytes(value.into_bytes()).wide, 0.0);
+}
+
+#[test]
+fn truncated_mantissa_keeps_exponent() {
+    // 24 bit floats with the f32 exponent are the top 24 bits of the f32.
+    let value = 1234.5678f32;
+    let bits = value.to_reduced_bits(8, 15, FloatRounding::TowardZero);
+    assert_eq!(bits, value.to_bits() >> 8);
+    assert_eq!(
+        f32::from_reduced_bits(bits, 8, 15),
+        f32::from_bits(value.to_bits() & !0xFF)
+    );
+}
+
+#[test]
+fn reduced_f32_fields() {
+    assert_eq!(Reduced::BIT_SIZE, 60);
+    let above = 1.0f32 + 2f32.powi(-11) + 2f32.powi(-12);
+    let value = Reduced {
+        wide: -1.5,
+        half: above,
+        truncated: above,
+        flags: 0xA,
+    };
+    let bytes = value.into_bytes();
+    assert_eq!(bytes, [0xBF, 0xC0, 0x00, 0x3C, 0x01, 0x3C, 0x00, 0xA0]);
+    let decoded = Reduced::from_bytes(bytes);
+    assert_eq!(decoded.wide, -1.5);
+    assert_eq!(decoded.half, 1.0 + 2f32.powi(-10));
+    assert_eq!(decoded.truncated, 1.0);
+    assert_eq!(decoded.flags, 0xA);
+    assert_eq!(Reduced::read_half(&bytes), 1.0 + 2f32.powi(-10));
+    let mut bytes = bytes;
+    Reduced::write_truncated(&mut bytes, 65504.0);
+    assert_eq!(Reduced::read_truncated(&bytes), 65504.0);
+    assert_eq!(Reduced::read_flags(&bytes), 0xA);
+}
+
+#[test]
+fn half_fields() {
+    let value = HalfFloats {
+        a: half::f16::from_f32(-2.5),
+        b: half::bf16::from_f32(1.0e20),
+        tiny: half::f16::from_f32(0.75),
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(&bytes[0..2], &value.a.to_bits().to_le_bytes());
+    assert_eq!(&bytes[2..4], &value.b.to_bits().to_le_bytes());
+    // 0.75 with a 4 bit exponent and 3 bit mantissa, the bias is 7. sign 0, exponent 0110, mantissa 100.
+    assert_eq!(bytes[4], 0b0011_0100);
+    assert_eq!(HalfFloats::from_bytes(bytes), value);
+}
+
+#[test]
+fn reduced_float_arrays() {
+    let value = Samples {
+        id: 0x5,
+        values: [1.0, -2.0, 65504.0],
+        tail: 0xC,
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(bytes, [0x53, 0xC0, 0x0C, 0x00, 0x07, 0xBF, 0xFC]);
+    assert_eq!(Samples::from_bytes(bytes), value);
+    assert_eq!(Samples::read_values(&bytes), [1.0, -2.0, 65504.0]);
+}
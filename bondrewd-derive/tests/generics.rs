@@ -0,0 +1,166 @@
+use bondrewd::*;
+
+mod sensors {
+    use bondrewd::*;
+
+    #[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+    #[bondrewd(default_endianness = "be")]
+    pub struct Reading {
+        #[bondrewd(bit_length = 3)]
+        pub channel: u8,
+        #[bondrewd(bit_length = 5)]
+        pub value: u8,
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug, BitfieldEnum)]
+enum Kind {
+    Zero,
+    One,
+    Two,
+    Other(u8),
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct Tagged<T> {
+    tag: u8,
+    #[bondrewd(struct_size = 1)]
+    inner: T,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct Frame {
+    #[bondrewd(bit_length = 4)]
+    version: u8,
+    #[bondrewd(struct_size = 1, bit_length = 8)]
+    reading: sensors::Reading,
+    #[bondrewd(struct_size = 2)]
+    tagged: Tagged<sensors::Reading>,
+    #[bondrewd(bit_length = 4)]
+    tail: u8,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "le")]
+struct Flagged<E> {
+    #[bondrewd(enum_primitive = "u8", bit_length = 3)]
+    kind: E,
+    #[bondrewd(bit_length = 5)]
+    count: u8,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct Samples<const N: usize = 3> {
+    id: u8,
+    #[bondrewd(element_bit_length = 4)]
+    values: [u8; N],
+}
+
+fn reading() -> sensors::Reading {
+    sensors::Reading {
+        channel: 5,
+        value: 17,
+    }
+}
+
+#[test]
+fn generic_nested_struct() {
+    let tagged = Tagged {
+        tag: 0xA5,
+        inner: reading(),
+    };
+    assert_eq!(Tagged::<sensors::Reading>::BYTE_SIZE, 2);
+    let bytes = tagged.clone().into_bytes();
+    assert_eq!(bytes, [0xA5, 0b1011_0001]);
+    assert_eq!(Tagged::from_bytes(bytes), tagged);
+    assert_eq!(Tagged::<sensors::Reading>::read_inner(&bytes), reading());
+    let view = Tagged::<sensors::Reading>::view(&bytes);
+    assert_eq!(view.read_inner().read_value(), 17);
+    let paths = Tagged::<sensors::Reading>::paths();
+    assert_eq!(paths.inner().channel().read(&bytes), 5);
+}
+
+#[test]
+fn module_and_generic_paths() {
+    let frame = Frame {
+        version: 0xF,
+        reading: reading(),
+        tagged: Tagged {
+            tag: 1,
+            inner: sensors::Reading {
+                channel: 2,
+                value: 3,
+            },
+        },
+        tail: 0x9,
+    };
+    let bytes = frame.clone().into_bytes();
+    assert_eq!(bytes, [0xFB, 0x10, 0x14, 0x39]);
+    assert_eq!(Frame::from_bytes(bytes), frame);
+    assert_eq!(Frame::read_tagged(&bytes), frame.tagged);
+    let checked = Frame::check_slice(&bytes).unwrap();
+    assert_eq!(checked.read_reading(), reading());
+}
+
+#[test]
+fn generic_enum_field() {
+    let flagged = Flagged {
+        kind: Kind::Two,
+        count: 9,
+    };
+    let bytes = flagged.clone().into_bytes();
+    assert_eq!(Flagged::from_bytes(bytes), flagged);
+    let checked = Flagged::<Kind>::check_slice(&bytes).unwrap();
+    assert_eq!(checked.read_kind(), Kind::Two);
+    assert_eq!(checked.read_count(), 9);
+    assert_eq!(
+        Flagged::<Kind>::try_from_bytes(bytes),
+        Ok(Flagged {
+            kind: Kind::Two,
+            count: 9,
+        })
+    );
+}
+
+#[test]
+fn const_generic_array_default() {
+    let samples: Samples = Samples {
+        id: 7,
+        values: [1, 2, 3],
+    };
+    assert_eq!(Samples::<3>::BIT_SIZE, 20);
+    let bytes = samples.clone().into_bytes();
+    assert_eq!(bytes, [7, 0x12, 0x30]);
+    assert_eq!(Samples::from_bytes(bytes), samples);
+}
+
+// single letter names are also used for the parameters of views and paths.
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct B {
+    #[bondrewd(bit_length = 4)]
+    one: u8,
+    #[bondrewd(bit_length = 4)]
+    two: u8,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct P {
+    #[bondrewd(struct_size = 1)]
+    inner: B,
+}
+
+#[test]
+fn structure_named_like_generated_parameters() {
+    let bytes = P {
+        inner: B { one: 1, two: 2 },
+    }
+    .into_bytes();
+    assert_eq!(bytes, [0x12]);
+    assert_eq!(P::view(&bytes).read_inner().read_two(), 2);
+    assert_eq!(P::paths().inner().one().read(&bytes), 1);
+}
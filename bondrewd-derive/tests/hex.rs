@@ -27,7 +27,7 @@ mod hex_tests {
     #[test]
     fn hex_test() {
         let og = Magnetometer {
-            timestamp: 063482412850,
+            timestamp: 63482412850,
             status: StatusMagnetometer {
                 int_mtm1: true,
                 int_mtm2: false,
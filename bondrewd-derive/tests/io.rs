@@ -0,0 +1,98 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Header {
+    #[bondrewd(bit_length = 4)]
+    version: u8,
+    #[bondrewd(reserve, bit_length = 4)]
+    reserve: u8,
+    length: u16,
+}
+
+fn header() -> Header {
+    Header {
+        version: 0x2,
+        reserve: 0,
+        length: 0x1234,
+    }
+}
+
+mod std_io {
+    use super::*;
+    use bondrewd::io::{ReadBitfieldsExt, WriteBitfieldsExt};
+    use std::io::Cursor;
+
+    #[test]
+    fn read_and_write_bitfields() -> anyhow::Result<()> {
+        let mut output = Vec::new();
+        output.write_bitfields(&header())?;
+        output.write_bitfields(&header())?;
+        assert_eq!(output, [0x20, 0x12, 0x34, 0x20, 0x12, 0x34]);
+
+        let mut input = Cursor::new(output);
+        let first: Header = input.read_bitfields()?;
+        assert_eq!(first, header());
+        assert_eq!(input.try_read_bitfields::<Header, 3>()?, header());
+        let err = input.read_bitfields::<Header, 3>().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        Ok(())
+    }
+
+    #[test]
+    fn try_read_bitfields_errors() {
+        let mut input = Cursor::new([0x21, 0x12, 0x34, 0x20]);
+        match input.try_read_bitfields::<Header, 3>() {
+            Err(BitfieldIoError::Decode(err)) => {
+                assert_eq!(err.field, "reserve");
+                assert_eq!(err.kind, BitfieldDecodeErrorKind::NonZeroReserve);
+            }
+            other => panic!("expected a decode error, got {:?}", other),
+        }
+        match input.try_read_bitfields::<Header, 3>() {
+            Err(BitfieldIoError::Io(err)) => {
+                assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof)
+            }
+            other => panic!("expected an io error, got {:?}", other),
+        }
+    }
+}
+
+mod embedded {
+    use super::*;
+    use bondrewd::embedded::{ReadBitfieldsExt, WriteBitfieldsExt};
+    use bondrewd::embedded_io::ReadExactError;
+
+    #[test]
+    fn read_and_write_bitfields() {
+        let mut buffer = [0u8; 4];
+        let mut output: &mut [u8] = &mut buffer;
+        output.write_bitfields(&header()).unwrap();
+        // write_all writes what fits before it fails.
+        assert!(output.write_bitfields(&header()).is_err());
+        assert_eq!(buffer, [0x20, 0x12, 0x34, 0x20]);
+
+        let mut input: &[u8] = &buffer;
+        assert_eq!(input.read_bitfields::<Header, 3>().unwrap(), header());
+        assert!(matches!(
+            input.read_bitfields::<Header, 3>(),
+            Err(ReadExactError::UnexpectedEof)
+        ));
+    }
+
+    #[test]
+    fn try_read_bitfields_errors() {
+        let mut input: &[u8] = &[0x2F, 0x12, 0x34, 0x20];
+        assert!(matches!(
+            input.try_read_bitfields::<Header, 3>(),
+            Err(BitfieldIoError::Decode(BitfieldDecodeError {
+                kind: BitfieldDecodeErrorKind::NonZeroReserve,
+                ..
+            }))
+        ));
+        assert!(matches!(
+            input.try_read_bitfields::<Header, 3>(),
+            Err(BitfieldIoError::Io(ReadExactError::UnexpectedEof))
+        ));
+    }
+}
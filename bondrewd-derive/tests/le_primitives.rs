@@ -26,7 +26,7 @@ fn le_into_bytes_simple() -> anyhow::Result<()> {
     assert_eq!(bytes[0], 0b010_11001);
     assert_eq!(bytes[1], 0b00100011);
     assert_eq!(bytes[2], 0b00000000);
-    assert_eq!(bytes[3], 0b000000_01);
+    assert_eq!(bytes[3], 0b0000_0001);
     assert_eq!(bytes[4], 0b10000100);
     assert_eq!(bytes[5], 0b1000_0100);
     // this last 4 bits here don't exist in the struct
@@ -59,7 +59,7 @@ struct SimpleWithFlip {
 fn le_into_bytes_simple_with_reverse() -> anyhow::Result<()> {
     let simple = SimpleWithFlip {
         one: false,
-        two: u16::MAX & 0b0000001111111111,
+        two: 0b0000001111111111,
         three: 0,
     };
     assert_eq!(SimpleWithFlip::BYTE_SIZE, 2);
@@ -95,7 +95,7 @@ struct SimpleWithReadFromBack {
 fn le_into_bytes_simple_with_read_from_back() -> anyhow::Result<()> {
     let simple = SimpleWithReadFromBack {
         one: false,
-        two: u16::MAX & 0b0000001111111111,
+        two: 0b0000001111111111,
         three: 0,
     };
     assert_eq!(SimpleWithReadFromBack::BYTE_SIZE, 2);
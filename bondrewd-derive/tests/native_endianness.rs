@@ -0,0 +1,195 @@
+use bondrewd::*;
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug, BitfieldEnum)]
+#[bondrewd_enum(u16)]
+enum Kind {
+    One,
+    Two,
+    Other(u16),
+}
+
+// the same structure in every byte order, native has to match one of the others on the host.
+macro_rules! matrix_struct {
+    ($name:ident, $endianness:literal) => {
+        #[derive(Bitfields, Clone, PartialEq, Debug)]
+        #[bondrewd(default_endianness = $endianness)]
+        struct $name {
+            #[bondrewd(bit_length = 3)]
+            flags: u8,
+            #[bondrewd(bit_length = 13)]
+            small: u16,
+            #[bondrewd(bit_length = 20)]
+            signed: i32,
+            wide: u64,
+            float: f32,
+            #[bondrewd(bit_length = 21)]
+            letter: char,
+            #[bondrewd(enum_primitive = "u16", bit_length = 16)]
+            kind: Kind,
+            #[bondrewd(element_bit_length = 12)]
+            samples: [i16; 3],
+            full: u128,
+        }
+
+        impl $name {
+            fn sample() -> Self {
+                Self {
+                    flags: 0b101,
+                    small: 0x1ABC,
+                    signed: -12_345,
+                    wide: 0x0123_4567_89AB_CDEF,
+                    float: -1.5,
+                    letter: 'ß',
+                    kind: Kind::Two,
+                    samples: [-1, 0x7FF, -0x800],
+                    full: 0x0011_2233_4455_6677_8899_AABB_CCDD_EEFF,
+                }
+            }
+        }
+    };
+}
+
+matrix_struct!(Native, "ne");
+matrix_struct!(Little, "le");
+matrix_struct!(Big, "be");
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Mixed {
+    #[bondrewd(bit_length = 4)]
+    head: u8,
+    #[bondrewd(endianness = "ne")]
+    native: u32,
+    big: u32,
+    #[bondrewd(endianness = "native", bit_length = 12)]
+    tail: i16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "ne", reverse)]
+struct NativeReversed {
+    one: u16,
+    #[bondrewd(bit_length = 12)]
+    two: i16,
+    #[bondrewd(bit_length = 4)]
+    three: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le", reverse)]
+struct LittleReversed {
+    one: u16,
+    #[bondrewd(bit_length = 12)]
+    two: i16,
+    #[bondrewd(bit_length = 4)]
+    three: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", reverse)]
+struct BigReversed {
+    one: u16,
+    #[bondrewd(bit_length = 12)]
+    two: i16,
+    #[bondrewd(bit_length = 4)]
+    three: u8,
+}
+
+fn host_bytes<const N: usize>(little: [u8; N], big: [u8; N]) -> [u8; N] {
+    if cfg!(target_endian = "little") {
+        little
+    } else {
+        big
+    }
+}
+
+#[test]
+fn native_matches_host_byte_order() {
+    assert_eq!(Native::BIT_SIZE, Little::BIT_SIZE);
+    let native = Native::sample().into_bytes();
+    let expected = host_bytes(Little::sample().into_bytes(), Big::sample().into_bytes());
+    assert_eq!(native, expected);
+    assert_ne!(Little::sample().into_bytes(), Big::sample().into_bytes());
+    assert_eq!(Native::from_bytes(native), Native::sample());
+}
+
+#[test]
+fn native_read_and_write() {
+    let mut bytes = Native::sample().into_bytes();
+    assert_eq!(Native::read_signed(&bytes), -12_345);
+    assert_eq!(Native::read_wide(&bytes), 0x0123_4567_89AB_CDEF);
+    assert_eq!(Native::read_letter(&bytes), 'ß');
+    assert_eq!(Native::read_kind(&bytes), Kind::Two);
+    assert_eq!(Native::read_samples(&bytes), [-1, 0x7FF, -0x800]);
+    Native::write_signed(&mut bytes, 0x7_FFFF);
+    Native::write_float(&mut bytes, 2.25);
+    Native::write_kind(&mut bytes, Kind::Other(77));
+    assert_eq!(Native::read_signed(&bytes), 0x7_FFFF);
+    assert_eq!(Native::read_float(&bytes), 2.25);
+    assert_eq!(Native::read_kind(&bytes), Kind::Other(77));
+    assert_eq!(Native::read_small(&bytes), 0x1ABC);
+    assert_eq!(Native::read_wide(&bytes), 0x0123_4567_89AB_CDEF);
+    let mut little = Little::sample();
+    little.signed = 0x7_FFFF;
+    little.float = 2.25;
+    little.kind = Kind::Other(77);
+    let mut big = Big::sample();
+    big.signed = 0x7_FFFF;
+    big.float = 2.25;
+    big.kind = Kind::Other(77);
+    assert_eq!(bytes, host_bytes(little.into_bytes(), big.into_bytes()));
+}
+
+#[test]
+fn native_slice_and_try_fns() -> anyhow::Result<()> {
+    let bytes = Native::sample().into_bytes();
+    assert_eq!(Native::read_slice_signed(&bytes)?, -12_345);
+    assert_eq!(Native::read_slice_full(&bytes)?, Native::sample().full);
+    let checked = Native::check_slice(&bytes)?;
+    assert_eq!(checked.read_samples(), [-1, 0x7FF, -0x800]);
+    assert_eq!(Native::try_from_bytes(bytes)?, Native::sample());
+    Ok(())
+}
+
+#[test]
+fn native_field_in_big_endian_structure() {
+    let value = Mixed {
+        head: 0xA,
+        native: 0x0102_0304,
+        big: 0x0506_0708,
+        tail: -2,
+    };
+    let bytes = value.clone().into_bytes();
+    assert_eq!(Mixed::from_bytes(bytes), value);
+    // the big endian field is 4 bits into byte 4.
+    assert_eq!(bytes[4] & 0x0F, 0x0);
+    assert_eq!(bytes[5], 0x50);
+    let native = ((u64::from_be_bytes([0, 0, 0, bytes[0], bytes[1], bytes[2], bytes[3], bytes[4]])
+        >> 4)
+        & 0xFFFF_FFFF) as u32;
+    assert_eq!(native, u32::from_be_bytes(0x0102_0304u32.to_ne_bytes()));
+    assert_eq!(Mixed::read_tail(&bytes), -2);
+}
+
+#[test]
+fn native_reversed_structures() {
+    let native = NativeReversed {
+        one: 0x1234,
+        two: -3,
+        three: 0x5,
+    };
+    let bytes = native.clone().into_bytes();
+    let little = LittleReversed {
+        one: 0x1234,
+        two: -3,
+        three: 0x5,
+    };
+    let big = BigReversed {
+        one: 0x1234,
+        two: -3,
+        three: 0x5,
+    };
+    assert_eq!(bytes, host_bytes(little.into_bytes(), big.into_bytes()));
+    assert_eq!(NativeReversed::from_bytes(bytes), native);
+    assert_eq!(NativeReversed::read_two(&bytes), -3);
+}
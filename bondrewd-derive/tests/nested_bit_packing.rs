@@ -0,0 +1,172 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct Flags {
+    ack: bool,
+    retry: bool,
+    last: bool,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct Reading {
+    #[bondrewd(bit_length = 4)]
+    channel: u8,
+    value: u8,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be", enforce_bits = 16)]
+struct Header {
+    #[bondrewd(bit_length = 3)]
+    flags: Flags,
+    #[bondrewd(bit_length = 2)]
+    kind: u8,
+    #[bondrewd(element_bit_length = 3)]
+    history: [Flags; 3],
+    #[bondrewd(bit_length = 2)]
+    tail: u8,
+}
+
+// the same layout as Header, the structures take the BIT_SIZE of their impls by default.
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be", enforce_bits = 16)]
+struct DefaultHeader {
+    flags: Flags,
+    #[bondrewd(bit_length = 2)]
+    kind: u8,
+    history: [Flags; 3],
+    #[bondrewd(bit_length = 2)]
+    tail: u8,
+}
+
+fn flags(ack: bool, retry: bool, last: bool) -> Flags {
+    Flags { ack, retry, last }
+}
+
+#[test]
+fn small_structs_use_their_bits() {
+    let header = Header {
+        flags: flags(true, true, false),
+        kind: 1,
+        history: [
+            flags(true, false, true),
+            flags(false, true, false),
+            flags(false, false, true),
+        ],
+        tail: 3,
+    };
+    let bytes = header.clone().into_bytes();
+    assert_eq!(bytes, [0b1100_1101, 0b0100_0111]);
+    assert_eq!(Header::from_bytes(bytes), header);
+    assert_eq!(Header::read_history(&bytes)[1], flags(false, true, false));
+}
+
+#[test]
+fn small_structs_default_to_their_bit_size() {
+    let header = DefaultHeader {
+        flags: flags(true, true, false),
+        kind: 1,
+        history: [
+            flags(true, false, true),
+            flags(false, true, false),
+            flags(false, false, true),
+        ],
+        tail: 3,
+    };
+    assert_eq!(DefaultHeader::BYTE_SIZE, 2);
+    let bytes = header.clone().into_bytes();
+    assert_eq!(bytes, [0b1100_1101, 0b0100_0111]);
+    assert_eq!(DefaultHeader::from_bytes(bytes), header);
+    let mut bytes = [0xFF; 2];
+    DefaultHeader::write_flags(&mut bytes, flags(false, false, false));
+    assert_eq!(bytes, [0b0001_1111, 0xFF]);
+    assert_eq!(DefaultHeader::read_tail(&bytes), 3);
+}
+
+#[test]
+fn small_struct_writes_keep_neighbours() {
+    let mut bytes = [0xFF; 2];
+    Header::write_flags(&mut bytes, flags(false, false, false));
+    assert_eq!(bytes, [0b0001_1111, 0xFF]);
+    Header::write_history(
+        &mut bytes,
+        [
+            flags(false, false, false),
+            flags(false, false, false),
+            flags(false, false, false),
+        ],
+    );
+    assert_eq!(bytes, [0b0001_1000, 0b0000_0011]);
+}
+
+macro_rules! truncated_struct {
+    ($name:ident, $($attrs:tt)*) => {
+        #[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+        #[bondrewd($($attrs)*)]
+        struct $name {
+            #[bondrewd(bit_length = 3)]
+            before: u8,
+            // Reading is 2 bytes but only uses 12 bits.
+            #[bondrewd(bit_length = 12)]
+            reading: Reading,
+            #[bondrewd(bit_length = 9)]
+            after: u16,
+        }
+    };
+}
+
+truncated_struct!(TruncatedBe, default_endianness = "be");
+truncated_struct!(TruncatedLe, default_endianness = "le");
+truncated_struct!(TruncatedLsb0, default_endianness = "be", read_from = "lsb0");
+truncated_struct!(TruncatedReverse, default_endianness = "be", reverse);
+
+macro_rules! check_truncated_write {
+    ($name:ident) => {{
+        let value = $name {
+            before: 5,
+            reading: Reading {
+                channel: 0xF,
+                value: 0xFF,
+            },
+            after: 0x1FF,
+        };
+        let mut bytes = value.clone().into_bytes();
+        assert_eq!($name::from_bytes(bytes), value);
+        // the bytes of Reading are longer than the field, the bits after it belong to `after`.
+        $name::write_reading(
+            &mut bytes,
+            Reading {
+                channel: 0,
+                value: 0,
+            },
+        );
+        assert_eq!($name::read_before(&bytes), 5);
+        assert_eq!($name::read_after(&bytes), 0x1FF);
+        $name::write_reading(&mut bytes, value.reading.clone());
+        $name::write_before(&mut bytes, 0);
+        $name::write_after(&mut bytes, 0);
+        assert_eq!($name::read_reading(&bytes), value.reading);
+    }};
+}
+
+#[test]
+fn truncated_struct_write_be() {
+    check_truncated_write!(TruncatedBe);
+}
+
+#[test]
+fn truncated_struct_write_le() {
+    check_truncated_write!(TruncatedLe);
+}
+
+#[test]
+fn truncated_struct_write_lsb0() {
+    check_truncated_write!(TruncatedLsb0);
+}
+
+#[test]
+fn truncated_struct_write_reverse() {
+    check_truncated_write!(TruncatedReverse);
+}
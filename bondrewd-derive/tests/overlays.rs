@@ -0,0 +1,121 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug, Default)]
+#[bondrewd(default_endianness = "be")]
+struct Pair {
+    high: u8,
+    low: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", enforce_bytes = 4)]
+struct Register {
+    mode: u8,
+    raw: u16,
+    #[bondrewd(alias_of = "raw")]
+    signed: i16,
+    #[bondrewd(alias_of = "raw", struct_size = 2)]
+    pair: Pair,
+    #[bondrewd(alias_of = "raw", element_bit_length = 4)]
+    nibbles: [u8; 4],
+    #[bondrewd(overlay, bits = 8..12)]
+    channel: u8,
+    #[bondrewd(overlay, bits = 23..24)]
+    ready: bool,
+    // overlays do not move the fields after them.
+    tail: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", runtime_endianness)]
+struct Runtime {
+    value: u16,
+    #[bondrewd(alias_of = "value")]
+    signed: i16,
+}
+
+fn register() -> Register {
+    Register {
+        mode: 0x12,
+        raw: 0xFFFE,
+        signed: 0,
+        pair: Pair::default(),
+        nibbles: [0; 4],
+        channel: 0,
+        ready: false,
+        tail: 0x34,
+    }
+}
+
+#[test]
+fn overlays_read_the_same_bits() {
+    assert_eq!(Register::BIT_SIZE, 32);
+    let bytes = register().into_bytes();
+    assert_eq!(bytes, [0x12, 0xFF, 0xFE, 0x34]);
+    assert_eq!(Register::read_signed(&bytes), -2);
+    assert_eq!(
+        Register::read_pair(&bytes),
+        Pair {
+            high: 0xFF,
+            low: 0xFE
+        }
+    );
+    assert_eq!(Register::read_nibbles(&bytes), [0xF, 0xF, 0xF, 0xE]);
+    assert_eq!(Register::read_channel(&bytes), 0xF);
+    assert!(!Register::read_ready(&bytes));
+    assert_eq!(Register::read_tail(&bytes), 0x34);
+}
+
+#[test]
+fn overlays_write_the_same_bits() {
+    let mut bytes = register().into_bytes();
+    Register::write_signed(&mut bytes, -0x1235);
+    assert_eq!(Register::read_raw(&bytes), 0xEDCB);
+    Register::write_channel(&mut bytes, 0x1);
+    Register::write_ready(&mut bytes, true);
+    assert_eq!(Register::read_raw(&bytes), 0x1DCB);
+    Register::write_pair(&mut bytes, Pair { high: 1, low: 2 });
+    assert_eq!(bytes, [0x12, 0x01, 0x02, 0x34]);
+    Register::write_nibbles(&mut bytes, [0xA, 0xB, 0xC, 0xD]);
+    assert_eq!(bytes, [0x12, 0xAB, 0xCD, 0x34]);
+}
+
+#[test]
+fn overlays_are_not_in_from_and_into_bytes() -> anyhow::Result<()> {
+    // only the fields owning the bits are written.
+    let mut value = register();
+    value.signed = 5;
+    value.channel = 0;
+    value.pair = Pair { high: 1, low: 1 };
+    assert_eq!(value.into_bytes(), [0x12, 0xFF, 0xFE, 0x34]);
+
+    let bytes = [0x12, 0xFF, 0xFE, 0x34];
+    assert_eq!(Register::from_bytes(bytes), register());
+    assert_eq!(Register::try_from_bytes(bytes)?, register());
+    assert_eq!(Register::try_read_signed(&bytes)?, -2);
+    Ok(())
+}
+
+#[test]
+fn overlays_in_views_and_paths() {
+    let mut bytes = register().into_bytes();
+    let mut view = Register::view_mut(&mut bytes);
+    assert_eq!(view.read_signed(), -2);
+    view.write_channel(0x7);
+    assert_eq!(view.read_raw(), 0x7FFE);
+    assert_eq!(Register::paths().pair().low().read(&bytes), 0xFE);
+    Register::paths().signed().write(&mut bytes, -1);
+    assert_eq!(Register::read_raw(&bytes), 0xFFFF);
+}
+
+#[test]
+fn overlays_with_runtime_endianness() {
+    let value = Runtime {
+        value: 0xFFFD,
+        signed: 0,
+    };
+    let le = value.clone().into_bytes_le();
+    assert_eq!(le, [0xFD, 0xFF]);
+    assert_eq!(Runtime::read_signed_le(&le), -3);
+    assert_eq!(Runtime::from_bytes_le(le), value);
+}
@@ -0,0 +1,133 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct Status {
+    mtm1: bool,
+    mtm2: bool,
+    #[bondrewd(bit_length = 6)]
+    temperature: u8,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be", enforce_bits = 27)]
+struct Sensor {
+    #[bondrewd(bit_length = 3)]
+    id: u8,
+    #[bondrewd(struct_size = 1)]
+    status: Status,
+    reading: u16,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct Magnetometers {
+    #[bondrewd(bit_length = 5)]
+    count: u8,
+    #[bondrewd(struct_size = 4, bit_length = 27)]
+    first: Sensor,
+    #[bondrewd(struct_size = 4, bit_length = 27)]
+    second: Sensor,
+    #[bondrewd(struct_size = 1)]
+    status: Status,
+}
+
+fn sensor(id: u8, mtm1: bool, temperature: u8, reading: u16) -> Sensor {
+    Sensor {
+        id,
+        status: Status {
+            mtm1,
+            mtm2: !mtm1,
+            temperature,
+        },
+        reading,
+    }
+}
+
+fn magnetometers() -> Magnetometers {
+    Magnetometers {
+        count: 2,
+        first: sensor(1, true, 20, 0x1234),
+        second: sensor(6, false, 63, 0xFFFF),
+        status: Status {
+            mtm1: false,
+            mtm2: true,
+            temperature: 5,
+        },
+    }
+}
+
+#[test]
+fn paths_read_nested_fields() {
+    let bytes = magnetometers().into_bytes();
+    let paths = Magnetometers::paths();
+    assert_eq!(paths.count().read(&bytes), 2);
+    assert!(!paths.status().mtm1().read(&bytes));
+    assert_eq!(paths.status().temperature().read(&bytes), 5);
+    assert!(paths.first().status().mtm1().read(&bytes));
+    assert_eq!(paths.first().status().temperature().read(&bytes), 20);
+    assert_eq!(paths.second().id().read(&bytes), 6);
+    assert!(paths.second().status().mtm2().read(&bytes));
+    assert_eq!(paths.second().reading().read(&bytes), 0xFFFF);
+    assert_eq!(
+        paths.second().status().read(&bytes),
+        magnetometers().second.status
+    );
+    assert_eq!(paths.first().read(&bytes), magnetometers().first);
+}
+
+#[test]
+fn paths_write_nested_fields() {
+    let mut bytes = magnetometers().into_bytes();
+    let paths = Magnetometers::paths();
+    paths.second().status().temperature().write(&mut bytes, 7);
+    paths.first().status().mtm1().write(&mut bytes, false);
+    paths.first().reading().write(&mut bytes, 0xBEEF);
+    let mut expected = magnetometers();
+    expected.second.status.temperature = 7;
+    expected.first.status.mtm1 = false;
+    expected.first.reading = 0xBEEF;
+    // everything around the written fields is left as it was.
+    assert_eq!(Magnetometers::from_bytes(bytes), expected);
+    let status = Status {
+        mtm1: true,
+        mtm2: true,
+        temperature: 1,
+    };
+    paths.second().status().write(&mut bytes, status.clone());
+    expected.second.status = status;
+    assert_eq!(Magnetometers::from_bytes(bytes), expected);
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "le", reverse)]
+struct Reversed {
+    #[bondrewd(bit_length = 4)]
+    flags: u8,
+    #[bondrewd(struct_size = 1)]
+    status: Status,
+    #[bondrewd(bit_length = 12)]
+    value: u16,
+}
+
+#[test]
+fn paths_reversed_little_endian() {
+    let value = Reversed {
+        flags: 0b1010,
+        status: Status {
+            mtm1: true,
+            mtm2: false,
+            temperature: 33,
+        },
+        value: 0xABC,
+    };
+    let mut bytes = value.clone().into_bytes();
+    let paths = Reversed::paths();
+    assert_eq!(paths.status().temperature().read(&bytes), 33);
+    assert!(paths.status().mtm1().read(&bytes));
+    assert_eq!(paths.value().read(&bytes), 0xABC);
+    paths.status().mtm2().write(&mut bytes, true);
+    let mut expected = value;
+    expected.status.mtm2 = true;
+    assert_eq!(Reversed::from_bytes(bytes), expected);
+}
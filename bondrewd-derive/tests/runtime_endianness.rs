@@ -0,0 +1,111 @@
+use bondrewd::*;
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug, BitfieldEnum)]
+#[bondrewd_enum(u16)]
+enum Mode {
+    Idle,
+    Run,
+    Fault,
+    Other(u16),
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", runtime_endianness)]
+struct Report {
+    #[bondrewd(bit_length = 4)]
+    version: u8,
+    #[bondrewd(bit_length = 12)]
+    length: u16,
+    sequence: u32,
+    // always big endian no matter which functions are used.
+    #[bondrewd(endianness = "be")]
+    crc: u16,
+    #[bondrewd(enum_primitive = "u16", bit_length = 16)]
+    mode: Mode,
+    #[bondrewd(element_bit_length = 16)]
+    samples: [i16; 2],
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le", runtime_endianness, reverse)]
+struct Reversed {
+    one: u16,
+    #[bondrewd(bit_length = 4)]
+    two: u8,
+    #[bondrewd(bit_length = 12)]
+    three: i16,
+}
+
+fn report() -> Report {
+    Report {
+        version: 0x3,
+        length: 0x456,
+        sequence: 0x0102_0304,
+        crc: 0xABCD,
+        mode: Mode::Fault,
+        samples: [-2, 0x1234],
+    }
+}
+
+#[test]
+fn le_and_be_from_one_definition() {
+    let be = report().into_bytes_be();
+    assert_eq!(be, report().into_bytes());
+    assert_eq!(
+        be,
+        [0x34, 0x56, 0x01, 0x02, 0x03, 0x04, 0xAB, 0xCD, 0x00, 0x02, 0xFF, 0xFE, 0x12, 0x34]
+    );
+    let le = report().into_bytes_le();
+    assert_eq!(&le[2..6], &[0x04, 0x03, 0x02, 0x01]);
+    assert_eq!(&le[6..8], &[0xAB, 0xCD]);
+    assert_eq!(&le[8..10], &[0x02, 0x00]);
+    assert_eq!(&le[10..14], &[0xFE, 0xFF, 0x34, 0x12]);
+    assert_eq!(Report::from_bytes_le(le), report());
+    assert_eq!(Report::from_bytes_be(be), report());
+    assert_ne!(Report::from_bytes_le(be), report());
+}
+
+#[test]
+fn read_and_write_with_byte_order() {
+    let mut le = report().into_bytes_le();
+    assert_eq!(Report::read_sequence_le(&le), 0x0102_0304);
+    assert_eq!(Report::read_crc_le(&le), 0xABCD);
+    assert_eq!(Report::read_samples_le(&le), [-2, 0x1234]);
+    Report::write_length_le(&mut le, 0x789);
+    Report::write_mode_le(&mut le, Mode::Run);
+    assert_eq!(Report::read_length_le(&le), 0x789);
+    assert_eq!(Report::read_mode_le(&le), Mode::Run);
+    assert_eq!(Report::read_version_le(&le), 0x3);
+    let mut be = report().into_bytes_be();
+    Report::write_sequence_be(&mut be, 7);
+    assert_eq!(Report::read_sequence(&be), 7);
+}
+
+#[test]
+fn byte_order_from_header_flag() {
+    fn decode(little: bool, bytes: [u8; 14]) -> Report {
+        if little {
+            Report::from_bytes_le(bytes)
+        } else {
+            Report::from_bytes_be(bytes)
+        }
+    }
+    assert_eq!(decode(true, report().into_bytes_le()), report());
+    assert_eq!(decode(false, report().into_bytes_be()), report());
+}
+
+#[test]
+fn reversed_structures() {
+    let value = Reversed {
+        one: 0x1234,
+        two: 0x5,
+        three: -3,
+    };
+    let le = value.clone().into_bytes_le();
+    assert_eq!(le, value.clone().into_bytes());
+    assert_eq!(Reversed::from_bytes_le(le), value);
+    let be = value.clone().into_bytes_be();
+    assert_eq!(Reversed::from_bytes_be(be), value);
+    assert_eq!(Reversed::read_three_be(&be), -3);
+    assert_eq!(&be[2..4], &[0x34, 0x12]);
+}
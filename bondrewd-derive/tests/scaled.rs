@@ -0,0 +1,105 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, PartialEq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Telemetry {
+    #[bondrewd(bit_length = 14, scale = 0.01, offset = -40.0)]
+    temperature: f32,
+    #[bondrewd(bit_length = 10, scale = 0.5)]
+    voltage: f64,
+    #[bondrewd(q = "Q3.12")]
+    gain: f32,
+    #[bondrewd(q = "UQ2.6")]
+    ratio: f64,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Debug)]
+#[bondrewd(default_endianness = "le")]
+struct Small {
+    #[bondrewd(q = "Q1.2")]
+    tiny: f32,
+    #[bondrewd(bit_length = 12, offset = 100)]
+    counter: f64,
+    #[bondrewd(q = "Q7.12")]
+    wide: f32,
+}
+
+#[test]
+fn scale_and_offset() {
+    let value = Telemetry {
+        temperature: 21.5,
+        voltage: 12.5,
+        gain: -1.25,
+        ratio: 0.75,
+    };
+    assert_eq!(Telemetry::BIT_SIZE, 48);
+    let bytes = value.clone().into_bytes();
+    // 21.5 + 40 = 61.5 / 0.01 = 6150 raw, 12.5 / 0.5 = 25 raw.
+    assert_eq!(Telemetry::read_temperature(&bytes), 6150.0 * 0.01 - 40.0);
+    assert_eq!(bytes[0], (6150u16 >> 6) as u8);
+    assert_eq!(bytes[1], ((6150u16 << 2) as u8) | (25u16 >> 8) as u8);
+    assert_eq!(bytes[2], 25);
+    // -1.25 in Q3.12 is -5120.
+    assert_eq!(&bytes[3..5], &(-5120i16).to_be_bytes());
+    // 0.75 in UQ2.6 is 48.
+    assert_eq!(bytes[5], 48);
+    let decoded = Telemetry::from_bytes(bytes);
+    assert!((decoded.temperature - 21.5).abs() < 0.005);
+    assert_eq!(decoded.voltage, 12.5);
+    assert_eq!(decoded.gain, -1.25);
+    assert_eq!(decoded.ratio, 0.75);
+}
+
+#[test]
+fn saturate_on_overflow() {
+    let value = Telemetry {
+        temperature: 1000.0,
+        voltage: -3.0,
+        gain: 9.0,
+        ratio: f64::NAN,
+    };
+    let bytes = value.into_bytes();
+    assert_eq!(
+        Telemetry::read_temperature(&bytes),
+        (16383.0f64 * 0.01 - 40.0) as f32
+    );
+    assert_eq!(Telemetry::read_voltage(&bytes), 0.0);
+    assert_eq!(Telemetry::read_gain(&bytes), 32767.0 / 4096.0);
+    assert_eq!(Telemetry::read_ratio(&bytes), 0.0);
+    let mut bytes = bytes;
+    Telemetry::write_gain(&mut bytes, -100.0);
+    assert_eq!(Telemetry::read_gain(&bytes), -8.0);
+    assert_eq!(Telemetry::read_ratio(&bytes), 0.0);
+}
+
+#[test]
+fn rounds_to_nearest() {
+    let mut bytes = [0u8; 6];
+    Telemetry::write_voltage(&mut bytes, 1.74);
+    assert_eq!(Telemetry::read_voltage(&bytes), 1.5);
+    Telemetry::write_voltage(&mut bytes, 1.75);
+    assert_eq!(Telemetry::read_voltage(&bytes), 2.0);
+    Telemetry::write_gain(&mut bytes, -0.000_01);
+    assert_eq!(Telemetry::read_gain(&bytes), 0.0);
+}
+
+#[test]
+fn little_endian_fixed_point() {
+    let value = Small {
+        tiny: -0.75,
+        counter: 4095.0,
+        wide: -100.5,
+    };
+    assert_eq!(Small::BIT_SIZE, 36);
+    let bytes = value.clone().into_bytes();
+    assert_eq!(Small::from_bytes(bytes), value);
+    assert_eq!(Small::read_tiny(&bytes), -0.75);
+    assert_eq!(Small::read_counter(&bytes), 4095.0);
+    assert_eq!(Small::read_wide(&bytes), -100.5);
+    let mut bytes = bytes;
+    Small::write_tiny(&mut bytes, 0.5);
+    Small::write_counter(&mut bytes, 50.0);
+    assert_eq!(Small::read_tiny(&bytes), 0.5);
+    assert_eq!(Small::read_counter(&bytes), 100.0);
+    assert_eq!(Small::read_wide(&bytes), -100.5);
+}
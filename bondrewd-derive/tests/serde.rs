@@ -0,0 +1,137 @@
+use bondrewd::*;
+use serde::{Deserialize, Serialize};
+
+const ID_BITS: usize = 12;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", serde_bytes)]
+struct Header {
+    #[bondrewd(bit_length = 4)]
+    version: u8,
+    #[bondrewd(bit_length = 12)]
+    id: u16,
+    length: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", serde_bytes)]
+struct ConstHeader {
+    #[bondrewd(bit_length = 4)]
+    version: u8,
+    #[bondrewd(bit_length = ID_BITS)]
+    id: u16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2, serde_bytes)]
+enum Command {
+    Noop,
+    Set {
+        #[bondrewd(bit_length = 6)]
+        value: u8,
+    },
+}
+
+#[derive(BitfieldEnum, Clone, PartialEq, Eq, Debug)]
+#[bondrewd_enum(u8, strict, serde_primitive)]
+enum Mode {
+    Safe,
+    Nominal,
+    Science = 7,
+}
+
+#[derive(BitfieldEnum, Clone, PartialEq, Eq, Debug)]
+enum Link {
+    Down,
+    Up,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Status {
+    #[bondrewd(bit_length = 3)]
+    counter: u8,
+    #[bondrewd(bit_length = 5)]
+    flags: u8,
+}
+
+// types without the derive attributes can use the adapters directly.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Config {
+    header: Header,
+    mode: Mode,
+    #[serde(with = "bondrewd::packed_serde::as_bytes")]
+    status: Status,
+    #[serde(with = "bondrewd::packed_serde::as_primitive")]
+    link: Link,
+}
+
+#[test]
+fn structures_as_bytes() -> anyhow::Result<()> {
+    let header = Header {
+        version: 1,
+        id: 0x234,
+        length: 0x56,
+    };
+    let json = serde_json::to_string(&header)?;
+    assert_eq!(json, "[18,52,86]");
+    assert_eq!(serde_json::from_str::<Header>(&json)?, header);
+
+    let const_header = ConstHeader {
+        version: 0xA,
+        id: 0xBCD,
+    };
+    let json = serde_json::to_string(&const_header)?;
+    assert_eq!(json, "[171,205]");
+    assert_eq!(serde_json::from_str::<ConstHeader>(&json)?, const_header);
+
+    let command = Command::Set { value: 5 };
+    let json = serde_json::to_string(&command)?;
+    assert_eq!(json, "[69]");
+    assert_eq!(serde_json::from_str::<Command>(&json)?, command);
+    Ok(())
+}
+
+#[test]
+fn wrong_amount_of_bytes() {
+    let err = serde_json::from_str::<Header>("[18,52]").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid length 2, expected 3 bytes at line 1 column 7"
+    );
+    assert!(serde_json::from_str::<Header>("[18,52,86,0]").is_err());
+    assert!(serde_json::from_str::<Header>("[18,52,256]").is_err());
+}
+
+#[test]
+fn enums_as_primitives() -> anyhow::Result<()> {
+    assert_eq!(serde_json::to_string(&Mode::Science)?, "7");
+    assert_eq!(serde_json::from_str::<Mode>("1")?, Mode::Nominal);
+    let err = serde_json::from_str::<Mode>("3").unwrap_err();
+    assert_eq!(err.to_string(), "3 does not match an enum variant.");
+    Ok(())
+}
+
+#[test]
+fn adapters_on_fields() -> anyhow::Result<()> {
+    let config = Config {
+        header: Header {
+            version: 2,
+            id: 0x001,
+            length: 4,
+        },
+        mode: Mode::Safe,
+        status: Status {
+            counter: 5,
+            flags: 0x11,
+        },
+        link: Link::Up,
+    };
+    let json = serde_json::to_string(&config)?;
+    assert_eq!(
+        json,
+        r#"{"header":[32,1,4],"mode":0,"status":[177],"link":1}"#
+    );
+    assert_eq!(serde_json::from_str::<Config>(&json)?, config);
+    Ok(())
+}
@@ -227,3 +227,67 @@ fn struct_within_one_byte_shift_required() -> anyhow::Result<()> {
     assert_eq!(simple, new_simple);
     Ok(())
 }
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct StructSizeFromBitLength {
+    #[bondrewd(bit_length = 3)]
+    one: u8,
+    // struct_size is the bytes needed for 5 bits.
+    #[bondrewd(bit_length = 5)]
+    two: SmallStruct,
+    three: u8,
+}
+#[test]
+fn struct_size_from_bit_length() -> anyhow::Result<()> {
+    let simple = StructSizeFromBitLength {
+        one: 2,
+        two: SmallStruct {
+            one: true,
+            two: false,
+            three: true,
+            four: false,
+            five: true,
+        },
+        three: 10,
+    };
+    assert_eq!(StructSizeFromBitLength::BYTE_SIZE, 2);
+    let bytes = simple.clone().into_bytes();
+    assert_eq!(bytes, [0b01010101, 0b00001010]);
+    assert_eq!(simple.two, StructSizeFromBitLength::read_two(&bytes));
+    let new_simple = StructSizeFromBitLength::from_bytes(bytes);
+    assert_eq!(simple, new_simple);
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct StructSizeFromTrait {
+    // no attributes, the size comes from the Bitfields impl of SmallStruct.
+    two: SmallStruct,
+    #[bondrewd(bit_length = 3)]
+    one: u8,
+    three: u8,
+}
+#[test]
+fn struct_size_from_trait() -> anyhow::Result<()> {
+    let simple = StructSizeFromTrait {
+        two: SmallStruct {
+            one: true,
+            two: false,
+            three: true,
+            four: false,
+            five: true,
+        },
+        one: 2,
+        three: 10,
+    };
+    assert_eq!(StructSizeFromTrait::BYTE_SIZE, 2);
+    assert_eq!(StructSizeFromTrait::BIT_SIZE, 16);
+    let bytes = simple.clone().into_bytes();
+    assert_eq!(bytes, [0b10101010, 0b00001010]);
+    assert_eq!(simple.two, StructSizeFromTrait::read_two(&bytes));
+    let new_simple = StructSizeFromTrait::from_bytes(bytes);
+    assert_eq!(simple, new_simple);
+    Ok(())
+}
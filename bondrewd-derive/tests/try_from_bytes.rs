@@ -0,0 +1,280 @@
+use bondrewd::*;
+
+#[derive(BitfieldEnum, Clone, Debug, PartialEq, Eq)]
+enum Mode {
+    Off,
+    Idle,
+    Active,
+}
+
+#[derive(BitfieldEnum, Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    Telemetry,
+    Command,
+    #[bondrewd_enum(invalid)]
+    Unknown,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be", enforce_bytes = 4)]
+struct Packet {
+    #[bondrewd(enum_primitive = "u8", bit_length = 2)]
+    mode: Mode,
+    #[bondrewd(enum_primitive = "u8", bit_length = 2)]
+    kind: Kind,
+    #[bondrewd(enum_primitive = "u8", bit_length = 2, strict)]
+    strict_kind: Kind,
+    #[bondrewd(bit_length = 2, reserve)]
+    reserve: u8,
+    #[bondrewd(bit_length = 21)]
+    letter: char,
+    flag: bool,
+    #[bondrewd(bit_length = 2, reserve)]
+    tail: u8,
+}
+
+#[test]
+fn try_from_bytes_valid() -> anyhow::Result<()> {
+    let packet = Packet {
+        mode: Mode::Active,
+        kind: Kind::Command,
+        strict_kind: Kind::Telemetry,
+        reserve: 0,
+        letter: 'b',
+        flag: true,
+        tail: 0,
+    };
+    let bytes = packet.clone().into_bytes();
+    assert_eq!(Packet::try_from_bytes(bytes)?, packet);
+    Ok(())
+}
+
+#[test]
+fn try_from_bytes_invalid_enum() {
+    // mode only has 3 variants and no marked catch variant, 3 would land in Active.
+    let bytes = [0b1100_0000, 0, 0, 0];
+    assert_eq!(Packet::from_bytes(bytes).mode, Mode::Active);
+    assert_eq!(
+        Packet::try_from_bytes(bytes),
+        Err(BitfieldDecodeError {
+            field: "mode".into(),
+            bits: 0..2,
+            kind: BitfieldDecodeErrorKind::InvalidEnum,
+        })
+    );
+    // kind has a marked catch variant so 3 is fine unless the field is strict.
+    let bytes = [0b0011_0000, 0, 0, 0];
+    assert_eq!(Packet::try_from_bytes(bytes).unwrap().kind, Kind::Unknown);
+    let bytes = [0b0000_1100, 0, 0, 0];
+    assert_eq!(
+        Packet::try_from_bytes(bytes),
+        Err(BitfieldDecodeError {
+            field: "strict_kind".into(),
+            bits: 4..6,
+            kind: BitfieldDecodeErrorKind::InvalidEnum,
+        })
+    );
+    // the value of the catch variant itself is still valid for strict fields.
+    let bytes = [0b0000_1000, 0, 0, 0];
+    assert_eq!(
+        Packet::try_from_bytes(bytes).unwrap().strict_kind,
+        Kind::Unknown
+    );
+}
+
+#[test]
+fn try_from_bytes_nonzero_reserve() {
+    let bytes = [0b0000_0010, 0, 0, 0];
+    assert_eq!(
+        Packet::try_from_bytes(bytes),
+        Err(BitfieldDecodeError {
+            field: "reserve".into(),
+            bits: 6..8,
+            kind: BitfieldDecodeErrorKind::NonZeroReserve,
+        })
+    );
+    let bytes = [0, 0, 0, 0b0000_0001];
+    let err = Packet::try_from_bytes(bytes).unwrap_err();
+    assert_eq!(err.field, "tail");
+    assert_eq!(err.bits, 30..32);
+    assert_eq!(err.kind, BitfieldDecodeErrorKind::NonZeroReserve);
+}
+
+#[test]
+fn try_from_bytes_invalid_char() {
+    // 0xD800 is a surrogate and not a valid char.
+    let mut bytes = Packet {
+        mode: Mode::Off,
+        kind: Kind::Telemetry,
+        strict_kind: Kind::Telemetry,
+        reserve: 0,
+        letter: 'a',
+        flag: false,
+        tail: 0,
+    }
+    .into_bytes();
+    Packet::write_letter(&mut bytes, 'a');
+    assert!(Packet::try_from_bytes(bytes).is_ok());
+    bytes[1] = 0b0000_0110;
+    bytes[2] = 0b1100_0000;
+    bytes[3] = 0;
+    assert_eq!(Packet::from_bytes(bytes).letter, '�');
+    assert_eq!(
+        Packet::try_from_bytes(bytes),
+        Err(BitfieldDecodeError {
+            field: "letter".into(),
+            bits: 8..29,
+            kind: BitfieldDecodeErrorKind::InvalidChar,
+        })
+    );
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be", fill_bytes = 2)]
+struct Modes {
+    #[bondrewd(enum_primitive = "u8", element_bit_length = 2)]
+    modes: [Mode; 3],
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be")]
+struct Outer {
+    #[bondrewd(struct_size = 2)]
+    inner: Modes,
+    id: u8,
+}
+
+#[test]
+fn try_from_bytes_arrays_fill_and_nested() {
+    let outer = Outer {
+        inner: Modes {
+            modes: [Mode::Idle, Mode::Off, Mode::Active],
+        },
+        id: 7,
+    };
+    let bytes = outer.clone().into_bytes();
+    assert_eq!(Outer::try_from_bytes(bytes).unwrap(), outer);
+    assert_eq!(
+        Modes::try_from_bytes([0b0011_0000, 0]),
+        Err(BitfieldDecodeError {
+            field: "modes".into(),
+            bits: 2..4,
+            kind: BitfieldDecodeErrorKind::InvalidEnum,
+        })
+    );
+    assert_eq!(
+        Outer::try_from_bytes([0, 0b1000_0000, 1]),
+        Err(BitfieldDecodeError {
+            field: "inner.bondrewd_fill_bits".into(),
+            bits: 6..16,
+            kind: BitfieldDecodeErrorKind::NonZeroReserve,
+        })
+    );
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be", reverse)]
+struct Reversed {
+    one: u8,
+    #[bondrewd(bit_length = 4, reserve)]
+    reserve: u8,
+    #[bondrewd(bit_length = 4)]
+    two: u8,
+}
+
+#[test]
+fn try_from_bytes_reserve_with_reverse() {
+    let bytes = Reversed {
+        one: 0xFF,
+        reserve: 0,
+        two: 0x0F,
+    }
+    .into_bytes();
+    assert_eq!(bytes, [0b0000_1111, 0b1111_1111]);
+    assert!(Reversed::try_from_bytes(bytes).is_ok());
+    assert_eq!(
+        Reversed::try_from_bytes([0b0001_1111, 0b1111_1111]),
+        Err(BitfieldDecodeError {
+            field: "reserve".into(),
+            bits: 8..12,
+            kind: BitfieldDecodeErrorKind::NonZeroReserve,
+        })
+    );
+}
+
+#[derive(BitfieldEnum, Clone, Debug, PartialEq, Eq)]
+#[bondrewd_enum(strict)]
+enum Apid {
+    Housekeeping = 3,
+    Science = 9,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be")]
+struct StrictEnumPacket {
+    #[bondrewd(enum_primitive = "u8", bit_length = 4)]
+    apid: Apid,
+    #[bondrewd(bit_length = 4)]
+    count: u8,
+}
+
+#[test]
+fn try_read_strict_enum_field() {
+    let bytes = [0b1001_0010];
+    assert_eq!(StrictEnumPacket::try_read_apid(&bytes), Ok(Apid::Science));
+    assert_eq!(StrictEnumPacket::try_read_count(&bytes), Ok(2));
+    let bytes = [0b0100_0010];
+    let err = BitfieldDecodeError {
+        field: "apid".into(),
+        bits: 0..4,
+        kind: BitfieldDecodeErrorKind::InvalidEnum,
+    };
+    assert_eq!(StrictEnumPacket::try_read_apid(&bytes), Err(err.clone()));
+    assert_eq!(StrictEnumPacket::try_from_bytes(bytes), Err(err));
+    // the infallible functions do not panic, they give the last variant.
+    assert_eq!(StrictEnumPacket::read_apid(&bytes), Apid::Science);
+    assert_eq!(
+        StrictEnumPacket::from_bytes([0x40]),
+        StrictEnumPacket {
+            apid: Apid::Science,
+            count: 0,
+        }
+    );
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be")]
+struct ReservedInner {
+    #[bondrewd(bit_length = 4, reserve)]
+    r: u8,
+    #[bondrewd(bit_length = 4)]
+    value: u8,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be")]
+struct WithReservedInner {
+    id: u8,
+    #[bondrewd(struct_size = 1)]
+    inner: ReservedInner,
+}
+
+#[test]
+fn try_from_bytes_nested_error_position() {
+    let bytes = [0x01, 0b0100_0011];
+    let err = WithReservedInner::try_from_bytes(bytes).unwrap_err();
+    assert_eq!(
+        err,
+        BitfieldDecodeError {
+            field: "inner.r".into(),
+            bits: 8..12,
+            kind: BitfieldDecodeErrorKind::NonZeroReserve,
+        }
+    );
+    assert_eq!(err.field, "inner.r");
+    assert_eq!(
+        err.to_string(),
+        "field inner.r @ bits 8..12 is reserved but not zero."
+    );
+    assert_eq!(WithReservedInner::try_read_inner(&bytes).unwrap_err().bits, 8..12);
+}
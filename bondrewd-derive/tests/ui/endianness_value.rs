@@ -0,0 +1,9 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianness = "middle")]
+struct Middle {
+    one: u16,
+}
+
+fn main() {}
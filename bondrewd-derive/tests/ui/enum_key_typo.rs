@@ -0,0 +1,11 @@
+use bondrewd::*;
+
+#[derive(BitfieldEnum)]
+#[bondrewd_enum(u8, partial_qe)]
+enum Typo {
+    Zero,
+    One,
+    Invalid,
+}
+
+fn main() {}
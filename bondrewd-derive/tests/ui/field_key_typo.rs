@@ -0,0 +1,10 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianness = "be")]
+struct FieldTypo {
+    #[bondrewd(bit_lenght = 3)]
+    one: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianness = "be")]
+struct FlagValue {
+    #[bondrewd(reserve = true)]
+    one: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianness = "be", reserve)]
+struct Misplaced {
+    one: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianness = "be")]
+struct Misplaced {
+    #[bondrewd(enforce_bytes = 2)]
+    one: u16,
+}
+
+fn main() {}
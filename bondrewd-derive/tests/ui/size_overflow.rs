@@ -0,0 +1,10 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianness = "be")]
+struct Overflow {
+    #[bondrewd(bit_length = 1 << 200)]
+    one: u16,
+}
+
+fn main() {}
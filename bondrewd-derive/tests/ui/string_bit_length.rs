@@ -0,0 +1,10 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianness = "be")]
+struct WrongValue {
+    #[bondrewd(bit_length = "3")]
+    one: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianness = "be", enforce_bytes = "2")]
+struct WrongValue {
+    one: u16,
+}
+
+fn main() {}
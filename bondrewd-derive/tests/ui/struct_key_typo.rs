@@ -0,0 +1,9 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianess = "be")]
+struct Typo {
+    one: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bondrewd::*;
+
+#[derive(Bitfields)]
+#[bondrewd(default_endianness = "be")]
+struct MissingValue {
+    #[bondrewd(bit_length)]
+    one: u8,
+}
+
+fn main() {}
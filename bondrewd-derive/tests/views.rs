@@ -0,0 +1,136 @@
+use bondrewd::*;
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct Inner {
+    #[bondrewd(bit_length = 4)]
+    one: u8,
+    #[bondrewd(bit_length = 12)]
+    two: u16,
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "le", enforce_bytes = 6)]
+struct Outer {
+    #[bondrewd(bit_length = 7)]
+    id: u8,
+    flag: bool,
+    #[bondrewd(struct_size = 2)]
+    aligned: Inner,
+    #[bondrewd(bit_length = 4)]
+    count: u8,
+    #[bondrewd(struct_size = 2)]
+    unaligned: Inner,
+    #[bondrewd(bit_length = 4, reserve)]
+    reserve: u8,
+}
+
+fn outer() -> Outer {
+    Outer {
+        id: 0x55,
+        flag: true,
+        aligned: Inner { one: 3, two: 0xABC },
+        count: 9,
+        unaligned: Inner { one: 5, two: 0x123 },
+        reserve: 0,
+    }
+}
+
+#[test]
+fn view_reads_fields() {
+    let bytes = outer().into_bytes();
+    let view = Outer::view(&bytes);
+    assert_eq!(view.read_id(), 0x55);
+    assert!(view.read_flag());
+    assert_eq!(view.read_count(), 9);
+    assert_eq!(view.read_unaligned(), Inner { one: 5, two: 0x123 });
+    assert_eq!(view.to_owned(), outer());
+    assert_eq!(view.as_bytes(), &bytes);
+}
+
+#[test]
+fn view_nested_borrows_bytes() {
+    let bytes = outer().into_bytes();
+    let view = Outer::view(&bytes);
+    let inner = view.read_aligned();
+    // the nested view is backed by the bytes of the outer structure.
+    assert_eq!(inner.as_bytes(), &Inner { one: 3, two: 0xABC }.into_bytes());
+    assert_eq!(inner.read_one(), 3);
+    assert_eq!(inner.read_two(), 0xABC);
+    assert_eq!(inner.to_owned(), Inner { one: 3, two: 0xABC });
+}
+
+#[test]
+fn view_mut_writes_in_place() {
+    let mut bytes = outer().into_bytes();
+    let mut view = Outer::view_mut(&mut bytes);
+    view.write_count(2);
+    view.read_aligned_mut().write_two(0x321);
+    view.write_unaligned(Inner { one: 1, two: 1 });
+    assert_eq!(view.read_aligned().read_two(), 0x321);
+    let mut expected = outer();
+    expected.count = 2;
+    expected.aligned.two = 0x321;
+    expected.unaligned = Inner { one: 1, two: 1 };
+    assert_eq!(view.to_owned(), expected);
+    view.set(outer());
+    assert_eq!(bytes, outer().into_bytes());
+}
+
+#[test]
+fn view_slice_generic_buffers() {
+    let mut buffer = outer().into_bytes().to_vec();
+    buffer.push(0xFF);
+    let view = Outer::view_slice(buffer.as_slice()).unwrap();
+    assert_eq!(view.to_owned(), outer());
+    let mut view = Outer::view_slice_mut(buffer).unwrap();
+    view.write_id(1);
+    let buffer = view.into_inner();
+    assert_eq!(buffer.len(), 7);
+    assert_eq!(Outer::read_id(&buffer[..6].try_into().unwrap()), 1);
+    assert!(matches!(
+        Outer::view_slice(&buffer[..5]),
+        Err(BitfieldSliceError(5, 6))
+    ));
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be", read_from = "lsb0")]
+struct LsbOuter {
+    #[bondrewd(struct_size = 2)]
+    inner: Inner,
+    tail: u8,
+}
+
+#[test]
+fn view_nested_lsb0() {
+    let value = LsbOuter {
+        inner: Inner {
+            one: 0xF,
+            two: 0x00F,
+        },
+        tail: 0x42,
+    };
+    let bytes = value.clone().into_bytes();
+    let view = LsbOuter::view(&bytes);
+    assert_eq!(view.read_inner().to_owned(), value.inner);
+    assert_eq!(view.read_tail(), 0x42);
+}
+
+#[derive(Bitfields, Clone, Debug, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2)]
+enum Message {
+    Ping,
+    Data { value: u8 },
+}
+
+#[test]
+fn view_enum_with_data() {
+    let bytes = Message::Data { value: 4 }.into_bytes();
+    let view = Message::view(&bytes);
+    assert_eq!(view.read_variant_id(), 1);
+    assert_eq!(view.to_owned(), Message::Data { value: 4 });
+    let mut bytes = bytes;
+    Message::view_mut(&mut bytes).set(Message::Ping);
+    assert_eq!(Message::from_bytes(bytes), Message::Ping);
+}
@@ -0,0 +1,175 @@
+//! Bit access used by the derive for structures whose sizes come from constants. The derive can not
+//! know where those fields are, so instead of unrolled byte math it emits calls to these functions
+//! with positions the compiler works out.
+//!
+//! Bits are counted from the most significant bit of the first byte. `flip` is the index of the
+//! last byte of structures using `reverse`, their bytes are read back to front.
+//!
+//! [`read_stream`] and [`write_stream`] move the bits of a whole structure to and from any bit
+//! offset, for [`Bitfields::read_from_bits`](crate::Bitfields::read_from_bits) and
+//! [`Bitfields::write_to_bits`](crate::Bitfields::write_to_bits).
+
+use crate::BitfieldSliceError;
+
+#[inline]
+fn byte_index(bit: usize, flip: Option<usize>) -> usize {
+    match flip {
+        Some(last) => last - bit / 8,
+        None => bit / 8,
+    }
+}
+
+#[inline]
+fn get_bit(buffer: &[u8], bit: usize, flip: Option<usize>) -> bool {
+    buffer[byte_index(bit, flip)] & (0b1000_0000 >> (bit % 8)) != 0
+}
+
+#[inline]
+fn set_bit(buffer: &mut [u8], bit: usize, flip: Option<usize>, value: bool) {
+    let mask = 0b1000_0000 >> (bit % 8);
+    let byte = &mut buffer[byte_index(bit, flip)];
+    if value {
+        *byte |= mask;
+    } else {
+        *byte &= !mask;
+    }
+}
+
+/// Reads `length` bits starting at `start`, the first bit is the most significant. `length` can not
+/// be more than 128.
+pub fn read_be(buffer: &[u8], start: usize, length: usize, flip: Option<usize>) -> u128 {
+    let mut value = 0u128;
+    for bit in start..start + length {
+        value = (value << 1) | u128::from(get_bit(buffer, bit, flip));
+    }
+    value
+}
+
+/// Writes the low `length` bits of `value` starting at `start`, the most significant first.
+pub fn write_be(buffer: &mut [u8], start: usize, length: usize, value: u128, flip: Option<usize>) {
+    for offset in 0..length {
+        let bit = (value >> (length - 1 - offset)) & 1 != 0;
+        set_bit(buffer, start + offset, flip, bit);
+    }
+}
+
+/// Reads `length` bits starting at `start` with the least significant byte first. Every byte is
+/// stored like [`read_be`] would read it, the last one only keeps the bits that are left.
+pub fn read_le(buffer: &[u8], start: usize, length: usize, flip: Option<usize>) -> u128 {
+    let mut value = 0u128;
+    let mut done = 0;
+    while done < length {
+        let bits = (length - done).min(8);
+        value |= read_be(buffer, start + done, bits, flip) << done;
+        done += bits;
+    }
+    value
+}
+
+/// Writes the low `length` bits of `value` starting at `start` with the least significant byte
+/// first.
+pub fn write_le(buffer: &mut [u8], start: usize, length: usize, value: u128, flip: Option<usize>) {
+    let mut done = 0;
+    while done < length {
+        let bits = (length - done).min(8);
+        write_be(buffer, start + done, bits, value >> done, flip);
+        done += bits;
+    }
+}
+
+/// [`read_le`] or [`read_be`], whichever is the byte order of the target.
+#[inline]
+pub fn read_ne(buffer: &[u8], start: usize, length: usize, flip: Option<usize>) -> u128 {
+    if cfg!(target_endian = "little") {
+        read_le(buffer, start, length, flip)
+    } else {
+        read_be(buffer, start, length, flip)
+    }
+}
+
+/// [`write_le`] or [`write_be`], whichever is the byte order of the target.
+#[inline]
+pub fn write_ne(buffer: &mut [u8], start: usize, length: usize, value: u128, flip: Option<usize>) {
+    if cfg!(target_endian = "little") {
+        write_le(buffer, start, length, value, flip)
+    } else {
+        write_be(buffer, start, length, value, flip)
+    }
+}
+
+/// Copies `length` bits starting at `start` to the start of `output`, which is how the bytes of a
+/// nested structure are stored. Bits of `output` after `length` are left alone.
+pub fn read_bytes(buffer: &[u8], start: usize, length: usize, flip: Option<usize>, output: &mut [u8]) {
+    for offset in 0..length {
+        set_bit(output, offset, None, get_bit(buffer, start + offset, flip));
+    }
+}
+
+/// Copies the first `length` bits of `input` to the bits starting at `start`.
+pub fn write_bytes(buffer: &mut [u8], start: usize, length: usize, flip: Option<usize>, input: &[u8]) {
+    for offset in 0..length {
+        set_bit(buffer, start + offset, flip, get_bit(input, offset, None));
+    }
+}
+
+/// [`read_bytes`] from a buffer that has to be checked to hold the bits first.
+pub fn read_stream(
+    buffer: &[u8],
+    start: usize,
+    length: usize,
+    output: &mut [u8],
+) -> Result<(), BitfieldSliceError> {
+    check_stream(buffer, start, length)?;
+    if start.is_multiple_of(8) {
+        // whole bytes can be copied, only the bits of the last partial byte are moved one by one.
+        let whole = length / 8;
+        output[..whole].copy_from_slice(&buffer[start / 8..start / 8 + whole]);
+        read_bytes(buffer, start + whole * 8, length % 8, None, &mut output[whole..]);
+    } else {
+        read_bytes(buffer, start, length, None, output);
+    }
+    Ok(())
+}
+
+/// [`write_bytes`] to a buffer that has to be checked to hold the bits first. Bits of `buffer`
+/// outside of `start..start + length` are left alone.
+pub fn write_stream(
+    buffer: &mut [u8],
+    start: usize,
+    length: usize,
+    input: &[u8],
+) -> Result<(), BitfieldSliceError> {
+    check_stream(buffer, start, length)?;
+    if start.is_multiple_of(8) {
+        let whole = length / 8;
+        buffer[start / 8..start / 8 + whole].copy_from_slice(&input[..whole]);
+        write_bytes(buffer, start + whole * 8, length % 8, None, &input[whole..]);
+    } else {
+        write_bytes(buffer, start, length, None, input);
+    }
+    Ok(())
+}
+
+fn check_stream(buffer: &[u8], start: usize, length: usize) -> Result<(), BitfieldSliceError> {
+    let required = (start + length).div_ceil(8);
+    if buffer.len() < required {
+        Err(BitfieldSliceError(buffer.len(), required))
+    } else {
+        Ok(())
+    }
+}
+
+/// True when none of the `length` bits starting at `start` are set.
+pub fn is_zero(buffer: &[u8], start: usize, length: usize, flip: Option<usize>) -> bool {
+    (start..start + length).all(|bit| !get_bit(buffer, bit, flip))
+}
+
+/// Makes the low `length` bits of `value` a signed number.
+#[inline]
+pub const fn sign_extend(value: u128, length: usize) -> i128 {
+    if length == 0 {
+        return 0;
+    }
+    let shift = (128 - length) as u32;
+    ((value << shift) as i128) >> shift
+}
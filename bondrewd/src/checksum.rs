@@ -0,0 +1,71 @@
+/// An algorithm for `checksum` fields. The field holds the value `checksum` returns for the bytes
+/// it covers, so its type has to be `Output`. `into_bytes` calls it after every other field is
+/// written and `verify_checksums` calls it to check the field.
+///
+/// ```
+/// use bondrewd::BitfieldChecksum;
+///
+/// /// the two's complement of the sum of all bytes.
+/// struct TwosComplement;
+///
+/// impl BitfieldChecksum for TwosComplement {
+///     type Output = u8;
+///     fn checksum(bytes: &[u8]) -> u8 {
+///         bytes
+///             .iter()
+///             .fold(0u8, |sum, byte| sum.wrapping_add(*byte))
+///             .wrapping_neg()
+///     }
+/// }
+///
+/// assert_eq!(TwosComplement::checksum(&[0x01, 0x02]), 0xFD);
+/// ```
+pub trait BitfieldChecksum {
+    type Output: Copy + Into<u128>;
+    fn checksum(bytes: &[u8]) -> Self::Output;
+}
+
+/// CRC-16/CCITT-FALSE, polynomial `0x1021` starting at `0xFFFF` with nothing reflected. This is the
+/// CRC used by CCSDS frames. `checksum = "crc16_ccitt"` in the derive.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Crc16Ccitt;
+
+impl BitfieldChecksum for Crc16Ccitt {
+    type Output = u16;
+    fn checksum(bytes: &[u8]) -> u16 {
+        let mut crc = 0xFFFFu16;
+        for byte in bytes {
+            crc ^= (*byte as u16) << 8;
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 {
+                    (crc << 1) ^ 0x1021
+                } else {
+                    crc << 1
+                };
+            }
+        }
+        crc
+    }
+}
+
+/// The sum of all bytes, wrapping on overflow. `checksum = "sum8"` in the derive.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sum8;
+
+impl BitfieldChecksum for Sum8 {
+    type Output = u8;
+    fn checksum(bytes: &[u8]) -> u8 {
+        bytes.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))
+    }
+}
+
+/// All bytes xor-ed together. `checksum = "xor8"` in the derive.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Xor8;
+
+impl BitfieldChecksum for Xor8 {
+    type Output = u8;
+    fn checksum(bytes: &[u8]) -> u8 {
+        bytes.iter().fold(0, |xor, byte| xor ^ *byte)
+    }
+}
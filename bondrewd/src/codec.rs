@@ -0,0 +1,198 @@
+//! A [`tokio_util::codec`] codec for [`Bitfields`] structures sent as fixed size frames, so a
+//! `FramedRead`/`FramedWrite` over a socket yields and takes the structures themselves.
+use crate::Bitfields;
+#[cfg(feature = "try_fns")]
+use crate::TryBitfields;
+use bytes::{Buf, BufMut, BytesMut};
+use core::marker::PhantomData;
+use std::vec::Vec;
+use tokio_util::codec::{Decoder, Encoder};
+
+/// Decodes and encodes frames of exactly `SIZE` bytes, each holding one `T`.
+///
+/// With [`with_sync_marker`](Self::with_sync_marker) every frame is sent after a sync marker, the
+/// decoder drops bytes until it finds the marker so it can start reading in the middle of a stream
+/// or after bytes got lost. The marker is not part of `T`.
+#[derive(Debug, Clone)]
+pub struct BitfieldsCodec<T, const SIZE: usize> {
+    sync_marker: Vec<u8>,
+    marker_found: bool,
+    _frame: PhantomData<fn() -> T>,
+}
+
+impl<T: Bitfields<SIZE>, const SIZE: usize> BitfieldsCodec<T, SIZE> {
+    pub fn new() -> Self {
+        Self {
+            sync_marker: Vec::new(),
+            marker_found: false,
+            _frame: PhantomData,
+        }
+    }
+
+    /// Frames start with `sync_marker`, it is written before every frame and searched for before
+    /// every frame is read.
+    pub fn with_sync_marker(sync_marker: impl Into<Vec<u8>>) -> Self {
+        Self {
+            sync_marker: sync_marker.into(),
+            marker_found: false,
+            _frame: PhantomData,
+        }
+    }
+
+    pub fn sync_marker(&self) -> &[u8] {
+        &self.sync_marker
+    }
+
+    /// drops the bytes before the sync marker and the marker itself, returns false when the
+    /// marker is not in `src` yet.
+    fn find_sync_marker(&mut self, src: &mut BytesMut) -> bool {
+        let marker_len = self.sync_marker.len();
+        if self.marker_found || marker_len == 0 {
+            return true;
+        }
+        match src
+            .windows(marker_len)
+            .position(|window| window == self.sync_marker.as_slice())
+        {
+            Some(start) => {
+                src.advance(start + marker_len);
+                self.marker_found = true;
+                true
+            }
+            None => {
+                // the end could be the start of a marker that has not fully arrived.
+                let keep = (marker_len - 1).min(src.len());
+                src.advance(src.len() - keep);
+                false
+            }
+        }
+    }
+
+    /// the bytes of the next frame, `None` until all of them arrived.
+    fn next_frame(&mut self, src: &mut BytesMut) -> Option<[u8; SIZE]> {
+        if !self.find_sync_marker(src) {
+            src.reserve(self.sync_marker.len() + SIZE);
+            return None;
+        }
+        if src.len() < SIZE {
+            src.reserve(SIZE - src.len());
+            return None;
+        }
+        let mut bytes = [0u8; SIZE];
+        src.copy_to_slice(&mut bytes);
+        self.marker_found = false;
+        Some(bytes)
+    }
+
+    /// bytes left over without a sync marker in them are noise, a frame that was cut off is still
+    /// an error.
+    fn last_frame(&mut self, src: &mut BytesMut) -> std::io::Result<Option<[u8; SIZE]>> {
+        match self.next_frame(src) {
+            Some(bytes) => Ok(Some(bytes)),
+            None if src.is_empty() || (!self.marker_found && !self.sync_marker.is_empty()) => {
+                src.clear();
+                Ok(None)
+            }
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "the stream ended in the middle of a frame",
+            )),
+        }
+    }
+}
+
+impl<T: Bitfields<SIZE>, const SIZE: usize> Default for BitfieldsCodec<T, SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Bitfields<SIZE>, const SIZE: usize> Decoder for BitfieldsCodec<T, SIZE> {
+    type Item = T;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<T>, Self::Error> {
+        Ok(self.next_frame(src).map(T::from_bytes))
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<T>, Self::Error> {
+        Ok(self.last_frame(src)?.map(T::from_bytes))
+    }
+}
+
+impl<T: Bitfields<SIZE>, const SIZE: usize> Encoder<T> for BitfieldsCodec<T, SIZE> {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        dst.reserve(self.sync_marker.len() + SIZE);
+        dst.put_slice(&self.sync_marker);
+        dst.put_slice(&item.into_bytes());
+        Ok(())
+    }
+}
+
+/// [`BitfieldsCodec`] decoding with [`TryBitfields::try_from_bytes`], a frame holding values the
+/// fields can not hold is an [`InvalidData`](std::io::ErrorKind::InvalidData) error wrapping the
+/// [`BitfieldDecodeError`](crate::BitfieldDecodeError) instead of a structure.
+#[cfg(feature = "try_fns")]
+#[derive(Debug, Clone)]
+pub struct TryBitfieldsCodec<T, const SIZE: usize> {
+    codec: BitfieldsCodec<T, SIZE>,
+}
+
+#[cfg(feature = "try_fns")]
+impl<T: TryBitfields<SIZE>, const SIZE: usize> TryBitfieldsCodec<T, SIZE> {
+    pub fn new() -> Self {
+        Self {
+            codec: BitfieldsCodec::new(),
+        }
+    }
+
+    /// See [`BitfieldsCodec::with_sync_marker`].
+    pub fn with_sync_marker(sync_marker: impl Into<Vec<u8>>) -> Self {
+        Self {
+            codec: BitfieldsCodec::with_sync_marker(sync_marker),
+        }
+    }
+
+    pub fn sync_marker(&self) -> &[u8] {
+        self.codec.sync_marker()
+    }
+}
+
+#[cfg(feature = "try_fns")]
+impl<T: TryBitfields<SIZE>, const SIZE: usize> Default for TryBitfieldsCodec<T, SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "try_fns")]
+impl<T: TryBitfields<SIZE>, const SIZE: usize> Decoder for TryBitfieldsCodec<T, SIZE> {
+    type Item = T;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<T>, Self::Error> {
+        self.codec.next_frame(src).map(try_frame).transpose()
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<T>, Self::Error> {
+        self.codec.last_frame(src)?.map(try_frame).transpose()
+    }
+}
+
+/// decoding errors become io errors so the codec works with `FramedRead`.
+#[cfg(feature = "try_fns")]
+fn try_frame<T: TryBitfields<SIZE>, const SIZE: usize>(bytes: [u8; SIZE]) -> std::io::Result<T> {
+    T::try_from_bytes(bytes)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+#[cfg(feature = "try_fns")]
+impl<T: TryBitfields<SIZE>, const SIZE: usize> Encoder<T> for TryBitfieldsCodec<T, SIZE> {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.codec.encode(item, dst)
+    }
+}
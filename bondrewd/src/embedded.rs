@@ -0,0 +1,41 @@
+//! Reading and writing [`Bitfields`] structures with the `embedded-io` [`Read`] and [`Write`]
+//! traits, for targets without `std`.
+use crate::Bitfields;
+#[cfg(feature = "try_fns")]
+use crate::{BitfieldIoError, TryBitfields};
+use embedded_io::{Read, ReadExactError, Write};
+
+/// Reads a structure from its bytes in an `embedded-io` [`Read`].
+pub trait ReadBitfieldsExt: Read {
+    fn read_bitfields<T: Bitfields<SIZE>, const SIZE: usize>(
+        &mut self,
+    ) -> Result<T, ReadExactError<Self::Error>> {
+        let mut bytes = [0u8; SIZE];
+        self.read_exact(&mut bytes)?;
+        Ok(T::from_bytes(bytes))
+    }
+
+    /// Like `read_bitfields` but decodes with [`TryBitfields::try_from_bytes`].
+    #[cfg(feature = "try_fns")]
+    fn try_read_bitfields<T: TryBitfields<SIZE>, const SIZE: usize>(
+        &mut self,
+    ) -> Result<T, BitfieldIoError<ReadExactError<Self::Error>>> {
+        let mut bytes = [0u8; SIZE];
+        self.read_exact(&mut bytes).map_err(BitfieldIoError::Io)?;
+        T::try_from_bytes(bytes).map_err(BitfieldIoError::Decode)
+    }
+}
+
+impl<R: Read + ?Sized> ReadBitfieldsExt for R {}
+
+/// Writes the bytes of a structure to an `embedded-io` [`Write`].
+pub trait WriteBitfieldsExt: Write {
+    fn write_bitfields<T: Bitfields<SIZE> + Clone, const SIZE: usize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.write_all(&value.clone().into_bytes())
+    }
+}
+
+impl<W: Write + ?Sized> WriteBitfieldsExt for W {}
@@ -16,9 +16,11 @@ impl core::fmt::Display for BitfieldSliceError {
 #[cfg(feature = "std")]
 impl std::error::Error for BitfieldSliceError {}
 
+#[cfg(feature = "hex_fns")]
 #[derive(Debug)]
 pub struct BitfieldHexError(pub char, pub usize);
 
+#[cfg(feature = "hex_fns")]
 impl core::fmt::Display for BitfieldHexError {
     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
@@ -28,3 +30,231 @@ impl core::fmt::Display for BitfieldHexError {
         )
     }
 }
+
+/// Returned by `try_from_primitive` with the primitive that does not match a variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitfieldEnumError<P>(pub P);
+
+impl<P: core::fmt::Display> core::fmt::Display for BitfieldEnumError<P> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{} does not match an enum variant.", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: core::fmt::Debug + core::fmt::Display> std::error::Error for BitfieldEnumError<P> {}
+
+/// Returned by `verify_checksums` when a `checksum` field does not hold the checksum of the bytes
+/// it covers. `expected` is the checksum computed from the bytes, `actual` the value of the field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitfieldChecksumError {
+    pub field: &'static str,
+    pub expected: u128,
+    pub actual: u128,
+}
+
+impl core::fmt::Display for BitfieldChecksumError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            fmt,
+            "checksum {} is {:#x} but the bytes it covers have a checksum of {:#x}.",
+            self.field, self.actual, self.expected
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitfieldChecksumError {}
+
+/// What was wrong with a field [`BitfieldDecodeError`] is reporting.
+#[cfg(feature = "try_fns")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitfieldDecodeErrorKind {
+    /// the value does not match a variant of the enum, and the field is `strict` so catch
+    /// variants do not count.
+    InvalidEnum,
+    /// a reserve field (or fill bytes) had bits set.
+    NonZeroReserve,
+    /// the value is not a valid unicode scalar value.
+    InvalidChar,
+    /// a `const_value` field does not hold its value. both are the bits of the field read as an
+    /// unsigned number.
+    ConstMismatch { expected: u128, actual: u128 },
+}
+
+/// How many names a [`BitfieldFieldName`] keeps. Names of structures nested deeper than that are
+/// left out, which keeps [`BitfieldDecodeError`] small enough to return by value.
+#[cfg(feature = "try_fns")]
+const FIELD_NAME_DEPTH: usize = 3;
+
+/// Name of the field a [`BitfieldDecodeError`] is reporting. For a field of a nested structure the
+/// names of the fields holding it come first, displayed joined by dots (`inner.r`).
+#[cfg(feature = "try_fns")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BitfieldFieldName {
+    // innermost name first, unused names are empty.
+    names: [&'static str; FIELD_NAME_DEPTH],
+}
+
+#[cfg(feature = "try_fns")]
+impl BitfieldFieldName {
+    /// `name` can be a path of fields separated by dots.
+    pub fn new(name: &'static str) -> Self {
+        let mut names = [""; FIELD_NAME_DEPTH];
+        for (slot, name) in names.iter_mut().zip(name.rsplit('.')) {
+            *slot = name;
+        }
+        Self { names }
+    }
+    /// the name of the field inside of a nested structure stored in the field `outer`.
+    pub fn nested_in(mut self, outer: &'static str) -> Self {
+        if let Some(slot) = self.names.iter_mut().find(|name| name.is_empty()) {
+            *slot = outer;
+        }
+        self
+    }
+    /// the names from the outermost field to the field itself.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.names
+            .iter()
+            .rev()
+            .copied()
+            .filter(|name| !name.is_empty())
+    }
+}
+
+#[cfg(feature = "try_fns")]
+impl From<&'static str> for BitfieldFieldName {
+    fn from(name: &'static str) -> Self {
+        Self::new(name)
+    }
+}
+
+#[cfg(feature = "try_fns")]
+impl PartialEq<&str> for BitfieldFieldName {
+    fn eq(&self, other: &&str) -> bool {
+        let mut parts = other.split('.');
+        self.names().all(|name| parts.next() == Some(name)) && parts.next().is_none()
+    }
+}
+
+#[cfg(feature = "try_fns")]
+impl core::fmt::Display for BitfieldFieldName {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, name) in self.names().enumerate() {
+            if i != 0 {
+                write!(fmt, ".")?;
+            }
+            write!(fmt, "{}", name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "try_fns")]
+impl core::fmt::Debug for BitfieldFieldName {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "\"{}\"", self)
+    }
+}
+
+/// Returned by `try_from_bytes` when a field holds a value it should not.
+/// `bits` is the range of the field in the byte array, bit 0 being the most significant bit of the
+/// first byte.
+#[cfg(feature = "try_fns")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitfieldDecodeError {
+    pub field: BitfieldFieldName,
+    pub bits: core::ops::Range<usize>,
+    pub kind: BitfieldDecodeErrorKind,
+}
+
+#[cfg(feature = "try_fns")]
+impl BitfieldDecodeError {
+    /// moves an error of a nested structure stored in the field `outer`, which starts at bit
+    /// `start`, into the structure holding it.
+    pub fn nested_in(self, outer: &'static str, start: usize) -> Self {
+        Self {
+            field: self.field.nested_in(outer),
+            bits: (self.bits.start + start)..(self.bits.end + start),
+            kind: self.kind,
+        }
+    }
+}
+
+#[cfg(feature = "try_fns")]
+impl core::fmt::Display for BitfieldDecodeError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            fmt,
+            "field {} @ bits {}..{} ",
+            self.field, self.bits.start, self.bits.end
+        )?;
+        match self.kind {
+            BitfieldDecodeErrorKind::InvalidEnum => write!(fmt, "does not match an enum variant."),
+            BitfieldDecodeErrorKind::NonZeroReserve => write!(fmt, "is reserved but not zero."),
+            BitfieldDecodeErrorKind::InvalidChar => write!(fmt, "is not a valid char."),
+            BitfieldDecodeErrorKind::ConstMismatch { expected, actual } => {
+                write!(fmt, "is {:#x} but has to be {:#x}.", actual, expected)
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "try_fns"))]
+impl std::error::Error for BitfieldDecodeError {}
+
+/// Returned by the `try_read_bitfields` functions of the io extension traits. `Io` is the error of
+/// the reader, `Decode` means the bytes were read but `try_from_bytes` rejected them.
+#[cfg(feature = "try_fns")]
+#[derive(Debug)]
+pub enum BitfieldIoError<E> {
+    Io(E),
+    Decode(BitfieldDecodeError),
+}
+
+#[cfg(feature = "try_fns")]
+impl<E: core::fmt::Display> core::fmt::Display for BitfieldIoError<E> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(err) => write!(fmt, "reading the bytes failed: {}", err),
+            Self::Decode(err) => write!(fmt, "{}", err),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "try_fns"))]
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for BitfieldIoError<E> {}
+
+/// Returned by [`BitfieldsDyn`](crate::BitfieldsDyn) functions.
+#[cfg(feature = "dyn_fns")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitfieldDynError {
+    /// not enough bytes for the header and trailing field.
+    /// (amount of bytes provided , amount of bytes required). the amount required is `usize::MAX`
+    /// when the length field describes more bytes than a usize can count.
+    Slice(usize, usize),
+    /// the trailing field has a length the length field can not describe, with `offset` removed.
+    LengthOutOfRange(usize),
+}
+
+#[cfg(feature = "dyn_fns")]
+impl core::fmt::Display for BitfieldDynError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Slice(provided, required) => write!(
+                fmt,
+                "expected {} bytes, {} bytes were provided.",
+                required, provided
+            ),
+            Self::LengthOutOfRange(length) => write!(
+                fmt,
+                "a trailing field of length {} can not be described by its length field.",
+                length
+            ),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "dyn_fns"))]
+impl std::error::Error for BitfieldDynError {}
@@ -0,0 +1,253 @@
+/// How a float is rounded when it is stored with fewer mantissa bits than it has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatRounding {
+    /// Round to the closest value that can be stored, ties go to the value with an even mantissa.
+    /// This is the IEEE 754 default and what `as` casts between float types do.
+    NearestEven,
+    /// Drop the mantissa bits that do not fit, which rounds toward zero. Values too large for the
+    /// smaller format become its largest finite value instead of infinity.
+    TowardZero,
+}
+
+/// Floating point types that can be stored in fewer bits by bondrewd. A reduced format keeps the
+/// sign bit and uses `exponent_bits` and `mantissa_bits` for the rest, the exponent is rebiased to
+/// fit the smaller exponent. The reduced bits are in the low bits of `Bits`, sign bit first.
+pub trait BitfieldFloat: Sized {
+    type Bits;
+    /// amount of exponent bits in the full sized type.
+    const EXPONENT_BITS: u32;
+    /// amount of mantissa bits in the full sized type, not counting the implicit leading bit.
+    const MANTISSA_BITS: u32;
+    /// `exponent_bits` must be between 2 and `EXPONENT_BITS` and `mantissa_bits` can not be more
+    /// than `MANTISSA_BITS`.
+    fn to_reduced_bits(
+        self,
+        exponent_bits: u32,
+        mantissa_bits: u32,
+        rounding: FloatRounding,
+    ) -> Self::Bits;
+    /// the value of a reduced float. every reduced value can be represented exactly.
+    fn from_reduced_bits(bits: Self::Bits, exponent_bits: u32, mantissa_bits: u32) -> Self;
+    /// the integer nearest to `(self - offset) / scale`, ties away from zero. values outside of
+    /// `min..=max` are saturated and nan becomes 0.
+    fn to_scaled_raw(self, scale: f64, offset: f64, min: i128, max: i128) -> i128;
+    /// `raw * scale + offset`, values too large for `Self` saturate to its largest finite value.
+    fn from_scaled_raw(raw: i128, scale: f64, offset: f64) -> Self;
+}
+
+#[derive(Clone, Copy)]
+struct Format {
+    exponent_bits: u32,
+    mantissa_bits: u32,
+}
+
+impl Format {
+    fn bias(self) -> i64 {
+        (1i64 << (self.exponent_bits - 1)) - 1
+    }
+    fn max_exponent(self) -> u64 {
+        (1u64 << self.exponent_bits) - 1
+    }
+    fn mantissa_mask(self) -> u64 {
+        (1u64 << self.mantissa_bits) - 1
+    }
+    fn sign_shift(self) -> u32 {
+        self.exponent_bits + self.mantissa_bits
+    }
+}
+
+/// shifts `value` right rounding the bits shifted out.
+fn round_shift(value: u64, shift: u32, rounding: FloatRounding) -> u64 {
+    if shift == 0 {
+        return value;
+    }
+    let value = value as u128;
+    let quotient = if shift >= 128 { 0 } else { value >> shift };
+    match rounding {
+        FloatRounding::TowardZero => quotient as u64,
+        FloatRounding::NearestEven => {
+            if shift > 64 {
+                // everything is shifted out and the value is below half.
+                return 0;
+            }
+            let remainder = value & ((1u128 << shift) - 1);
+            let half = 1u128 << (shift - 1);
+            if remainder > half || (remainder == half && quotient & 1 == 1) {
+                (quotient + 1) as u64
+            } else {
+                quotient as u64
+            }
+        }
+    }
+}
+
+/// converts the bits of a float in the `from` format to the smaller `to` format.
+fn narrow(bits: u64, from: Format, to: Format, rounding: FloatRounding) -> u64 {
+    let sign = ((bits >> from.sign_shift()) & 1) << to.sign_shift();
+    let exponent = (bits >> from.mantissa_bits) & from.max_exponent();
+    let mantissa = bits & from.mantissa_mask();
+    let shift = from.mantissa_bits - to.mantissa_bits;
+    if exponent == from.max_exponent() {
+        // infinity stays infinity, nan keeps its top mantissa bits and must stay a nan.
+        let mut mantissa = mantissa >> shift;
+        if from.mantissa_bits != 0 && bits & from.mantissa_mask() != 0 && mantissa == 0 {
+            mantissa = 1u64 << to.mantissa_bits.saturating_sub(1);
+        }
+        return sign | (to.max_exponent() << to.mantissa_bits) | mantissa;
+    }
+    if exponent == 0 && mantissa == 0 {
+        return sign;
+    }
+    // the significand with its leading bit and the exponent it is scaled by. subnormals are
+    // normalized so the leading bit is where the implicit bit of a normal number would be.
+    let (significand, unbiased) = if exponent == 0 {
+        let normalize = from.mantissa_bits - (63 - mantissa.leading_zeros());
+        (mantissa << normalize, 1 - from.bias() - normalize as i64)
+    } else {
+        (mantissa | (1u64 << from.mantissa_bits), exponent as i64 - from.bias())
+    };
+    let biased = unbiased + to.bias();
+    let largest_finite = ((to.max_exponent() - 1) << to.mantissa_bits) | to.mantissa_mask();
+    let overflow = match rounding {
+        FloatRounding::NearestEven => sign | (to.max_exponent() << to.mantissa_bits),
+        FloatRounding::TowardZero => sign | largest_finite,
+    };
+    if biased >= 1 {
+        let mut significand = round_shift(significand, shift, rounding);
+        let mut biased = biased as u64;
+        // rounding up can carry into the next exponent.
+        if significand >> (to.mantissa_bits + 1) != 0 {
+            significand >>= 1;
+            biased += 1;
+        }
+        if biased >= to.max_exponent() {
+            return overflow;
+        }
+        sign | (biased << to.mantissa_bits) | (significand & to.mantissa_mask())
+    } else {
+        // subnormal in the smaller format. a rounded value reaching the implicit bit is the
+        // smallest normal number, which the same bits describe.
+        let extra = (1 - biased) as u64;
+        let total_shift = (shift as u64 + extra).min(u32::MAX as u64) as u32;
+        sign | round_shift(significand, total_shift, rounding)
+    }
+}
+
+/// converts the bits of a float in the smaller `from` format to the `to` format, which is exact.
+fn widen(bits: u64, from: Format, to: Format) -> u64 {
+    let sign = ((bits >> from.sign_shift()) & 1) << to.sign_shift();
+    let exponent = (bits >> from.mantissa_bits) & from.max_exponent();
+    let mantissa = bits & from.mantissa_mask();
+    let shift = to.mantissa_bits - from.mantissa_bits;
+    if exponent == from.max_exponent() {
+        return sign | (to.max_exponent() << to.mantissa_bits) | (mantissa << shift);
+    }
+    if exponent == 0 {
+        if mantissa == 0 {
+            return sign;
+        }
+        // normalize the subnormal, the wider exponent may be able to hold it as a normal number.
+        let leading = 63 - mantissa.leading_zeros();
+        let normalize = from.mantissa_bits - leading;
+        let biased = 1 - from.bias() - normalize as i64 + to.bias();
+        if biased >= 1 {
+            let mantissa = (mantissa << normalize) & from.mantissa_mask();
+            return sign | ((biased as u64) << to.mantissa_bits) | (mantissa << shift);
+        }
+        return sign | (mantissa << shift);
+    }
+    let biased = (exponent as i64 - from.bias() + to.bias()) as u64;
+    sign | (biased << to.mantissa_bits) | (mantissa << shift)
+}
+
+/// rounds `value` to the nearest integer in `min..=max`, without std's `round`.
+fn round_saturate(value: f64, min: i128, max: i128) -> i128 {
+    if value.is_nan() {
+        return 0i128.clamp(min, max);
+    }
+    if value <= min as f64 {
+        return min;
+    }
+    if value >= max as f64 {
+        return max;
+    }
+    let truncated = value as i128;
+    let fraction = value - truncated as f64;
+    if fraction >= 0.5 {
+        truncated + 1
+    } else if fraction <= -0.5 {
+        truncated - 1
+    } else {
+        truncated
+    }
+}
+
+macro_rules! impl_bitfield_float {
+    ($ty:ty, $bits:ty, $exponent:expr, $mantissa:expr, $to_f64:expr, $from_f64:expr) => {
+        impl BitfieldFloat for $ty {
+            type Bits = $bits;
+            const EXPONENT_BITS: u32 = $exponent;
+            const MANTISSA_BITS: u32 = $mantissa;
+            #[inline]
+            fn to_reduced_bits(
+                self,
+                exponent_bits: u32,
+                mantissa_bits: u32,
+                rounding: FloatRounding,
+            ) -> $bits {
+                let full = Format {
+                    exponent_bits: $exponent,
+                    mantissa_bits: $mantissa,
+                };
+                let reduced = Format {
+                    exponent_bits,
+                    mantissa_bits,
+                };
+                narrow(self.to_bits() as u64, full, reduced, rounding) as $bits
+            }
+            #[inline]
+            fn from_reduced_bits(bits: $bits, exponent_bits: u32, mantissa_bits: u32) -> Self {
+                let full = Format {
+                    exponent_bits: $exponent,
+                    mantissa_bits: $mantissa,
+                };
+                let reduced = Format {
+                    exponent_bits,
+                    mantissa_bits,
+                };
+                <$ty>::from_bits(widen(bits as u64, reduced, full) as $bits)
+            }
+            #[inline]
+            fn to_scaled_raw(self, scale: f64, offset: f64, min: i128, max: i128) -> i128 {
+                round_saturate(($to_f64(self) - offset) / scale, min, max)
+            }
+            #[inline]
+            fn from_scaled_raw(raw: i128, scale: f64, offset: f64) -> Self {
+                let value = raw as f64 * scale + offset;
+                let max = $to_f64(<$ty>::MAX);
+                $from_f64(value.clamp(-max, max))
+            }
+        }
+    };
+}
+
+impl_bitfield_float!(f32, u32, 8, 23, |v: f32| v as f64, |v: f64| v as f32);
+impl_bitfield_float!(f64, u64, 11, 52, |v: f64| v, |v: f64| v);
+#[cfg(feature = "half")]
+impl_bitfield_float!(
+    half::f16,
+    u16,
+    5,
+    10,
+    half::f16::to_f64,
+    half::f16::from_f64
+);
+#[cfg(feature = "half")]
+impl_bitfield_float!(
+    half::bf16,
+    u16,
+    8,
+    7,
+    half::bf16::to_f64,
+    half::bf16::from_f64
+);
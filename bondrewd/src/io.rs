@@ -0,0 +1,39 @@
+//! Reading and writing [`Bitfields`] structures with [`std::io::Read`] and [`std::io::Write`].
+use crate::Bitfields;
+#[cfg(feature = "try_fns")]
+use crate::{BitfieldIoError, TryBitfields};
+
+/// Reads a structure from its bytes in a [`std::io::Read`], instead of filling a
+/// `[u8; BYTE_SIZE]` buffer and calling [`Bitfields::from_bytes`] by hand.
+pub trait ReadBitfieldsExt: std::io::Read {
+    fn read_bitfields<T: Bitfields<SIZE>, const SIZE: usize>(&mut self) -> std::io::Result<T> {
+        let mut bytes = [0u8; SIZE];
+        self.read_exact(&mut bytes)?;
+        Ok(T::from_bytes(bytes))
+    }
+
+    /// Like `read_bitfields` but decodes with [`TryBitfields::try_from_bytes`], the error tells
+    /// reading the bytes failing apart from the bytes not being valid.
+    #[cfg(feature = "try_fns")]
+    fn try_read_bitfields<T: TryBitfields<SIZE>, const SIZE: usize>(
+        &mut self,
+    ) -> Result<T, BitfieldIoError<std::io::Error>> {
+        let mut bytes = [0u8; SIZE];
+        self.read_exact(&mut bytes).map_err(BitfieldIoError::Io)?;
+        T::try_from_bytes(bytes).map_err(BitfieldIoError::Decode)
+    }
+}
+
+impl<R: std::io::Read + ?Sized> ReadBitfieldsExt for R {}
+
+/// Writes the bytes of a structure to a [`std::io::Write`].
+pub trait WriteBitfieldsExt: std::io::Write {
+    fn write_bitfields<T: Bitfields<SIZE> + Clone, const SIZE: usize>(
+        &mut self,
+        value: &T,
+    ) -> std::io::Result<()> {
+        self.write_all(&value.clone().into_bytes())
+    }
+}
+
+impl<W: std::io::Write + ?Sized> WriteBitfieldsExt for W {}
@@ -2,24 +2,184 @@
 
 //! Defined Traits for bondrewd-derive.
 //! For Derive Docs see [bondrewd-derive](https://docs.rs/bondrewd-derive/latest/bondrewd_derive/)
+#[cfg(feature = "std")]
+extern crate std;
+
 pub trait Bitfields<const SIZE: usize> {
     const BYTE_SIZE: usize = SIZE;
     const BIT_SIZE: usize;
+    /// Takes the structure by value, so helpers that only borrow a structure, like
+    /// `write_bitfields` and `packed_serde::as_bytes`, clone it first and need it to be `Clone`.
     fn into_bytes(self) -> [u8; SIZE];
     fn from_bytes(input_byte_buffer: [u8; SIZE]) -> Self;
+    /// Reads the structure from the `BIT_SIZE` bits of `input` starting at `bit_offset`, bit 0
+    /// being the most significant bit of the first byte. Structures packed back to back without
+    /// padding can be read one after another, see [`BitReader`].
+    fn read_from_bits(input: &[u8], bit_offset: usize) -> Result<Self, BitfieldSliceError>
+    where
+        Self: Sized,
+    {
+        let mut bytes = [0u8; SIZE];
+        bitfield_layout::read_stream(input, bit_offset, Self::BIT_SIZE, &mut bytes)?;
+        Ok(Self::from_bytes(bytes))
+    }
+    /// Writes the structure to the `BIT_SIZE` bits of `output` starting at `bit_offset`, the other
+    /// bits of `output` are left alone. See [`BitWriter`].
+    fn write_to_bits(self, output: &mut [u8], bit_offset: usize) -> Result<(), BitfieldSliceError>
+    where
+        Self: Sized,
+    {
+        let bytes = self.into_bytes();
+        bitfield_layout::write_stream(output, bit_offset, Self::BIT_SIZE, &bytes)
+    }
 }
 
 pub trait BitfieldEnum {
     type Primitive;
     fn from_primitive(prim: Self::Primitive) -> Self;
     fn into_primitive(self) -> Self::Primitive;
+    /// True when a variant was marked to catch invalid values, false when there is no catch
+    /// variant or the last variant only catches them because nothing else was marked.
+    /// `try_from_bytes` rejects values that would fall into an implicit catch.
+    const HAS_CATCH_VARIANT: bool = true;
+    /// Returns false if `prim` would only be matched by a catch all or catch value variant.
+    /// Used by `try_from_bytes` to validate fields. The default treats every value as valid,
+    /// the derive overrides it.
+    fn is_variant_value(prim: &Self::Primitive) -> bool {
+        let _ = prim;
+        true
+    }
 }
 
+mod checksum;
+#[cfg(feature = "tokio_codec")]
+pub mod codec;
+#[cfg(feature = "embedded_io")]
+pub mod embedded;
 mod error;
+mod float;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "serde")]
+pub mod packed_serde;
+mod stream;
+#[doc(hidden)]
+pub mod bitfield_layout;
+pub use checksum::{BitfieldChecksum, Crc16Ccitt, Sum8, Xor8};
+pub use error::BitfieldChecksumError;
+pub use error::BitfieldEnumError;
+pub use float::{BitfieldFloat, FloatRounding};
+pub use stream::{BitReader, BitWriter};
+#[cfg(feature = "embedded_io")]
+pub use embedded_io;
+#[cfg(feature = "half")]
+pub use half;
 #[cfg(feature = "hex_fns")]
 pub use error::BitfieldHexError;
-#[cfg(feature = "slice_fns")]
 pub use error::BitfieldSliceError;
+#[cfg(feature = "try_fns")]
+pub use error::{BitfieldDecodeError, BitfieldDecodeErrorKind, BitfieldFieldName, BitfieldIoError};
+#[cfg(feature = "dyn_fns")]
+pub use error::BitfieldDynError;
+
+/// Borrowed views of the bytes of a [`Bitfields`] structure. Views read and write single fields in
+/// place, so only the fields used get decoded, and nested structures are returned as views of their
+/// own bytes instead of being copied out when they start and end on a byte boundary.
+pub trait BitfieldsView<const SIZE: usize>: Bitfields<SIZE>
+where
+    Self: Sized,
+{
+    type View<B: AsRef<[u8]>>;
+    type ViewMut<B: AsRef<[u8]> + AsMut<[u8]>>;
+    fn view(input_byte_buffer: &[u8; SIZE]) -> Self::View<&[u8; SIZE]>;
+    fn view_mut(output_byte_buffer: &mut [u8; SIZE]) -> Self::ViewMut<&mut [u8; SIZE]>;
+    /// Makes a view of any buffer, which must hold at least `SIZE` bytes.
+    fn view_slice<B: AsRef<[u8]>>(buffer: B) -> Result<Self::View<B>, BitfieldSliceError>;
+    /// Makes a mutable view of any buffer, which must hold at least `SIZE` bytes.
+    fn view_slice_mut<B: AsRef<[u8]> + AsMut<[u8]>>(
+        buffer: B,
+    ) -> Result<Self::ViewMut<B>, BitfieldSliceError>;
+}
+
+/// Locates the bytes of a nested structure within the bytes of the outermost structure, the root.
+/// Implemented by the path types generated for nested structure fields, each of which wraps the
+/// path of the structure holding it.
+pub trait BitfieldsPath<const SIZE: usize>: Copy {
+    type Root;
+    fn read_bytes(&self, root: &Self::Root) -> [u8; SIZE];
+    fn write_bytes(&self, root: &mut Self::Root, bytes: [u8; SIZE]);
+}
+
+/// Path of the outermost structure, its bytes are the root.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RootPath<const SIZE: usize>;
+
+impl<const SIZE: usize> BitfieldsPath<SIZE> for RootPath<SIZE> {
+    type Root = [u8; SIZE];
+    fn read_bytes(&self, root: &[u8; SIZE]) -> [u8; SIZE] {
+        *root
+    }
+    fn write_bytes(&self, root: &mut [u8; SIZE], bytes: [u8; SIZE]) {
+        *root = bytes;
+    }
+}
+
+/// Accessors for fields of nested structures, for example
+/// `Outer::paths().inner().field().read(&bytes)`. None of the other fields along the path get
+/// decoded, but the bytes of every structure along the path are copied out of the structure holding
+/// it, and writes copy each of them back, one level at a time.
+pub trait BitfieldsPaths<const SIZE: usize>: Bitfields<SIZE>
+where
+    Self: Sized,
+{
+    type Paths<P: BitfieldsPath<SIZE>>;
+    fn paths_from<P: BitfieldsPath<SIZE>>(path: P) -> Self::Paths<P>;
+    fn paths() -> Self::Paths<RootPath<SIZE>> {
+        Self::paths_from(RootPath)
+    }
+}
+
+/// A field at the end of a path, read and written in the bytes of the outermost structure using the
+/// `read_{field}` and `write_{field}` functions of the structure holding it.
+pub struct FieldPath<P, T, const SIZE: usize> {
+    path: P,
+    read_fn: fn(&[u8; SIZE]) -> T,
+    write_fn: fn(&mut [u8; SIZE], T),
+}
+
+impl<P: BitfieldsPath<SIZE>, T, const SIZE: usize> FieldPath<P, T, SIZE> {
+    pub fn new(path: P, read_fn: fn(&[u8; SIZE]) -> T, write_fn: fn(&mut [u8; SIZE], T)) -> Self {
+        Self {
+            path,
+            read_fn,
+            write_fn,
+        }
+    }
+    #[inline]
+    pub fn read(&self, root: &P::Root) -> T {
+        (self.read_fn)(&self.path.read_bytes(root))
+    }
+    #[inline]
+    pub fn write(&self, root: &mut P::Root, value: T) {
+        let mut bytes = self.path.read_bytes(root);
+        (self.write_fn)(&mut bytes, value);
+        self.path.write_bytes(root, bytes);
+    }
+}
+
+/// Fallible version of [`BitfieldEnum::from_primitive`]. Values that only a catch variant would
+/// match are accepted if the variant was marked, and rejected if it was only the implicit last
+/// variant. Enums deriving with `#[bondrewd_enum(strict)]` have no implicit catch so they should
+/// be decoded with this, their `from_primitive` gives the last variant for values without one.
+pub trait TryBitfieldEnum: BitfieldEnum
+where
+    Self: Sized,
+{
+    fn try_from_primitive(
+        prim: Self::Primitive,
+    ) -> Result<Self, BitfieldEnumError<Self::Primitive>>;
+}
+
 #[cfg(feature = "hex_fns")]
 pub trait BitfieldHex<const SIZE: usize>
 where
@@ -32,6 +192,33 @@ where
     fn into_hex_lower(self) -> [u8; SIZE];
 }
 
+/// Fallible version of [`Bitfields::from_bytes`] that rejects values the fields can not hold, like
+/// nonzero reserve bits or invalid chars.
+#[cfg(feature = "try_fns")]
+pub trait TryBitfields<const SIZE: usize>: Bitfields<SIZE>
+where
+    Self: Sized,
+{
+    fn try_from_bytes(input_byte_buffer: [u8; SIZE]) -> Result<Self, BitfieldDecodeError>;
+}
+
+/// For structures ending in a field whose length is read from another field
+/// (`#[bondrewd(length_from = "field")]`), so they do not have a fixed size. The fields before the
+/// trailing one are the header, which is packed the same way [`Bitfields`] packs a structure.
+#[cfg(feature = "dyn_fns")]
+pub trait BitfieldsDyn<'a>
+where
+    Self: Sized,
+{
+    /// Amount of bytes the header uses, the trailing field starts right after it.
+    const HEADER_SIZE: usize;
+    /// Decodes a structure from the start of `input`, returning it with the amount of bytes used.
+    fn decode(input: &'a [u8]) -> Result<(Self, usize), BitfieldDynError>;
+    /// Encodes the structure to the start of `output`, returning the amount of bytes written. The
+    /// length field is written from the length of the trailing field, not the value it holds.
+    fn encode_into(&self, output: &mut [u8]) -> Result<usize, BitfieldDynError>;
+}
+
 // re-export the derive stuff
 #[cfg(feature = "derive")]
 #[doc(hidden)]
@@ -39,3 +226,7 @@ pub use bondrewd_derive::*;
 
 #[cfg(all(not(feature = "derive"), feature = "slice_fns"))]
 compile_error!("the slice_fns attribute depends on the derive attribute");
+#[cfg(all(not(feature = "derive"), feature = "try_fns"))]
+compile_error!("the try_fns attribute depends on the derive attribute");
+#[cfg(all(not(feature = "derive"), feature = "dyn_fns"))]
+compile_error!("the dyn_fns attribute depends on the derive attribute");
@@ -0,0 +1,87 @@
+//! Adapters for `#[serde(with = "...")]` that (de)serialize bondrewd types in their packed form.
+//! The derives use them for `#[bondrewd(serde_bytes)]` and `#[bondrewd_enum(serde_primitive)]`.
+pub use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// (de)serializes a [`Bitfields`](crate::Bitfields) type as the byte string `into_bytes` returns.
+/// Formats without byte strings, like JSON, get an array of numbers.
+pub mod as_bytes {
+    use crate::Bitfields;
+    use serde::de::{Error, SeqAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T, S, const SIZE: usize>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Bitfields<SIZE> + Clone,
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&value.clone().into_bytes())
+    }
+
+    /// fails when there are not exactly `SIZE` bytes.
+    pub fn deserialize<'de, T, D, const SIZE: usize>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Bitfields<SIZE>,
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_bytes(BytesVisitor::<SIZE>)
+            .map(T::from_bytes)
+    }
+
+    struct BytesVisitor<const SIZE: usize>;
+
+    impl<'de, const SIZE: usize> Visitor<'de> for BytesVisitor<SIZE> {
+        type Value = [u8; SIZE];
+
+        fn expecting(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(fmt, "{} bytes", SIZE)
+        }
+
+        fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<[u8; SIZE], E> {
+            bytes
+                .try_into()
+                .map_err(|_| E::invalid_length(bytes.len(), &self))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[u8; SIZE], A::Error> {
+            let mut bytes = [0u8; SIZE];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<u8>()?.is_some() {
+                return Err(A::Error::invalid_length(SIZE + 1, &self));
+            }
+            Ok(bytes)
+        }
+    }
+}
+
+/// (de)serializes a [`BitfieldEnum`](crate::BitfieldEnum) as its primitive instead of its variant
+/// name. Values without a variant are rejected the way
+/// [`try_from_primitive`](crate::TryBitfieldEnum::try_from_primitive) rejects them.
+pub mod as_primitive {
+    use crate::{BitfieldEnum, TryBitfieldEnum};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: BitfieldEnum + Clone,
+        T::Primitive: Serialize,
+        S: Serializer,
+    {
+        value.clone().into_primitive().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryBitfieldEnum,
+        T::Primitive: Deserialize<'de> + core::fmt::Display,
+        D: Deserializer<'de>,
+    {
+        let prim = T::Primitive::deserialize(deserializer)?;
+        T::try_from_primitive(prim).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{Bitfields, BitfieldSliceError};
+
+/// Reads [`Bitfields`] structures packed back to back, each starting at the bit the one before it
+/// ends on. Bit 0 is the most significant bit of the first byte.
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    bit_index: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            bit_index: 0,
+        }
+    }
+
+    /// Starts reading at `bit_index` instead of the first bit.
+    pub fn with_bit_index(bytes: &'a [u8], bit_index: usize) -> Self {
+        Self { bytes, bit_index }
+    }
+
+    /// The bit the next structure starts at.
+    pub fn bit_index(&self) -> usize {
+        self.bit_index
+    }
+
+    /// Amount of bits after the cursor.
+    pub fn remaining_bits(&self) -> usize {
+        (self.bytes.len() * 8).saturating_sub(self.bit_index)
+    }
+
+    /// Reads a structure at the cursor and moves the cursor past it. The cursor does not move when
+    /// there are not enough bits left.
+    pub fn read<T: Bitfields<SIZE>, const SIZE: usize>(&mut self) -> Result<T, BitfieldSliceError> {
+        let value = T::read_from_bits(self.bytes, self.bit_index)?;
+        self.bit_index += T::BIT_SIZE;
+        Ok(value)
+    }
+
+    /// Moves the cursor `bits` forward.
+    pub fn skip(&mut self, bits: usize) -> Result<(), BitfieldSliceError> {
+        check_bits(self.bytes.len(), self.bit_index + bits)?;
+        self.bit_index += bits;
+        Ok(())
+    }
+
+    /// Moves the cursor to the start of the next byte, unless it already is at the start of one.
+    pub fn align_to_byte(&mut self) {
+        self.bit_index = self.bit_index.div_ceil(8) * 8;
+    }
+
+    pub fn into_inner(self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+/// Writes [`Bitfields`] structures back to back, each starting at the bit the one before it ends
+/// on. Bits the structures do not use are left as they are in the buffer.
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    bytes: &'a mut [u8],
+    bit_index: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self {
+            bytes,
+            bit_index: 0,
+        }
+    }
+
+    /// Starts writing at `bit_index` instead of the first bit.
+    pub fn with_bit_index(bytes: &'a mut [u8], bit_index: usize) -> Self {
+        Self { bytes, bit_index }
+    }
+
+    /// The bit the next structure starts at.
+    pub fn bit_index(&self) -> usize {
+        self.bit_index
+    }
+
+    /// Amount of bytes holding bits written so far, counting a partially written last byte.
+    pub fn bytes_used(&self) -> usize {
+        self.bit_index.div_ceil(8)
+    }
+
+    /// Writes a structure at the cursor and moves the cursor past it. Nothing is written when there
+    /// are not enough bits left.
+    pub fn write<T: Bitfields<SIZE>, const SIZE: usize>(
+        &mut self,
+        value: T,
+    ) -> Result<(), BitfieldSliceError> {
+        value.write_to_bits(self.bytes, self.bit_index)?;
+        self.bit_index += T::BIT_SIZE;
+        Ok(())
+    }
+
+    /// Moves the cursor `bits` forward, the bits skipped are not changed.
+    pub fn skip(&mut self, bits: usize) -> Result<(), BitfieldSliceError> {
+        check_bits(self.bytes.len(), self.bit_index + bits)?;
+        self.bit_index += bits;
+        Ok(())
+    }
+
+    /// Moves the cursor to the start of the next byte, unless it already is at the start of one.
+    pub fn align_to_byte(&mut self) {
+        self.bit_index = self.bit_index.div_ceil(8) * 8;
+    }
+
+    pub fn into_inner(self) -> &'a mut [u8] {
+        self.bytes
+    }
+}
+
+fn check_bits(len: usize, end: usize) -> Result<(), BitfieldSliceError> {
+    let required = end.div_ceil(8);
+    if len < required {
+        Err(BitfieldSliceError(len, required))
+    } else {
+        Ok(())
+    }
+}